@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
+
 use criterion::{criterion_group, criterion_main};
 
 fn expand_u8(c: &mut criterion::Criterion) {
@@ -102,6 +104,83 @@ fn compress_normalised(c: &mut criterion::Criterion) {
     });
 }
 
+fn expand_normalised_4(c: &mut criterion::Criterion) {
+    c.bench_function("expand normalised ×4", move |b| {
+        b.iter(|| {
+            for e in 0..64 {
+                let e =
+                    core::array::from_fn(|lane| (e * 4 + lane) as f32 / 255.0);
+                criterion::black_box(srgb::gamma::expand_normalised_4(e));
+            }
+        });
+    });
+}
+
+fn compress_normalised_4(c: &mut criterion::Criterion) {
+    c.bench_function("compress normalised ×4", move |b| {
+        b.iter(|| {
+            for s in 0..64 {
+                let s =
+                    core::array::from_fn(|lane| (s * 4 + lane) as f32 / 255.0);
+                criterion::black_box(srgb::gamma::compress_normalised_4(s));
+            }
+        });
+    });
+}
+
+fn compress_u8_vec(c: &mut criterion::Criterion) {
+    let linear = || {
+        (0..(1 << 12)).map(|i| {
+            let r = (i >> 8) as f32 / 15.0;
+            let g = ((i >> 4) & 15) as f32 / 15.0;
+            let b = (i & 15) as f32 / 15.0;
+            [r, g, b]
+        })
+    };
+    c.bench_function("compress 8-bit map-collect", move |b| {
+        b.iter(|| {
+            let vec: Vec<[u8; 3]> =
+                linear().map(srgb::gamma::u8_from_linear).collect();
+            criterion::black_box(vec);
+        });
+    });
+    c.bench_function("compress 8-bit vec", move |b| {
+        b.iter(|| {
+            criterion::black_box(srgb::gamma::compress_u8_vec(linear()));
+        });
+    });
+}
+
+#[cfg(feature = "portable-simd")]
+fn expand_normalised_simd(c: &mut criterion::Criterion) {
+    use std::simd::f32x16;
+    c.bench_function("expand normalised f32x16", move |b| {
+        b.iter(|| {
+            for e in 0..16 {
+                let e = f32x16::from_array(core::array::from_fn(|lane| {
+                    (e * 16 + lane) as f32 / 255.0
+                }));
+                criterion::black_box(srgb::gamma::expand_normalised_simd(e));
+            }
+        });
+    });
+}
+
+#[cfg(feature = "portable-simd")]
+fn compress_normalised_simd(c: &mut criterion::Criterion) {
+    use std::simd::f32x16;
+    c.bench_function("compress normalised f32x16", move |b| {
+        b.iter(|| {
+            for s in 0..16 {
+                let s = f32x16::from_array(core::array::from_fn(|lane| {
+                    (s * 16 + lane) as f32 / 255.0
+                }));
+                criterion::black_box(srgb::gamma::compress_normalised_simd(s));
+            }
+        });
+    });
+}
+
 criterion_group!(
     benches,
     expand_u8,
@@ -113,5 +192,18 @@ criterion_group!(
     compress_rec709_10bit,
     expand_normalised,
     compress_normalised,
+    expand_normalised_4,
+    compress_normalised_4,
+    compress_u8_vec,
+);
+#[cfg(feature = "portable-simd")]
+criterion_group!(
+    simd_benches,
+    expand_normalised_simd,
+    compress_normalised_simd
 );
+
+#[cfg(not(feature = "portable-simd"))]
 criterion_main!(benches);
+#[cfg(feature = "portable-simd")]
+criterion_main!(benches, simd_benches);
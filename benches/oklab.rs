@@ -0,0 +1,51 @@
+use criterion::{criterion_group, criterion_main};
+
+fn oklab_from_u8(c: &mut criterion::Criterion) {
+    let src: Vec<[u8; 3]> = (0..(1 << 12))
+        .map(|i| [(i >> 4) as u8, (i << 4) as u8, (i * 7) as u8])
+        .collect();
+    let mut dst = vec![[0.0; 3]; src.len()];
+    c.bench_function("8-bit → Oklab", |b| {
+        b.iter(|| {
+            for (src, dst) in src.iter().zip(dst.iter_mut()) {
+                let linear = srgb::gamma::linear_from_u8(*src);
+                *dst = srgb::oklab::oklab_from_linear(linear);
+            }
+            criterion::black_box(&dst);
+        })
+    });
+    c.bench_function("8-bit → Oklab slice", |b| {
+        b.iter(|| {
+            srgb::oklab::oklab_from_u8_slice(&src, &mut dst);
+            criterion::black_box(&dst);
+        })
+    });
+}
+
+fn u8_from_oklab(c: &mut criterion::Criterion) {
+    let src: Vec<[f32; 3]> = (0..(1 << 12))
+        .map(|i| {
+            let rgb = [(i >> 4) as u8, (i << 4) as u8, (i * 7) as u8];
+            srgb::oklab::oklab_from_linear(srgb::gamma::linear_from_u8(rgb))
+        })
+        .collect();
+    let mut dst = vec![[0; 3]; src.len()];
+    c.bench_function("Oklab → 8-bit", |b| {
+        b.iter(|| {
+            for (src, dst) in src.iter().zip(dst.iter_mut()) {
+                let linear = srgb::oklab::linear_from_oklab(*src);
+                *dst = srgb::gamma::u8_from_linear(linear);
+            }
+            criterion::black_box(&dst);
+        })
+    });
+    c.bench_function("Oklab → 8-bit slice", |b| {
+        b.iter(|| {
+            srgb::oklab::u8_from_oklab_slice(&src, &mut dst);
+            criterion::black_box(&dst);
+        })
+    });
+}
+
+criterion_group!(benches, oklab_from_u8, u8_from_oklab);
+criterion_main!(benches);
@@ -22,5 +22,66 @@ fn linear_from_xyz(c: &mut criterion::Criterion) {
     });
 }
 
-criterion_group!(benches, xyz_from_linear, linear_from_xyz,);
+fn u8_from_xyz(c: &mut criterion::Criterion) {
+    let src: Vec<[f32; 3]> = (0..(1 << 12))
+        .map(|i| {
+            let r = (i >> 8) as f32 / 15.0;
+            let g = ((i >> 4) & 15) as f32 / 15.0;
+            let b = (i & 15) as f32 / 15.0;
+            srgb::xyz::xyz_from_linear([r, g, b])
+        })
+        .collect();
+    let mut dst = vec![[0; 3]; src.len()];
+    c.bench_function("XYZ → 8-bit", |b| {
+        b.iter(|| {
+            for (src, dst) in src.iter().zip(dst.iter_mut()) {
+                *dst = srgb::u8_from_xyz(*src);
+            }
+            criterion::black_box(&dst);
+        })
+    });
+    c.bench_function("XYZ → 8-bit slice", |b| {
+        b.iter(|| {
+            srgb::u8_from_xyz_slice(&src, &mut dst);
+            criterion::black_box(&dst);
+        })
+    });
+}
+
+fn xyz_from_u8(c: &mut criterion::Criterion) {
+    let src: Vec<[u8; 3]> = (0..(1 << 12))
+        .map(|i| [(i >> 4) as u8, (i << 4) as u8, (i * 7) as u8])
+        .collect();
+    let mut dst = vec![[0.0; 3]; src.len()];
+    c.bench_function("8-bit → XYZ", |b| {
+        b.iter(|| {
+            for (src, dst) in src.iter().zip(dst.iter_mut()) {
+                *dst = srgb::xyz_from_u8(*src);
+            }
+            criterion::black_box(&dst);
+        })
+    });
+    c.bench_function("8-bit → XYZ slice", |b| {
+        b.iter(|| {
+            srgb::xyz_from_u8_slice(&src, &mut dst);
+            criterion::black_box(&dst);
+        })
+    });
+    c.bench_function("8-bit → XYZ LUT", |b| {
+        b.iter(|| {
+            for (src, dst) in src.iter().zip(dst.iter_mut()) {
+                *dst = srgb::xyz::xyz_from_u8_lut(*src);
+            }
+            criterion::black_box(&dst);
+        })
+    });
+}
+
+criterion_group!(
+    benches,
+    xyz_from_linear,
+    linear_from_xyz,
+    u8_from_xyz,
+    xyz_from_u8,
+);
 criterion_main!(benches);
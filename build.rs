@@ -41,6 +41,35 @@ fn fmt_scalar(scalar: &Scalar) -> String {
     }
 }
 
+/// Converts scalar into a high-precision floating point number.
+fn fl_from_scalar(scalar: &Scalar) -> rug::Float {
+    let fl = |v: &num::BigInt| {
+        rug::Float::with_val(
+            512,
+            rug::Float::parse(v.to_str_radix(10)).unwrap(),
+        )
+    };
+    fl(scalar.numer()) / fl(scalar.denom())
+}
+
+/// Multiplies two high-precision floating point numbers.
+fn mul(lhs: &rug::Float, rhs: &rug::Float) -> rug::Float {
+    rug::Float::with_val(512, lhs * rhs)
+}
+
+/// Formats high-precision floating point number.
+fn fmt_float(value: &rug::Float) -> String {
+    /* Make sure zero is encoded as `0.0` so it’s parsed as a floating point
+     * number and not integer.  Normally, to_str_radix() does not include the
+     * decimal separator when formatting zero. */
+    let value = value.to_string_radix(10, Some(24));
+    if value == "0" {
+        "0.0".into()
+    } else {
+        value
+    }
+}
+
 fn fmt_vector(vec: &[Scalar; 3]) -> String {
     format!(
         "[{}, {}, {}]",
@@ -66,6 +95,70 @@ fn fmt_matrix<T, D: std::fmt::Display>(
     )
 }
 
+/// Formats vector of scalars as an array of high-precision floating point
+/// numbers rounded to 24 significant digits.
+fn fmt_float_vector(vec: &[Scalar; 3]) -> String {
+    format!(
+        "[{}, {}, {}]",
+        fmt_float(&fl_from_scalar(&vec[0])),
+        fmt_float(&fl_from_scalar(&vec[1])),
+        fmt_float(&fl_from_scalar(&vec[2]))
+    )
+}
+
+/// Multiplies two matrices.
+fn matrix_product(
+    lhs: &[[Scalar; 3]; 3],
+    rhs: &[[Scalar; 3]; 3],
+) -> [[Scalar; 3]; 3] {
+    let cell = |row: usize, col: usize| {
+        (0..3).fold(Scalar::zero(), |acc, k| acc + &lhs[row][k] * &rhs[k][col])
+    };
+    [
+        [cell(0, 0), cell(0, 1), cell(0, 2)],
+        [cell(1, 0), cell(1, 1), cell(1, 2)],
+        [cell(2, 0), cell(2, 1), cell(2, 2)],
+    ]
+}
+
+/// Multiplies a matrix by a column.
+fn matrix_column_product(
+    matrix: &[[Scalar; 3]; 3],
+    column: &[Scalar; 3],
+) -> [Scalar; 3] {
+    let cell = |row: usize| {
+        (0..3).fold(Scalar::zero(), |acc, k| acc + &matrix[row][k] * &column[k])
+    };
+    [cell(0), cell(1), cell(2)]
+}
+
+/// Calculates a colour vision deficiency simulation matrix in LMS space.
+///
+/// Response of cone `missing` is replaced by a linear combination of the
+/// remaining two cones chosen such that both `first` and `second` colours
+/// (given in LMS space) are unaffected.
+fn cvd_matrix(
+    missing: usize,
+    first: &[Scalar; 3],
+    second: &[Scalar; 3],
+) -> [[Scalar; 3]; 3] {
+    let (a, b) = ((missing + 1) % 3, (missing + 2) % 3);
+    // Solve p·first[a] + q·first[b] = first[missing] and likewise for second.
+    let det = &first[a] * &second[b] - &first[b] * &second[a];
+    let p =
+        (&first[missing] * &second[b] - &first[b] * &second[missing]) / &det;
+    let q =
+        (&first[a] * &second[missing] - &first[missing] * &second[a]) / &det;
+    let mut matrix: [[Scalar; 3]; 3] = Default::default();
+    for (idx, row) in matrix.iter_mut().enumerate() {
+        row[idx] = Scalar::one();
+    }
+    matrix[missing] = Default::default();
+    matrix[missing][a] = p;
+    matrix[missing][b] = q;
+    matrix
+}
+
 fn fmt_chromaticity(ch: &Chromaticity) -> String {
     fmt_vector(&[ch.x().clone(), ch.y().clone(), One::one()])
 }
@@ -127,6 +220,24 @@ fn generate() -> std::io::Result<()> {
         ));
     };
 
+    let s0 = calc_gamma_threshold::<f64>();
+    let e0 = gamma_compress_lin_part(&s0);
+
+    /* 512 bits of precision is a massive overkill but whatever, we don’t care
+     * about speed and having too much precision won’t hurt. */
+    let fl = |v| rug::Float::with_val(512, v);
+    let u8_to_linear = (0..=255)
+        .map(|v| {
+            if v <= (e0 * 255.0) as u8 {
+                fl(v as u32 * 10) / fl(32946)
+            } else {
+                let v = fl(v as u32 * 1_000 + 55 * 255) / fl(1055u32 * 255);
+                let e = fl(24) / fl(10);
+                rug::ops::Pow::pow(v, e)
+            }
+        })
+        .collect::<Vec<_>>();
+
     let white_xy = chromaticity((312713, 1000000), (329016, 1000000));
     let primaries_xy = [
         chromaticity((64, 100), (33, 100)),
@@ -140,6 +251,64 @@ fn generate() -> std::io::Result<()> {
     let inverse = rgb_derivation::matrix::inversed_copy(&matrix).unwrap();
     let primaries_xyz = rgb_derivation::matrix::transposed_copy(&matrix);
 
+    let xyz_from_u8_lut = (0..3)
+        .map(|channel| {
+            let column = [
+                fl_from_scalar(&matrix[0][channel]),
+                fl_from_scalar(&matrix[1][channel]),
+                fl_from_scalar(&matrix[2][channel]),
+            ];
+            let rows = u8_to_linear
+                .iter()
+                .map(|v| {
+                    format!(
+                        "        [{}, {}, {}],\n",
+                        fmt_float(&mul(&column[0], v)),
+                        fmt_float(&mul(&column[1], v)),
+                        fmt_float(&mul(&column[2], v)),
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("");
+            format!("    [\n{}    ],\n", rows)
+        })
+        .collect::<Vec<_>>()
+        .join("");
+
+    /* Other standard illuminants.  Chromaticities are for the CIE 1931 2°
+     * standard observer. */
+    let white_points = [
+        ("D50", "horizon light", (34567, 100000), (35850, 100000)),
+        ("D55", "mid-morning daylight", (33242, 100000), (34743, 100000)),
+        ("D75", "north sky daylight", (29902, 100000), (31485, 100000)),
+        ("A", "incandescent light", (44757, 100000), (40745, 100000)),
+        ("C", "average daylight", (31006, 100000), (31616, 100000)),
+        ("E", "equal energy", (1, 3), (1, 3)),
+    ]
+    .iter()
+    .map(|(name, desc, x, y)| {
+        let xy = chromaticity(*x, *y);
+        let fmt = |(n, d): (i64, i64)| {
+            if d == 100000 {
+                format!("{}", n as f64 / d as f64)
+            } else {
+                format!("{}/{}", n, d)
+            }
+        };
+        format!(
+            "\n/// XYZ coordinates of the CIE standard illuminant {name} \
+             ({desc}).\n///\n/// Calculated from ({x}, {y}) xy chromaticity \
+             with Y equal one.\npub const {name}_XYZ: [f32; 3] = {xyz};\n",
+            name = name,
+            desc = desc,
+            x = fmt(*x),
+            y = fmt(*y),
+            xyz = fmt_vector(&xy.to_xyz()),
+        )
+    })
+    .collect::<Vec<_>>()
+    .join("");
+
     write_to(
         &out_dir,
         "xyz_constants.rs",
@@ -152,7 +321,7 @@ pub const D65_xyY: [f32; 3] = {white_xyY};
 
 /// XYZ coordinates of the D65 reference white-point used in sRGB colour space.
 pub const D65_XYZ: [f32; 3] = {white_XYZ};
-
+{white_points}
 /// xyY coordinates of red, green and blue primaries defining the sRGB space.
 #[allow(non_upper_case_globals)]
 pub const PRIMARIES_xyY: [[f32; 3]; 3] = {primaries_xyY};
@@ -181,39 +350,161 @@ pub const XYZ_FROM_SRGB_MATRIX: [[f32; 3]; 3] = {matrix};
 /// one-column matrices.  With that, converting from XYZ to sRGB is done by the
 /// following formula: `RGB = SRGB_FROM_XYZ_MATRIX ✕ XYZ`.
 pub const SRGB_FROM_XYZ_MATRIX: [[f32; 3]; 3] = {inverse};
+
+/// Lookup tables used by the xyz_from_u8_lut() function.
+///
+/// `XYZ_FROM_U8_LUT[c][v]` holds XYZ coordinates of a colour whose `c`-th sRGB
+/// component is `v` and other components are zero.
+static XYZ_FROM_U8_LUT: [[[f32; 3]; 256]; 3] = [
+{xyz_from_u8_lut}];
 ",
             white_xyY = fmt_chromaticity(&white_xy),
             white_XYZ = fmt_vector(&white_xyz),
+            white_points = white_points,
             primaries_xyY = fmt_matrix(&primaries_xy, fmt_chromaticity),
             primaries_XYZ = fmt_matrix(&primaries_xyz, fmt_vector),
             matrix = fmt_matrix(&matrix, fmt_vector),
-            inverse = fmt_matrix(&inverse, fmt_vector)
+            inverse = fmt_matrix(&inverse, fmt_vector),
+            xyz_from_u8_lut = xyz_from_u8_lut
         ),
     )?;
 
-    let s0 = calc_gamma_threshold::<f64>();
-    let e0 = gamma_compress_lin_part(&s0);
+    /* Display P3 uses DCI-P3 primaries with D65 white point. */
+    let p3_primaries_xy = [
+        chromaticity((680, 1000), (320, 1000)),
+        chromaticity((265, 1000), (690, 1000)),
+        chromaticity((150, 1000), (60, 1000)),
+    ];
+    let xyz_from_p3 =
+        rgb_derivation::matrix::calculate(&white_xyz, &p3_primaries_xy)
+            .unwrap();
+    let p3_from_xyz =
+        rgb_derivation::matrix::inversed_copy(&xyz_from_p3).unwrap();
 
-    /* 512 bits of precision is a massive overkill but whatever, we don’t care
-     * about speed and having too much precision won’t hurt. */
-    let fl = |v| rug::Float::with_val(512, v);
-    let u8_to_linear = (0..=255)
-        .map(|v| {
-            if v <= (e0 * 255.0) as u8 {
-                fl(v as u32 * 10) / fl(32946)
-            } else {
-                let v = fl(v as u32 * 1_000 + 55 * 255) / fl(1055u32 * 255);
-                let e = fl(24) / fl(10);
-                rug::ops::Pow::pow(v, e)
-            }
-        })
-        .map(|v| {
-            /* Make sure zero is encoded as `0.0` so it’s parsed as a floating
-             * point number and not integer.  Normally, to_str_radix() does not
-             * include the decimal separator when formatting zero. */
-            let v = v.to_string_radix(10, Some(24));
-            format!("    {},\n", if v == "0" { &"0.0" } else { &v[..] })
-        })
+    write_to(
+        &out_dir,
+        "p3_constants.rs",
+        format_args!(
+            r"// Generated by build.rs
+
+/// The conversion matrix from linear Display P3 to XYZ colour space.
+///
+/// To perform the conversion it’s typically more convenient to use the
+/// xyz_from_p3_linear() function instead of accessing this constant.
+pub const XYZ_FROM_P3_MATRIX: [[f32; 3]; 3] = {xyz_from_p3};
+
+/// The conversion matrix from XYZ to linear Display P3 colour space.
+///
+/// To perform the conversion it’s typically more convenient to use the
+/// p3_linear_from_xyz() function instead of accessing this constant.
+pub const P3_FROM_XYZ_MATRIX: [[f32; 3]; 3] = {p3_from_xyz};
+",
+            xyz_from_p3 = fmt_matrix(&xyz_from_p3, fmt_vector),
+            p3_from_xyz = fmt_matrix(&p3_from_xyz, fmt_vector),
+        ),
+    )?;
+
+    /* ITU-R BT.2020 primaries with D65 white point. */
+    let rec2020_primaries_xy = [
+        chromaticity((708, 1000), (292, 1000)),
+        chromaticity((170, 1000), (797, 1000)),
+        chromaticity((131, 1000), (46, 1000)),
+    ];
+    let xyz_from_rec2020 =
+        rgb_derivation::matrix::calculate(&white_xyz, &rec2020_primaries_xy)
+            .unwrap();
+    let rec2020_from_xyz =
+        rgb_derivation::matrix::inversed_copy(&xyz_from_rec2020).unwrap();
+
+    write_to(
+        &out_dir,
+        "rec2020_constants.rs",
+        format_args!(
+            r"// Generated by build.rs
+
+/// The conversion matrix from linear Rec.2020 to XYZ colour space.
+///
+/// To perform the conversion it’s typically more convenient to use the
+/// xyz_from_rec2020_linear() function instead of accessing this constant.
+pub const XYZ_FROM_REC2020_MATRIX: [[f32; 3]; 3] = {xyz_from_rec2020};
+
+/// The conversion matrix from XYZ to linear Rec.2020 colour space.
+///
+/// To perform the conversion it’s typically more convenient to use the
+/// rec2020_linear_from_xyz() function instead of accessing this constant.
+pub const REC2020_FROM_XYZ_MATRIX: [[f32; 3]; 3] = {rec2020_from_xyz};
+",
+            xyz_from_rec2020 = fmt_matrix(&xyz_from_rec2020, fmt_vector),
+            rec2020_from_xyz = fmt_matrix(&rec2020_from_xyz, fmt_vector),
+        ),
+    )?;
+
+    /* Hunt-Pointer-Estevez matrix normalised to D65 white point. */
+    let lms_from_xyz = [
+        [scalar(4002, 10000), scalar(7076, 10000), scalar(-808, 10000)],
+        [scalar(-2263, 10000), scalar(11653, 10000), scalar(457, 10000)],
+        [scalar(0, 1), scalar(0, 1), scalar(9182, 10000)],
+    ];
+    let lms_from_srgb = matrix_product(&lms_from_xyz, &matrix);
+    let srgb_from_lms =
+        rgb_derivation::matrix::inversed_copy(&lms_from_srgb).unwrap();
+
+    /* Colour vision deficiency simulation matrices in linear sRGB space. */
+    let lms_of = |rgb: [i64; 3]| {
+        matrix_column_product(&lms_from_srgb, &rgb.map(|v| scalar(v, 1)))
+    };
+    let (white, red, blue) =
+        (lms_of([1, 1, 1]), lms_of([1, 0, 0]), lms_of([0, 0, 1]));
+    let cvd = |missing: usize, anchor: &[Scalar; 3]| {
+        let sim = cvd_matrix(missing, &white, anchor);
+        matrix_product(&srgb_from_lms, &matrix_product(&sim, &lms_from_srgb))
+    };
+
+    write_to(
+        &out_dir,
+        "lms_constants.rs",
+        format_args!(
+            r"// Generated by build.rs
+
+/// The conversion matrix from XYZ to LMS colour space.
+///
+/// This is Hunt-Pointer-Estevez matrix normalised to D65 white point such that
+/// the white point has equal cone responses.
+pub const LMS_FROM_XYZ_MATRIX: [[f32; 3]; 3] = {lms_from_xyz};
+
+/// The conversion matrix from linear sRGB to LMS colour space.
+///
+/// To perform the conversion it’s typically more convenient to use the
+/// lms_from_linear() function instead of accessing this constant.
+pub const LMS_FROM_SRGB_MATRIX: [[f32; 3]; 3] = {lms_from_srgb};
+
+/// The conversion matrix from LMS to linear sRGB colour space.
+///
+/// To perform the conversion it’s typically more convenient to use the
+/// linear_from_lms() function instead of accessing this constant.
+pub const SRGB_FROM_LMS_MATRIX: [[f32; 3]; 3] = {srgb_from_lms};
+
+/// Protanopia simulation matrix operating on linear sRGB.
+pub(crate) const PROTANOPIA_MATRIX: [[f32; 3]; 3] = {protanopia};
+
+/// Deuteranopia simulation matrix operating on linear sRGB.
+pub(crate) const DEUTERANOPIA_MATRIX: [[f32; 3]; 3] = {deuteranopia};
+
+/// Tritanopia simulation matrix operating on linear sRGB.
+pub(crate) const TRITANOPIA_MATRIX: [[f32; 3]; 3] = {tritanopia};
+",
+            lms_from_xyz = fmt_matrix(&lms_from_xyz, fmt_float_vector),
+            lms_from_srgb = fmt_matrix(&lms_from_srgb, fmt_float_vector),
+            srgb_from_lms = fmt_matrix(&srgb_from_lms, fmt_float_vector),
+            protanopia = fmt_matrix(&cvd(0, &blue), fmt_float_vector),
+            deuteranopia = fmt_matrix(&cvd(1, &blue), fmt_float_vector),
+            tritanopia = fmt_matrix(&cvd(2, &red), fmt_float_vector),
+        ),
+    )?;
+
+    let u8_to_linear = u8_to_linear
+        .iter()
+        .map(|v| format!("    {},\n", fmt_float(v)))
         .collect::<Vec<_>>()
         .join("");
 
@@ -245,6 +536,12 @@ pub const S_0: f32 = {:.};
 /// though that’s subject to floating-point rounding.
 pub const E_0: f32 = {:.};
 
+/// [`S_0`] in double precision.
+const S_0_F64: f64 = {0:.};
+
+/// [`E_0`] in double precision.
+const E_0_F64: f64 = {1:.};
+
 const U8_TO_LINEAR_LUT: [f32; 256] = [
 {}
 ];
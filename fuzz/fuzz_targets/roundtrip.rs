@@ -0,0 +1,155 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Fuzz target checking conversion functions with arbitrary inputs.
+//!
+//! Input is interpreted as a sequence of 12-byte records each holding three
+//! little-endian `f32` values.  All conversion functions are called with
+//! every value to make sure none of them panic (no matter if the value is
+//! NaN, infinite or subnormal).  If the values are in the 0–1 range,
+//! accuracy of round trips is verified as well.
+
+#![no_main]
+
+use srgb::{gamma, oklab, xyz};
+
+/// Maximum absolute error allowed for round trips of values in 0–1 range.
+const EPSILON: f32 = 0.000001;
+
+libfuzzer_sys::fuzz_target!(|data: &[u8]| {
+    for record in data.chunks_exact(12) {
+        let value = |idx: usize| {
+            let mut bytes = [0; 4];
+            bytes.copy_from_slice(&record[idx * 4..idx * 4 + 4]);
+            f32::from_le_bytes(bytes)
+        };
+        let triple = [value(0), value(1), value(2)];
+        check_no_panic(triple);
+        for value in triple.iter() {
+            check_scalar(*value);
+        }
+        if triple.iter().all(|v| (0.0..=1.0).contains(v)) {
+            check_xyz_roundtrip(triple);
+        }
+        check_u8([record[0], record[1], record[2]]);
+    }
+});
+
+
+/// Calls conversion functions with given triple ignoring the results.
+///
+/// The results are passed through [`core::hint::black_box`] so that the
+/// calls aren’t optimised away.
+fn check_no_panic(triple: [f32; 3]) {
+    use core::hint::black_box;
+
+    black_box(xyz::xyz_from_linear(triple));
+    black_box(xyz::linear_from_xyz(triple));
+    black_box(srgb::u8_from_xyz(triple));
+    black_box(srgb::normalised_from_xyz(triple));
+    black_box(gamma::u8_from_linear(triple));
+    black_box(gamma::linear_from_normalised(triple));
+    black_box(gamma::normalised_from_linear(triple));
+    black_box(oklab::oklab_from_linear(triple));
+    black_box(oklab::linear_from_oklab(triple));
+    black_box(srgb::u8_from_normalised(triple));
+}
+
+/// Checks single-component gamma functions with given value.
+fn check_scalar(value: f32) {
+    use core::hint::black_box;
+
+    black_box(gamma::compress_u8(value));
+    black_box(gamma::compress_u8_precise(value));
+    black_box(gamma::compress_u8_gpu(value));
+    black_box(gamma::compress_u16(value));
+    black_box(gamma::compress_rec709_8bit(value));
+    black_box(gamma::compress_rec709_10bit(value));
+
+    let expanded = gamma::expand_sycc(value);
+    let compressed = gamma::compress_sycc(value);
+    assert_eq!(value.is_nan(), expanded.is_nan(), "expand_sycc({})", value);
+    assert_eq!(value.is_nan(), compressed.is_nan(), "compress_sycc({})", value);
+
+    if !(0.0..=1.0).contains(&value) {
+        return;
+    }
+
+    let check = |name: &str, got: f32| {
+        assert!(
+            (got - value).abs() <= EPSILON,
+            "{}: {} → {} (error: {})",
+            name,
+            value,
+            got,
+            (got - value).abs()
+        );
+    };
+    check(
+        "compress(expand(v))",
+        gamma::compress_normalised(gamma::expand_normalised(value)),
+    );
+    check(
+        "expand(compress(v))",
+        gamma::expand_normalised(gamma::compress_normalised(value)),
+    );
+    check(
+        "compress_gpu(expand_gpu(v))",
+        gamma::compress_normalised_gpu(gamma::expand_normalised_gpu(value)),
+    );
+    check(
+        "expand_gpu(compress_gpu(v))",
+        gamma::expand_normalised_gpu(gamma::compress_normalised_gpu(value)),
+    );
+    check("compress_sycc(expand_sycc(v))", gamma::compress_sycc(expanded));
+    check("expand_sycc(compress_sycc(v))", gamma::expand_sycc(compressed));
+
+    // Value must fall into the range of linear values which are mapped to
+    // the code it was compressed to.
+    let code = gamma::compress_u8(value);
+    let (lo, hi) = gamma::u8_bucket(code);
+    assert!(
+        lo <= value && value < hi,
+        "{} → {} whose bucket is [{}, {})",
+        value,
+        code,
+        lo,
+        hi
+    );
+}
+
+/// Checks conversion to XYZ and back with given linear sRGB colour.
+fn check_xyz_roundtrip(linear: [f32; 3]) {
+    let got = xyz::linear_from_xyz(xyz::xyz_from_linear(linear));
+    for (want, got) in linear.iter().zip(got.iter()) {
+        assert!(
+            (want - got).abs() <= EPSILON,
+            "{:?} → {:?} (error: {})",
+            linear,
+            got,
+            (want - got).abs()
+        );
+    }
+}
+
+/// Checks that 24-bit sRGB colour survives trip through linear space.
+fn check_u8(rgb: [u8; 3]) {
+    assert_eq!(rgb, gamma::u8_from_linear(gamma::linear_from_u8(rgb)));
+    for code in rgb.iter() {
+        let value = gamma::expand_u8(*code);
+        let (lo, hi) = gamma::u8_bucket(*code);
+        assert!(lo <= value && value < hi, "{} → {}", code, value);
+    }
+}
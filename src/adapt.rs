@@ -0,0 +1,263 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions calculating chromatic adaptation transforms.
+//!
+//! Chromatic adaptation transform (CAT) converts XYZ coordinates of a colour
+//! seen under one illuminant into coordinates of a colour which looks the same
+//! under another illuminant.  All supported methods work the same way: XYZ
+//! coordinates are converted into a cone-like response space, each response
+//! is scaled by ratio of the destination and source white point responses and
+//! the result is converted back to XYZ.  Methods differ in the matrix used for
+//! the first step.
+//!
+//! Rather than converting individual colours, [`matrix()`] returns the whole
+//! transform as a single 3×3 matrix so it can be combined with other linear
+//! transforms, e.g. conversion from linear sRGB into XYZ.
+
+
+/// Method of chromatic adaptation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CatMethod {
+    /// Bradford transform as used by ICC profiles and CSS Color Module.
+    Bradford,
+    /// CAT02 transform as defined by CIECAM02 colour appearance model.
+    Cat02,
+    /// Von Kries transform using Hunt-Pointer-Estevez cone response matrix.
+    VonKries,
+    /// Scaling of XYZ coordinates (sometimes called ‘wrong von Kries’).
+    XyzScaling,
+}
+
+impl CatMethod {
+    /// Returns matrix converting XYZ coordinates into cone response space
+    /// used by the method.
+    fn cone_matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Bradford => {
+                [[0.8951, 0.2664, -0.1614], [-0.7502, 1.7135, 0.0367], [
+                    0.0389, -0.0685, 1.0296,
+                ]]
+            }
+            Self::Cat02 => {
+                [[0.7328, 0.4296, -0.1624], [-0.7036, 1.6975, 0.0061], [
+                    0.0030, 0.0136, 0.9834,
+                ]]
+            }
+            Self::VonKries => {
+                [[0.40024, 0.70760, -0.08081], [-0.22630, 1.16532, 0.04570], [
+                    0.0, 0.0, 0.91822,
+                ]]
+            }
+            Self::XyzScaling => {
+                [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]
+            }
+        }
+    }
+}
+
+
+/// Returns matrix adapting XYZ coordinates from `src` white point to `dst`
+/// white point using specified method.
+///
+/// White points are given as XYZ coordinates (e.g. [`crate::xyz::D65_XYZ`]).
+/// The result is a row-major matrix, i.e. the same layout as
+/// [`crate::xyz::XYZ_FROM_SRGB_MATRIX`], which maps `src` onto `dst`.  The
+/// calculations are done with double precision and only the final matrix is
+/// rounded to single precision.
+///
+/// # Example
+/// ```
+/// use srgb::adapt::{matrix, CatMethod};
+/// use srgb::xyz::{D50_XYZ, D65_XYZ};
+///
+/// let m = matrix(CatMethod::Bradford, D65_XYZ, D50_XYZ);
+/// approx::assert_abs_diff_eq!(1.04784, m[0][0], epsilon = 0.00001);
+///
+/// let dot = |row: &[f32; 3]| {
+///     row.iter().zip(D65_XYZ.iter()).map(|(a, b)| a * b).sum::<f32>()
+/// };
+/// let white = [dot(&m[0]), dot(&m[1]), dot(&m[2])];
+/// approx::assert_abs_diff_eq!(&D50_XYZ[..], &white[..], epsilon = 0.00001);
+/// ```
+pub fn matrix(
+    method: CatMethod,
+    src: impl Into<[f32; 3]>,
+    dst: impl Into<[f32; 3]>,
+) -> [[f32; 3]; 3] {
+    let src = src.into().map(f64::from);
+    let dst = dst.into().map(f64::from);
+    matrix_f64(method, src, dst).map(|row| row.map(|v| v as f32))
+}
+
+/// Returns matrix adapting XYZ coordinates from `src` white point to `dst`
+/// white point using Bradford transform.
+///
+/// This is a shorthand for [`matrix()`] with [`CatMethod::Bradford`].
+///
+/// # Example
+/// ```
+/// use srgb::adapt::{bradford_matrix, matrix, CatMethod};
+/// use srgb::xyz::{D50_XYZ, D65_XYZ};
+///
+/// let want = matrix(CatMethod::Bradford, D65_XYZ, D50_XYZ);
+/// assert_eq!(want, bradford_matrix(D65_XYZ, D50_XYZ));
+/// ```
+pub fn bradford_matrix(
+    src: impl Into<[f32; 3]>,
+    dst: impl Into<[f32; 3]>,
+) -> [[f32; 3]; 3] {
+    matrix(CatMethod::Bradford, src, dst)
+}
+
+/// Returns matrix adapting XYZ coordinates from `src` white point to `dst`
+/// white point using CAT02 transform.
+///
+/// This is a shorthand for [`matrix()`] with [`CatMethod::Cat02`].  It has the
+/// same signature as [`bradford_matrix()`] so the two can be used
+/// interchangeably.  CAT02 is the transform used by CIECAM02 colour appearance
+/// model.
+///
+/// # Example
+/// ```
+/// use srgb::adapt::cat02_matrix;
+/// use srgb::xyz::{D50_XYZ, D65_XYZ};
+///
+/// let m = cat02_matrix(D65_XYZ, D50_XYZ);
+/// approx::assert_abs_diff_eq!(1.04251, m[0][0], epsilon = 0.00001);
+/// ```
+pub fn cat02_matrix(
+    src: impl Into<[f32; 3]>,
+    dst: impl Into<[f32; 3]>,
+) -> [[f32; 3]; 3] {
+    matrix(CatMethod::Cat02, src, dst)
+}
+
+/// Returns matrix adapting XYZ coordinates from `src` white point to `dst`
+/// white point performing calculations in double precision.
+pub(crate) fn matrix_f64(
+    method: CatMethod,
+    src: [f64; 3],
+    dst: [f64; 3],
+) -> [[f64; 3]; 3] {
+    let cone = method.cone_matrix();
+    let src = cone_response(&cone, src);
+    let dst = cone_response(&cone, dst);
+    let mut scaled = cone;
+    // Scale rows of the cone matrix by ratios of white point responses.
+    for (row, (dst, src)) in scaled.iter_mut().zip(dst.iter().zip(src.iter())) {
+        for value in row.iter_mut() {
+            *value *= dst / src;
+        }
+    }
+    let inv = crate::maths::inverse_f64(&cone);
+    crate::maths::matrix_multiply_f64(&inv, &scaled)
+}
+
+/// Converts XYZ coordinates into cone responses using given matrix.
+fn cone_response(cone: &[[f64; 3]; 3], xyz: [f64; 3]) -> [f64; 3] {
+    cone.map(|row| row.iter().zip(xyz.iter()).map(|(a, b)| a * b).sum())
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const METHODS: [CatMethod; 4] = [
+        CatMethod::Bradford,
+        CatMethod::Cat02,
+        CatMethod::VonKries,
+        CatMethod::XyzScaling,
+    ];
+
+    const WHITES: [[f32; 3]; 3] =
+        [crate::xyz::D65_XYZ, crate::xyz::D50_XYZ, crate::xyz::A_XYZ];
+
+    fn apply(m: &[[f32; 3]; 3], xyz: [f32; 3]) -> [f32; 3] {
+        crate::arr_map(*m, |row: [f32; 3]| {
+            row.iter().zip(xyz.iter()).map(|(a, b)| a * b).sum()
+        })
+    }
+
+    #[test]
+    fn test_identity() {
+        for method in METHODS.iter().copied() {
+            for white in WHITES.iter().copied() {
+                let m = matrix(method, white, white);
+                for (r, row) in m.iter().enumerate() {
+                    for (c, value) in row.iter().enumerate() {
+                        let want = if r == c { 1.0 } else { 0.0 };
+                        approx::assert_abs_diff_eq!(
+                            want,
+                            *value,
+                            epsilon = 1e-6
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_white_mapping() {
+        for method in METHODS.iter().copied() {
+            for src in WHITES.iter().copied() {
+                for dst in WHITES.iter().copied() {
+                    let got = apply(&matrix(method, src, dst), src);
+                    approx::assert_abs_diff_eq!(
+                        &dst[..],
+                        &got[..],
+                        epsilon = 1e-6
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_bradford() {
+        // Matrix given in CSS Color Module Level 4.
+        const D65_FROM_D50: [[f32; 3]; 3] = [
+            [0.955473421488075, -0.02309845494876471, 0.06325924320057072],
+            [-0.0283697093338637, 1.0099953980813041, 0.021041441191917323],
+            [0.012314014864481998, -0.020507649298898964, 1.330365926242124],
+        ];
+        let d50 = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+        let d65 = [0.3127 / 0.3290, 1.0, (1.0 - 0.3127 - 0.3290) / 0.3290];
+        let got = matrix(CatMethod::Bradford, d50, d65);
+        for (want, got) in D65_FROM_D50.iter().zip(got.iter()) {
+            approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_cat02_identity() {
+        let white = crate::xyz::D65_XYZ;
+        let m = cat02_matrix(white, white);
+        for (r, row) in m.iter().enumerate() {
+            for (c, value) in row.iter().enumerate() {
+                let want = if r == c { 1.0 } else { 0.0 };
+                approx::assert_ulps_eq!(want, *value, max_ulps = 4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_xyz_scaling() {
+        let m = matrix(CatMethod::XyzScaling, [2.0, 1.0, 4.0], [1.0, 1.0, 1.0]);
+        assert_eq!([[0.5, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.25]], m);
+    }
+}
@@ -0,0 +1,157 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for handling colours with alpha channel.
+//!
+//! Colours are given as `[red, green, blue, alpha]` arrays with components in
+//! linear sRGB space (see [`crate::gamma`] module) and alpha in the range from
+//! zero to one.  Compositing and filtering must be done on linear values with
+//! premultiplied alpha; gamma compression and expansion must be done on
+//! values with straight (i.e. not premultiplied) alpha.
+
+/// Converts a colour in linear space with straight alpha into premultiplied
+/// alpha representation.
+///
+/// Each colour component is multiplied by the alpha.  The alpha itself is
+/// returned unchanged.
+///
+/// # Example
+/// ```
+/// use srgb::alpha::premultiply_linear;
+///
+/// assert_eq!([0.5, 0.25, 0.0, 0.5], premultiply_linear([1.0, 0.5, 0.0, 0.5]));
+/// assert_eq!([0.0, 0.0, 0.0, 0.0], premultiply_linear([1.0, 0.5, 0.0, 0.0]));
+/// ```
+pub fn premultiply_linear(rgba: impl Into<[f32; 4]>) -> [f32; 4] {
+    let [r, g, b, a] = rgba.into();
+    [r * a, g * a, b * a, a]
+}
+
+/// Converts a colour in linear space with premultiplied alpha into straight
+/// alpha representation.
+///
+/// This is an inverse of [`premultiply_linear()`].  Colour of a fully
+/// transparent pixel is undefined in premultiplied representation so if alpha
+/// is zero (or negative) returns transparent black.
+///
+/// # Example
+/// ```
+/// use srgb::alpha::unpremultiply_linear;
+///
+/// let got = unpremultiply_linear([0.5, 0.25, 0.0, 0.5]);
+/// assert_eq!([1.0, 0.5, 0.0, 0.5], got);
+/// let got = unpremultiply_linear([0.5, 0.5, 0.5, 0.0]);
+/// assert_eq!([0.0, 0.0, 0.0, 0.0], got);
+/// ```
+pub fn unpremultiply_linear(rgba: impl Into<[f32; 4]>) -> [f32; 4] {
+    let [r, g, b, a] = rgba.into();
+    if a <= 0.0 {
+        return [0.0; 4];
+    }
+    [r / a, g / a, b / a, a]
+}
+
+/// Composites `src` colour over `dst` colour.
+///
+/// Implements Porter-Duff ‘over’ operator.  Both colours and the result are
+/// in linear space with straight alpha.  If `src` is fully opaque returns it
+/// unchanged; if it’s fully transparent returns `dst` unchanged (even if
+/// `dst` is fully transparent as well).
+///
+/// # Example
+/// ```
+/// use srgb::alpha::blend_over;
+///
+/// let red = [1.0, 0.0, 0.0, 1.0];
+/// let blue = [0.0, 0.0, 1.0, 0.5];
+/// let clear = [0.5, 0.5, 0.5, 0.0];
+/// assert_eq!(red, blend_over(red, blue));
+/// assert_eq!(blue, blend_over(clear, blue));
+/// assert_eq!([0.5, 0.0, 0.5, 1.0], blend_over([0.0, 0.0, 1.0, 0.5], red));
+/// assert_eq!([0.0, 0.0, 1.0, 0.75], blend_over(blue, blue));
+/// ```
+pub fn blend_over(
+    src: impl Into<[f32; 4]>,
+    dst: impl Into<[f32; 4]>,
+) -> [f32; 4] {
+    let (src, dst) = (src.into(), dst.into());
+    if src[3] >= 1.0 {
+        return src;
+    } else if src[3] <= 0.0 {
+        return dst;
+    }
+    let src = premultiply_linear(src);
+    let dst = premultiply_linear(dst);
+    let k = 1.0 - src[3];
+    unpremultiply_linear([
+        crate::maths::mul_add(dst[0], k, src[0]),
+        crate::maths::mul_add(dst[1], k, src[1]),
+        crate::maths::mul_add(dst[2], k, src[2]),
+        crate::maths::mul_add(dst[3], k, src[3]),
+    ])
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for a in 1..=255 {
+            let a = a as f32 / 255.0;
+            for c in 0..=16 {
+                let c = c as f32 / 16.0;
+                let rgba = [c, 1.0 - c, c * 0.5, a];
+                let got = unpremultiply_linear(premultiply_linear(rgba));
+                let (want, got) = (&rgba[..], &got[..]);
+                approx::assert_abs_diff_eq!(want, got, epsilon = 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_blend_over() {
+        let colours = (0..64u32)
+            .map(|i| {
+                let [r, g, b] = crate::from_u32(i.wrapping_mul(0x9e3779b9));
+                let [r, g, b] = crate::gamma::linear_from_u8([r, g, b]);
+                [r, g, b, (i % 5) as f32 / 4.0]
+            })
+            .collect::<Vec<_>>();
+        for src in colours.iter().copied() {
+            for dst in colours.iter().copied() {
+                let got = blend_over(src, dst);
+                if src[3] == 1.0 {
+                    assert_eq!(src, got);
+                } else if src[3] == 0.0 {
+                    assert_eq!(dst, got);
+                } else {
+                    // Alpha of the result is at least alpha of either colour.
+                    assert!(got[3] >= src[3] && got[3] >= dst[3] - 1e-6);
+                    for i in 0..3 {
+                        let want =
+                            src[i] * src[3] + dst[i] * dst[3] * (1.0 - src[3]);
+                        let got = got[i] * got[3];
+                        approx::assert_abs_diff_eq!(want, got, epsilon = 1e-6);
+                    }
+                }
+            }
+        }
+        assert_eq!([0.0; 4], blend_over([1.0, 1.0, 1.0, 0.0], [0.0; 4]));
+        let clear = [0.5, 0.5, 0.5, 0.0];
+        assert_eq!(clear, blend_over([0.0; 4], clear));
+    }
+}
@@ -0,0 +1,439 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for perceptual analysis of sRGB colours.
+
+use core::convert::TryFrom;
+
+/// Estimates per-channel smallest change of a 24-bit sRGB colour which is
+/// perceptible.
+///
+/// For each channel, returns the smallest change of that channel alone (in
+/// either direction) which results in CIEDE2000 colour difference of at least
+/// one, i.e. a just-noticeable difference.  Larger values indicate that the
+/// channel can be quantised more coarsely around given colour without
+/// visible banding.  If no change of a channel is perceptible, 255 is
+/// returned for it.
+///
+/// Note that since sRGB gamma encoding is itself approximately perceptually
+/// uniform, for greys the steps are similar throughout the whole range.  The
+/// steps differ more significantly for saturated colours.
+///
+/// # Example
+/// ```
+/// use srgb::analyse::min_perceptible_step;
+///
+/// assert_eq!([2, 2, 2], min_perceptible_step([128, 128, 128]));
+/// assert_eq!([5, 8, 4], min_perceptible_step([212, 33, 61]));
+/// ```
+pub fn min_perceptible_step(rgb: impl Into<[u8; 3]>) -> [u8; 3] {
+    let rgb = rgb.into();
+    let lab = crate::lab::lab_from_u8(rgb);
+    let is_perceptible = |channel: usize, value: i32| {
+        u8::try_from(value).is_ok_and(|value| {
+            let mut other = rgb;
+            other[channel] = value;
+            crate::delta::delta_e_2000(lab, crate::lab::lab_from_u8(other)) >=
+                1.0
+        })
+    };
+    let step = |channel: usize| {
+        let value = i32::from(rgb[channel]);
+        (1..=255)
+            .find(|step| {
+                is_perceptible(channel, value + i32::from(*step)) ||
+                    is_perceptible(channel, value - i32::from(*step))
+            })
+            .unwrap_or(255)
+    };
+    [step(0), step(1), step(2)]
+}
+
+
+/// Calculates average colour of a set of 24-bit sRGB colours.
+///
+/// Averaging gamma-encoded values directly produces results which are too
+/// dark (e.g. an image which is half black and half white averages to 128).
+/// This function instead converts colours to linear space, averages them
+/// there using Kahan summation and converts the mean back to sRGB.  This is
+/// useful, for example, for computing a placeholder colour of an image.
+///
+/// Returns black if `pixels` is empty.
+///
+/// # Example
+/// ```
+/// use srgb::analyse::average_colour;
+///
+/// assert_eq!([188, 188, 188], average_colour(&[[0, 0, 0], [255, 255, 255]]));
+/// assert_eq!([212, 33, 61], average_colour(&[[212, 33, 61]]));
+/// ```
+pub fn average_colour(pixels: &[[u8; 3]]) -> [u8; 3] {
+    weighted_average_impl(pixels.iter().map(|rgb| (*rgb, 1.0)))
+}
+
+/// Calculates weighted average colour of a set of 24-bit sRGB colours.
+///
+/// Like [`average_colour`] but each colour has a corresponding weight.
+/// Averaging is done in linear space with Kahan summation.  Returns black if
+/// `pixels` is empty or sum of all weights is zero.
+///
+/// # Panics
+///
+/// Panics if `pixels` and `weights` have different lengths.
+///
+/// # Example
+/// ```
+/// use srgb::analyse::weighted_average_colour;
+///
+/// let pixels = [[0, 0, 0], [255, 255, 255]];
+/// assert_eq!([188, 188, 188], weighted_average_colour(&pixels, &[1.0, 1.0]));
+/// assert_eq!([137, 137, 137], weighted_average_colour(&pixels, &[3.0, 1.0]));
+/// assert_eq!([255, 255, 255], weighted_average_colour(&pixels, &[0.0, 1.0]));
+/// ```
+pub fn weighted_average_colour(pixels: &[[u8; 3]], weights: &[f32]) -> [u8; 3] {
+    assert_eq!(pixels.len(), weights.len());
+    weighted_average_impl(pixels.iter().copied().zip(weights.iter().copied()))
+}
+
+fn weighted_average_impl(
+    pixels: impl Iterator<Item = ([u8; 3], f32)>,
+) -> [u8; 3] {
+    let mut sums = [crate::stats::KahanSum::default(); 3];
+    let mut total = crate::stats::KahanSum::default();
+    for (rgb, weight) in pixels {
+        let linear = crate::gamma::linear_from_u8(rgb);
+        for (sum, value) in sums.iter_mut().zip(linear.iter()) {
+            sum.add(weight * *value);
+        }
+        total.add(weight);
+    }
+    if total.sum == 0.0 {
+        return [0; 3];
+    }
+    crate::gamma::u8_from_linear(crate::arr_map(sums, |sum| {
+        sum.sum / total.sum
+    }))
+}
+
+
+/// Calculates peak signal-to-noise ratio between two images based on
+/// perceptual colour differences.
+///
+/// Rather than using differences of 8-bit component values (as typical PSNR
+/// does), error of each pixel is the Euclidean distance between the colours
+/// in CIELAB colour space (i.e. CIE 1976 colour difference).  The peak value
+/// is 100 which is difference between black and white.  The result is in
+/// decibels with higher values indicating more similar images.  Returns
+/// positive infinity if the images are identical.
+///
+/// See [`colour_psnr_2000()`] for variant which uses more perceptually
+/// uniform CIEDE2000 colour difference.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+///
+/// # Example
+/// ```
+/// use srgb::analyse::colour_psnr;
+///
+/// let a = [[212, 33, 61], [0, 0, 0], [255, 255, 255]];
+/// let b = [[210, 35, 60], [2, 2, 2], [250, 255, 255]];
+/// assert_eq!(f32::INFINITY, colour_psnr(&a, &a));
+/// approx::assert_abs_diff_eq!(38.15, colour_psnr(&a, &b), epsilon = 0.01);
+/// assert_eq!(0.0, colour_psnr(&[[0, 0, 0]], &[[255, 255, 255]]));
+/// ```
+pub fn colour_psnr(a: &[[u8; 3]], b: &[[u8; 3]]) -> f32 {
+    assert_eq!(a.len(), b.len());
+    let sum = a
+        .iter()
+        .zip(b.iter())
+        .filter(|(a, b)| a != b)
+        .map(|(a, b)| {
+            let a = crate::lab::lab_from_u8(*a);
+            let b = crate::lab::lab_from_u8(*b);
+            (0..3).map(|i| ((a[i] - b[i]) as f64).powi(2)).sum::<f64>()
+        })
+        .sum::<f64>();
+    psnr(sum, a.len())
+}
+
+/// Calculates peak signal-to-noise ratio between two images based on
+/// CIEDE2000 colour differences.
+///
+/// Like [`colour_psnr()`] but error of each pixel is the CIEDE2000 colour
+/// difference (see [`crate::lab::delta_e_2000_slice()`]) which better
+/// matches perceived differences.  Returns positive infinity if the images
+/// are identical.
+///
+/// # Panics
+///
+/// Panics if `a` and `b` have different lengths.
+///
+/// # Example
+/// ```
+/// use srgb::analyse::colour_psnr_2000;
+///
+/// let a = [[212, 33, 61], [0, 0, 0], [255, 255, 255]];
+/// let b = [[210, 35, 60], [2, 2, 2], [250, 255, 255]];
+/// assert_eq!(f32::INFINITY, colour_psnr_2000(&a, &a));
+/// approx::assert_abs_diff_eq!(
+///     36.95,
+///     colour_psnr_2000(&a, &b),
+///     epsilon = 0.01
+/// );
+/// ```
+pub fn colour_psnr_2000(a: &[[u8; 3]], b: &[[u8; 3]]) -> f32 {
+    let mut delta_e = vec![0.0; a.len()];
+    crate::lab::delta_e_2000_slice(a, b, &mut delta_e);
+    let sum = delta_e.iter().map(|v| (*v as f64).powi(2)).sum::<f64>();
+    psnr(sum, a.len())
+}
+
+/// Estimates how likely it is that an image had sRGB gamma compression
+/// applied twice.
+///
+/// Returns a score in the range from zero to one where higher values mean
+/// the image is more likely double-encoded (see
+/// [`crate::undo_double_encode()`]).  The heuristic looks at the distribution
+/// of dark component values.  Second encoding stretches the shadows such
+/// that only a few codes below 64 can appear in a double-encoded image with
+/// wide gaps between them (e.g. no value from 1 to 12 is possible).  The
+/// score is high if the dark values present in the image fall only on those
+/// codes and cover most of them.
+///
+/// Images with no dark values provide no evidence and result in zero.  Being
+/// a heuristic, the function may misjudge images with few distinct colours.
+///
+/// # Example
+/// ```
+/// use srgb::analyse::likely_double_encoded;
+/// use srgb::gamma::compress_u8;
+///
+/// let gradient: Vec<[u8; 3]> = (0..=255).map(|v| [v, v, v]).collect();
+/// let double: Vec<[u8; 3]> = gradient
+///     .iter()
+///     .map(|rgb| rgb.map(|v| compress_u8(v as f32 / 255.0)))
+///     .collect();
+/// assert!(likely_double_encoded(&gradient) < 0.1);
+/// assert!(likely_double_encoded(&double) > 0.9);
+/// ```
+pub fn likely_double_encoded(pixels: &[[u8; 3]]) -> f32 {
+    const DARK: usize = 64;
+    let mut possible = [false; DARK];
+    for v in 0..=255u8 {
+        let code = usize::from(crate::gamma::compress_u8(v as f32 / 255.0));
+        if let Some(possible) = possible.get_mut(code) {
+            *possible = true;
+        }
+    }
+    let mut used = [false; DARK];
+    for value in pixels.iter().flatten() {
+        if let Some(used) = used.get_mut(usize::from(*value)) {
+            *used = true;
+        }
+    }
+    // Black is possible in either case so it provides no information.
+    let (mut hits, mut misses, mut possible_count) = (0, 0, 0);
+    for (used, possible) in used.iter().zip(possible.iter()).skip(1) {
+        possible_count += usize::from(*possible);
+        match (*used, *possible) {
+            (true, true) => hits += 1,
+            (true, false) => misses += 1,
+            (false, _) => (),
+        }
+    }
+    if hits + misses == 0 {
+        return 0.0;
+    }
+    // Fraction of used dark codes which are possible in a double-encoded
+    // image rescaled so that uniform use of all codes results in zero.
+    let base = possible_count as f32 / (DARK - 1) as f32;
+    let ratio = hits as f32 / (hits + misses) as f32;
+    let purity = ((ratio - base) / (1.0 - base)).max(0.0);
+    // Confidence grows with number of possible codes actually used.
+    let coverage = hits as f32 / possible_count as f32;
+    purity * coverage
+}
+
+/// Calculates PSNR given sum of squared errors of `count` pixels with peak
+/// value of 100.
+fn psnr(sum: f64, count: usize) -> f32 {
+    if sum == 0.0 {
+        return f32::INFINITY;
+    }
+    let mse = sum / count as f64;
+    (10.0 * (100.0 * 100.0 / mse).log10()) as f32
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_double_encoded() {
+        let double = |v: u8| crate::gamma::compress_u8(v as f32 / 255.0);
+        let gradient: Vec<[u8; 3]> = (0..=255).map(|v| [v, v, v]).collect();
+        let encoded: Vec<[u8; 3]> =
+            gradient.iter().map(|rgb| rgb.map(double)).collect();
+
+        for (want, got) in gradient.iter().zip(encoded.iter()) {
+            let got = crate::undo_double_encode(*got);
+            for (want, got) in want.iter().zip(got.iter()) {
+                assert!(want.abs_diff(*got) <= 1, "{} ≠ {}", want, got);
+            }
+        }
+
+        assert_eq!(1.0, likely_double_encoded(&encoded));
+        assert_eq!(0.0, likely_double_encoded(&gradient));
+        assert_eq!(0.0, likely_double_encoded(&[]));
+        assert_eq!(0.0, likely_double_encoded(&[[0, 0, 0], [255, 255, 255]]));
+    }
+
+    #[test]
+    fn test_min_perceptible_step() {
+        let delta_e = |a: [u8; 3], b: [u8; 3]| {
+            crate::delta::delta_e_2000(
+                crate::lab::lab_from_u8(a),
+                crate::lab::lab_from_u8(b),
+            )
+        };
+        for c in (0..(1 << 24)).step_by(99991) {
+            let rgb = crate::from_u32(c);
+            let steps = min_perceptible_step(rgb);
+            for (channel, step) in steps.iter().enumerate() {
+                // Changes smaller than the step are not perceptible while
+                // change by the step is in at least one direction.
+                let changed = |delta: i32| {
+                    let value = i32::from(rgb[channel]) + delta;
+                    u8::try_from(value).ok().map(|value| {
+                        let mut other = rgb;
+                        other[channel] = value;
+                        delta_e(rgb, other)
+                    })
+                };
+                let step = i32::from(*step);
+                for delta in 1..step {
+                    for delta in [delta, -delta] {
+                        let got = changed(delta).unwrap_or(0.0);
+                        assert!(got < 1.0, "{:?} {} {}", rgb, channel, delta);
+                    }
+                }
+                let got = changed(step)
+                    .unwrap_or(0.0)
+                    .max(changed(-step).unwrap_or(0.0));
+                assert!(got >= 1.0, "{:?} {} {}", rgb, channel, step);
+            }
+        }
+    }
+
+    #[test]
+    fn test_greys_and_saturated() {
+        // Greys are sensitive to changes of any channel.
+        for v in 0..=255 {
+            let steps = min_perceptible_step([v, v, v]);
+            assert!(steps.iter().all(|step| *step <= 3), "{} {:?}", v, steps);
+        }
+        // Saturated colours tolerate larger changes of weak channels.
+        let [_, g, _] = min_perceptible_step([212, 33, 61]);
+        let [_, grey_g, _] = min_perceptible_step([128, 128, 128]);
+        assert!(g > 2 * grey_g, "{} {}", g, grey_g);
+    }
+
+    #[test]
+    fn test_average_colour() {
+        assert_eq!([0, 0, 0], average_colour(&[]));
+        assert_eq!([0, 0, 0], weighted_average_colour(&[[255; 3]], &[0.0]));
+
+        // Black and white 50/50 image averages to linear mean, not to 128.
+        let mut image = vec![[0, 0, 0]; 5000];
+        image.resize(10000, [255, 255, 255]);
+        assert_eq!([188, 188, 188], average_colour(&image));
+
+        for c in (0..(1 << 24)).step_by(99991) {
+            let rgb = crate::from_u32(c);
+            assert_eq!(rgb, average_colour(&[rgb; 7]));
+        }
+
+        let pixels = [[212, 33, 61], [0, 128, 255], [17, 17, 17]];
+        let weights = [2.0, 1.0, 1.0];
+        let repeated = [pixels[0], pixels[0], pixels[1], pixels[2]];
+        assert_eq!(
+            average_colour(&repeated),
+            weighted_average_colour(&pixels, &weights)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_weighted_average_colour_length_mismatch() {
+        weighted_average_colour(&[[0; 3]], &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_colour_psnr() {
+        let image =
+            (0..4096).map(|c| crate::from_u32(c * 4093)).collect::<Vec<_>>();
+        assert_eq!(f32::INFINITY, colour_psnr(&image, &image));
+        assert_eq!(f32::INFINITY, colour_psnr_2000(&image, &image));
+        assert_eq!(f32::INFINITY, colour_psnr(&[], &[]));
+
+        let black_white = ([[0; 3]], [[255; 3]]);
+        assert_eq!(0.0, colour_psnr(&black_white.0, &black_white.1));
+        assert_eq!(0.0, colour_psnr_2000(&black_white.0, &black_white.1));
+
+        // More noise results in smaller PSNR.
+        let mut prev = (f32::INFINITY, f32::INFINITY);
+        for noise in [1, 2, 4, 8, 16] {
+            let degraded = image
+                .iter()
+                .enumerate()
+                .map(|(i, rgb)| {
+                    rgb.map(|v| {
+                        if i % 2 == 0 {
+                            v.saturating_add(noise)
+                        } else {
+                            v.saturating_sub(noise)
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            let got = (
+                colour_psnr(&image, &degraded),
+                colour_psnr_2000(&image, &degraded),
+            );
+            assert!(
+                got.0.is_finite() && got.1.is_finite(),
+                "{}: {:?}",
+                noise,
+                got
+            );
+            assert!(got.0 > 10.0 && got.1 > 10.0, "{}: {:?}", noise, got);
+            assert!(
+                got.0 < prev.0 && got.1 < prev.1,
+                "{}: {:?} {:?}",
+                noise,
+                got,
+                prev
+            );
+            prev = got;
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_colour_psnr_length_mismatch() { colour_psnr(&[[0; 3]], &[]); }
+}
@@ -0,0 +1,148 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for zero-copy reinterpretation of raw buffers as slices of
+//! colours.
+//!
+//! Graphics APIs commonly expose framebuffers as flat slices of bytes or
+//! floats.  Functions in this module view such buffers as slices of colour
+//! triples which can be passed to other functions in this crate (and back)
+//! without copying.  Casting is done with [`bytemuck`] and follows its rules:
+//! the length of the buffer must be a multiple of the size of a colour and,
+//! where applicable, the buffer must be suitably aligned.
+//!
+//! This module is available with the `bytemuck` feature.
+
+pub use bytemuck::PodCastError;
+
+/// Reinterprets a flat buffer of bytes as a slice of 24-bit sRGB colours.
+///
+/// Returns an error if length of the buffer is not a multiple of three.
+///
+/// # Example
+/// ```
+/// use srgb::buffer::as_triples;
+///
+/// let bytes = [212, 33, 61, 233, 232, 231];
+/// assert_eq!(Ok(&[[212, 33, 61], [233, 232, 231]][..]), as_triples(&bytes));
+/// assert!(as_triples(&bytes[..5]).is_err());
+/// ```
+pub fn as_triples(bytes: &[u8]) -> Result<&[[u8; 3]], PodCastError> {
+    bytemuck::try_cast_slice(bytes)
+}
+
+/// Reinterprets a mutable flat buffer of bytes as a slice of 24-bit sRGB
+/// colours.
+///
+/// Returns an error if length of the buffer is not a multiple of three.
+///
+/// # Example
+/// ```
+/// use srgb::buffer::as_triples_mut;
+///
+/// let mut bytes = [0; 6];
+/// as_triples_mut(&mut bytes).unwrap()[1] = [212, 33, 61];
+/// assert_eq!([0, 0, 0, 212, 33, 61], bytes);
+/// ```
+pub fn as_triples_mut(
+    bytes: &mut [u8],
+) -> Result<&mut [[u8; 3]], PodCastError> {
+    bytemuck::try_cast_slice_mut(bytes)
+}
+
+/// Reinterprets a slice of 24-bit sRGB colours as a flat buffer of bytes.
+///
+/// This is an inverse of [`as_triples()`] and it never fails.
+///
+/// # Example
+/// ```
+/// use srgb::buffer::as_bytes;
+///
+/// let colours = [[212, 33, 61], [233, 232, 231]];
+/// assert_eq!(&[212, 33, 61, 233, 232, 231], as_bytes(&colours));
+/// ```
+pub fn as_bytes(triples: &[[u8; 3]]) -> &[u8] { bytemuck::cast_slice(triples) }
+
+/// Reinterprets a flat buffer of bytes as a slice of colours with `f32`
+/// components, e.g. linear sRGB or XYZ.
+///
+/// Returns an error if length of the buffer is not a multiple of twelve or if
+/// the buffer is not aligned to four bytes.  Components are interpreted in
+/// platform’s native endianness.
+///
+/// # Example
+/// ```
+/// use srgb::buffer::as_f32_triples;
+///
+/// let floats = [0.5f32, 0.25, 1.0];
+/// let bytes: &[u8] = bytemuck::cast_slice(&floats);
+/// assert_eq!(Ok(&[[0.5, 0.25, 1.0]][..]), as_f32_triples(bytes));
+/// ```
+pub fn as_f32_triples(bytes: &[u8]) -> Result<&[[f32; 3]], PodCastError> {
+    bytemuck::try_cast_slice(bytes)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_u8_round_trip() {
+        let bytes = (0..=254).collect::<Vec<u8>>();
+        let triples = as_triples(&bytes).unwrap();
+        assert_eq!(85, triples.len());
+        assert_eq!([0, 1, 2], triples[0]);
+        assert_eq!([252, 253, 254], triples[84]);
+        assert_eq!(&bytes[..], as_bytes(triples));
+
+        assert_eq!(Ok(&[][..]), as_triples(&[]));
+        for len in [1, 2, 4, 5, 254] {
+            assert_eq!(
+                Err(PodCastError::OutputSliceWouldHaveSlop),
+                as_triples(&bytes[..len]),
+                "{}",
+                len
+            );
+        }
+    }
+
+    #[test]
+    fn test_u8_mut() {
+        let mut bytes = [0; 9];
+        let triples = as_triples_mut(&mut bytes).unwrap();
+        triples[2] = crate::from_u32(0xd4213d);
+        assert_eq!([0, 0, 0, 0, 0, 0, 212, 33, 61], bytes);
+        assert!(as_triples_mut(&mut bytes[1..]).is_err());
+    }
+
+    #[test]
+    fn test_f32() {
+        let floats = [0.0f32, 0.25, 0.5, 0.75, 1.0, 0.125];
+        let bytes: &[u8] = bytemuck::cast_slice(&floats);
+        assert_eq!(
+            Ok(&[[0.0, 0.25, 0.5], [0.75, 1.0, 0.125]][..]),
+            as_f32_triples(bytes)
+        );
+        assert_eq!(
+            Err(PodCastError::OutputSliceWouldHaveSlop),
+            as_f32_triples(&bytes[..12 + 4])
+        );
+        assert_eq!(
+            Err(PodCastError::TargetAlignmentGreaterAndInputNotAligned),
+            as_f32_triples(&bytes[1..13])
+        );
+    }
+}
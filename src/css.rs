@@ -0,0 +1,550 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Parsing and serialisation of colours in [CSS Color Module Level
+//! 4](https://www.w3.org/TR/css-color-4/) syntax.
+//!
+//! [`parse()`] accepts hexadecimal notation as well as `rgb()`, `rgba()`,
+//! `hsl()`, `hsla()`, `hwb()`, `lab()`, `lch()`, `oklab()` and `oklch()`
+//! functions.  Both the modern space-separated syntax (with optional alpha
+//! following a slash and `none` keyword for missing components) and the
+//! legacy comma-separated syntax of `rgb()` and `hsl()` are supported.
+//!
+//! The result is kept in the notation it was written in so that formatting
+//! a [`ParsedColour`] (with [`std::fmt::Display`]) produces a string which
+//! parses back into the same value.  [`ParsedColour::to_srgb_u8()`] converts
+//! the colour into a 24-bit sRGB colour.
+//!
+//! As per the specification, `lab()` and `lch()` use the D50 white point and
+//! colours are chromatically adapted to D65 with the Bradford transform before
+//! being converted to sRGB.  Note that this differs from the [`crate::lab`]
+//! module which uses D65 white point.
+
+/// Notation (i.e. CSS function) a colour was specified in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// `rgb()` function or hexadecimal notation.  Components are in the range
+    /// 0–255.
+    Rgb,
+    /// `hsl()` function.  Components are hue in degrees and saturation and
+    /// lightness as percentages.
+    Hsl,
+    /// `hwb()` function.  Components are hue in degrees and whiteness and
+    /// blackness as percentages.
+    Hwb,
+    /// `lab()` function.  Components are CIELAB `L*`, `a*` and `b*`
+    /// coordinates relative to D50 white point.
+    Lab,
+    /// `lch()` function.  Components are CIELCh lightness, chroma and hue in
+    /// degrees relative to D50 white point.
+    Lch,
+    /// `oklab()` function.  Components are Oklab `L`, `a` and `b` coordinates.
+    Oklab,
+    /// `oklch()` function.  Components are Oklch lightness, chroma and hue in
+    /// degrees.
+    Oklch,
+}
+
+/// A colour parsed from its CSS representation.
+///
+/// Components are stored as used by the [`Notation`] with percentages already
+/// resolved.  Missing components (specified with `none` keyword) are
+/// represented by `None` and are treated as zero when converting the colour.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ParsedColour {
+    /// Notation the colour was specified in.
+    pub notation: Notation,
+    /// Components of the colour.
+    pub components: [Option<f32>; 3],
+    /// Opacity of the colour in the range from zero to one.
+    pub alpha: Option<f32>,
+}
+
+/// Parses a colour in CSS syntax.
+///
+/// Returns `None` if the string isn’t a valid colour in one of the supported
+/// notations.  Named colours, `color()` function and relative colour syntax
+/// aren’t supported.
+///
+/// # Example
+/// ```
+/// use srgb::css::{parse, Notation};
+///
+/// let colour = parse("oklch(0.7 0.15 30)").unwrap();
+/// assert_eq!(Notation::Oklch, colour.notation);
+/// assert_eq!([Some(0.7), Some(0.15), Some(30.0)], colour.components);
+/// assert_eq!([237, 118, 101], colour.to_srgb_u8());
+///
+/// let colour = parse("rgb(212 33 none / 50%)").unwrap();
+/// assert_eq!([Some(212.0), Some(33.0), None], colour.components);
+/// assert_eq!(Some(0.5), colour.alpha);
+///
+/// assert_eq!(parse("rgb(212, 33, 61)"), parse("#d4213d"));
+/// assert_eq!(None, parse("rgb(212 33)"));
+/// ```
+pub fn parse(css: &str) -> Option<ParsedColour> {
+    let css = css.trim();
+    if let Some(hex) = css.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+
+    let (name, args) = css.strip_suffix(')')?.split_once('(')?;
+    let name = name.to_ascii_lowercase();
+    let (notation, legacy_allowed) = match name.as_str() {
+        "rgb" | "rgba" => (Notation::Rgb, true),
+        "hsl" | "hsla" => (Notation::Hsl, true),
+        "hwb" => (Notation::Hwb, false),
+        "lab" => (Notation::Lab, false),
+        "lch" => (Notation::Lch, false),
+        "oklab" => (Notation::Oklab, false),
+        "oklch" => (Notation::Oklch, false),
+        _ => return None,
+    };
+
+    let mut values = [Value::None; 4];
+    let legacy = args.contains(',');
+    let count = if legacy {
+        if !legacy_allowed {
+            return None;
+        }
+        tokenise(args.split(',').map(str::trim), &mut values)?
+    } else {
+        let args = args.replace('/', " / ");
+        let mut tokens = args.split_whitespace();
+        let count = tokenise(tokens.by_ref().take(3), &mut values)?;
+        match (tokens.next(), tokens.next(), tokens.next()) {
+            (None, ..) => count,
+            (Some("/"), Some(alpha), None) if count == 3 => {
+                values[3] = parse_value(alpha)?;
+                4
+            }
+            _ => return None,
+        }
+    };
+    if count < 3 {
+        return None;
+    }
+
+    if legacy {
+        let values = &values[..count];
+        if values.contains(&Value::None) {
+            return None;
+        }
+        let is_percentage = |v: &Value| matches!(v, Value::Percentage(_));
+        match notation {
+            // All components must be either numbers or percentages.
+            Notation::Rgb => {
+                let percentage = is_percentage(&values[0]);
+                if values[1..3].iter().any(|v| is_percentage(v) != percentage) {
+                    return None;
+                }
+            }
+            // Saturation and lightness must be percentages.
+            _ => {
+                if !values[1..3].iter().all(is_percentage) {
+                    return None;
+                }
+            }
+        }
+    }
+
+    let kinds = notation.components();
+    Some(ParsedColour {
+        notation,
+        components: [
+            kinds[0].resolve(values[0])?,
+            kinds[1].resolve(values[1])?,
+            kinds[2].resolve(values[2])?,
+        ],
+        alpha: if count == 4 { ALPHA.resolve(values[3])? } else { Some(1.0) },
+    })
+}
+
+/// Parses hexadecimal notation with the leading hash already stripped.
+fn parse_hex(hex: &str) -> Option<ParsedColour> {
+    let digits = hex.as_bytes();
+    if !digits.iter().all(u8::is_ascii_hexdigit) {
+        return None;
+    }
+    let digit = |idx: usize| (digits[idx] as char).to_digit(16).unwrap() as u8;
+    let mut rgba = [255; 4];
+    match digits.len() {
+        3 | 4 => {
+            for (idx, value) in rgba.iter_mut().take(digits.len()).enumerate() {
+                *value = digit(idx) * 17;
+            }
+        }
+        6 | 8 => {
+            let len = digits.len() / 2;
+            for (idx, value) in rgba.iter_mut().take(len).enumerate() {
+                *value = digit(idx * 2) * 16 + digit(idx * 2 + 1);
+            }
+        }
+        _ => return None,
+    }
+    let [r, g, b, a] = rgba;
+    Some(ParsedColour {
+        notation: Notation::Rgb,
+        components: [Some(r as f32), Some(g as f32), Some(b as f32)],
+        alpha: Some(a as f32 / 255.0),
+    })
+}
+
+/// Parses tokens into `values` returning number of parsed values or `None`
+/// if any of the tokens is invalid or there are too many of them.
+fn tokenise<'a>(
+    tokens: impl Iterator<Item = &'a str>,
+    values: &mut [Value; 4],
+) -> Option<usize> {
+    let mut count = 0;
+    for token in tokens {
+        *values.get_mut(count)? = parse_value(token)?;
+        count += 1;
+    }
+    Some(count)
+}
+
+
+/// A single CSS value given as colour component.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Value {
+    None,
+    Number(f32),
+    Percentage(f32),
+    /// Angle converted to degrees.
+    Angle(f32),
+}
+
+/// Parses a CSS number, percentage, angle or `none` keyword.
+fn parse_value(token: &str) -> Option<Value> {
+    if token.eq_ignore_ascii_case("none") {
+        return Some(Value::None);
+    }
+    let number = token
+        .trim_end_matches(|ch: char| ch.is_ascii_alphabetic() || ch == '%');
+    let unit = token[number.len()..].to_ascii_lowercase();
+    let valid = |ch: char| ch.is_ascii_digit() || "+-.eE".contains(ch);
+    if !number.starts_with(|ch: char| ch != 'e' && ch != 'E') ||
+        !number.chars().all(valid)
+    {
+        return None;
+    }
+    let number = number.parse::<f32>().ok().filter(|n| n.is_finite())?;
+    Some(match unit.as_str() {
+        "" => Value::Number(number),
+        "%" => Value::Percentage(number),
+        "deg" => Value::Angle(number),
+        "grad" => Value::Angle(number * 0.9),
+        "rad" => Value::Angle(number.to_degrees()),
+        "turn" => Value::Angle(number * 360.0),
+        _ => return None,
+    })
+}
+
+
+/// Describes how a colour component is interpreted.
+#[derive(Clone, Copy)]
+enum Component {
+    /// Hue given as a number of degrees or an angle.
+    Hue,
+    /// Number or percentage (where `percent` is the value of 1%) clamped to
+    /// the range from `min` to `max`.
+    Number { percent: f32, min: f32, max: f32 },
+}
+
+/// Opacity of a colour.
+const ALPHA: Component =
+    Component::Number { percent: 0.01, min: 0.0, max: 1.0 };
+
+impl Component {
+    const fn new(percent: f32, min: f32, max: f32) -> Self {
+        Self::Number { percent, min, max }
+    }
+
+    /// Resolves value into a number; returns `None` if the value isn’t valid
+    /// for the component and `Some(None)` for `none` keyword.
+    fn resolve(self, value: Value) -> Option<Option<f32>> {
+        Some(Some(match (self, value) {
+            (_, Value::None) => return Some(None),
+            (Self::Hue, Value::Number(deg) | Value::Angle(deg)) => deg,
+            (Self::Number { min, max, .. }, Value::Number(num)) => {
+                num.clamp(min, max)
+            }
+            (Self::Number { percent, min, max }, Value::Percentage(num)) => {
+                (num * percent).clamp(min, max)
+            }
+            _ => return None,
+        }))
+    }
+}
+
+impl Notation {
+    /// Returns name of the CSS function corresponding to the notation.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Rgb => "rgb",
+            Self::Hsl => "hsl",
+            Self::Hwb => "hwb",
+            Self::Lab => "lab",
+            Self::Lch => "lch",
+            Self::Oklab => "oklab",
+            Self::Oklch => "oklch",
+        }
+    }
+
+    /// Returns description of components of the notation.
+    fn components(self) -> [Component; 3] {
+        const INF: f32 = f32::INFINITY;
+        const RGB: Component = Component::new(2.55, 0.0, 255.0);
+        const AB: Component = Component::new(1.25, -INF, INF);
+        const OK_AB: Component = Component::new(0.004, -INF, INF);
+        match self {
+            Self::Rgb => [RGB, RGB, RGB],
+            Self::Hsl => [
+                Component::Hue,
+                Component::new(1.0, 0.0, INF),
+                Component::new(1.0, -INF, INF),
+            ],
+            Self::Hwb => [
+                Component::Hue,
+                Component::new(1.0, -INF, INF),
+                Component::new(1.0, -INF, INF),
+            ],
+            Self::Lab => [Component::new(1.0, 0.0, 100.0), AB, AB],
+            Self::Lch => [
+                Component::new(1.0, 0.0, 100.0),
+                Component::new(1.5, 0.0, INF),
+                Component::Hue,
+            ],
+            Self::Oklab => [Component::new(0.01, 0.0, 1.0), OK_AB, OK_AB],
+            Self::Oklch => [
+                Component::new(0.01, 0.0, 1.0),
+                Component::new(0.004, 0.0, INF),
+                Component::Hue,
+            ],
+        }
+    }
+}
+
+
+impl ParsedColour {
+    /// Converts the colour into a 24-bit sRGB colour ignoring its opacity.
+    ///
+    /// Missing components are treated as zero.  Colours outside of the sRGB
+    /// gamut are clipped; see [`crate::gamut`] module for alternatives.
+    ///
+    /// # Example
+    /// ```
+    /// use srgb::css::parse;
+    ///
+    /// let rgb = |css| parse(css).unwrap().to_srgb_u8();
+    /// assert_eq!([212, 33, 61], rgb("#d4213d"));
+    /// assert_eq!([0, 128, 0], rgb("hsl(120deg 100% 25%)"));
+    /// assert_eq!([255, 0, 0], rgb("lch(54.29 106.84 40.85)"));
+    /// ```
+    pub fn to_srgb_u8(&self) -> [u8; 3] {
+        let [c0, c1, c2] = self.components.map(|c| c.unwrap_or(0.0));
+        match self.notation {
+            Notation::Rgb => {
+                [c0, c1, c2].map(|v| v.clamp(0.0, 255.0).round() as u8)
+            }
+            Notation::Hsl => {
+                crate::hsl::u8_from_hsl([c0, c1 / 100.0, c2 / 100.0])
+            }
+            Notation::Hwb => {
+                crate::hwb::u8_from_hwb([c0, c1 / 100.0, c2 / 100.0])
+            }
+            Notation::Lab => u8_from_lab_d50([c0, c1, c2]),
+            Notation::Lch => {
+                let (sin, cos) = c2.to_radians().sin_cos();
+                u8_from_lab_d50([c0, c1 * cos, c1 * sin])
+            }
+            Notation::Oklab => crate::oklab::u8_from_oklab([c0, c1, c2]),
+            Notation::Oklch => crate::oklab::u8_from_oklab(
+                crate::oklab::oklab_from_oklch_deg([c0, c1, c2]),
+            ),
+        }
+    }
+
+    /// Converts the colour into a 24-bit sRGB colour with 8-bit alpha channel.
+    ///
+    /// Missing alpha is treated as fully transparent.
+    ///
+    /// # Example
+    /// ```
+    /// let colour = srgb::css::parse("hwb(0 0% 0% / 0.5)").unwrap();
+    /// assert_eq!([255, 0, 0, 128], colour.to_srgba_u8());
+    /// ```
+    pub fn to_srgba_u8(&self) -> [u8; 4] {
+        let [r, g, b] = self.to_srgb_u8();
+        let alpha = self.alpha.unwrap_or(0.0).clamp(0.0, 1.0);
+        [r, g, b, (alpha * 255.0).round() as u8]
+    }
+}
+
+/// Formats the colour in its notation using modern CSS syntax.
+///
+/// # Example
+/// ```
+/// let colour = srgb::css::parse("hsla(120, 100%, 25%, 50%)").unwrap();
+/// assert_eq!("hsl(120 100% 25% / 0.5)", colour.to_string());
+/// assert_eq!(Some(colour), srgb::css::parse(&colour.to_string()));
+/// ```
+impl core::fmt::Display for ParsedColour {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        let percentages =
+            matches!(self.notation, Notation::Hsl | Notation::Hwb);
+        fmtr.write_str(self.notation.name())?;
+        for (idx, component) in self.components.iter().enumerate() {
+            fmtr.write_str(if idx == 0 { "(" } else { " " })?;
+            match component {
+                None => fmtr.write_str("none")?,
+                Some(value) if percentages && idx > 0 => {
+                    write!(fmtr, "{}%", value)?
+                }
+                Some(value) => write!(fmtr, "{}", value)?,
+            }
+        }
+        match self.alpha {
+            Some(alpha) if alpha != 1.0 => write!(fmtr, " / {})", alpha),
+            Some(_) => fmtr.write_str(")"),
+            None => fmtr.write_str(" / none)"),
+        }
+    }
+}
+
+
+/// Converts CIELAB colour relative to D50 white point into 24-bit sRGB.
+fn u8_from_lab_d50(lab: [f32; 3]) -> [u8; 3] {
+    const D50: [f32; 3] =
+        [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+    let xyz = crate::lab::xyz_from_lab_wp(lab, D50);
+    crate::u8_from_xyz(crate::maths::matrix_product(&D65_FROM_D50, xyz))
+}
+
+/// Bradford chromatic adaptation matrix from D50 to D65 white point as given
+/// in CSS Color Module Level 4.
+const D65_FROM_D50: [[f32; 3]; 3] = [
+    [0.955473421488075, -0.02309845494876471, 0.06325924320057072],
+    [-0.0283697093338637, 1.0099953980813041, 0.021041441191917323],
+    [0.012314014864481998, -0.020507649298898964, 1.330365926242124],
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[track_caller]
+    fn check(want: [u8; 3], css: &str) {
+        let colour = parse(css).unwrap_or_else(|| panic!("{}", css));
+        assert_eq!(want, colour.to_srgb_u8(), "{}", css);
+        let serialised = colour.to_string();
+        assert_eq!(Some(colour), parse(&serialised), "{}", serialised);
+    }
+
+    #[test]
+    fn test_examples() {
+        // Examples from CSS Color Module Level 4 specification and values of
+        // sRGB red and green in other colour spaces.
+        check([0, 255, 0], "#0f0");
+        check([0, 255, 0], "#0F0F");
+        check([0, 255, 0], "#00ff00");
+        check([0, 255, 0], "#00ff00ff");
+        check([0, 255, 0], "rgb(0 255 0)");
+        check([0, 255, 0], "rgb(0% 100% 0%)");
+        check([0, 255, 0], "rgb(0, 255, 0)");
+        check([0, 255, 0], "rgba(0, 255, 0, 0.5)");
+        check([0, 255, 0], "RGB(none 255 0 / 50%)");
+        check([0, 255, 0], "hsl(120deg 100% 50%)");
+        check([0, 255, 0], "hsl(120 100 50)");
+        check([0, 255, 0], "hsla(120, 100%, 50%, 1)");
+        check([0, 255, 0], "hsl(0.3333turn 100% 50%)");
+        check([0, 128, 0], "hsl(120deg 100% 25%)");
+        check([0, 128, 0], "hwb(120 0% 49.8%)");
+        check([153, 153, 153], "hwb(120 60% 40%)");
+        check([255, 0, 0], "hwb(0 0% 0%)");
+        check([255, 0, 0], "lab(54.29 80.80 69.89)");
+        check([255, 0, 0], "lch(54.29% 106.84 40.85)");
+        check([0, 128, 0], "lab(46.28% -47.55 48.59)");
+        check([255, 0, 0], "oklab(0.628 0.2249 0.1258)");
+        check([255, 0, 0], "oklch(62.8% 0.2577 29.23deg)");
+        check([255, 0, 0], "oklch(0.628 64.425% 0.5101rad)");
+        check([255, 255, 255], "lab(100 0 0)");
+        check([255, 255, 255], "oklch(1 0 none)");
+        check([0, 0, 0], "lch(none none none / none)");
+    }
+
+    #[test]
+    fn test_alpha() {
+        let alpha = |css| parse(css).unwrap().alpha;
+        assert_eq!(Some(1.0), alpha("rgb(1 2 3)"));
+        assert_eq!(Some(0.25), alpha("rgb(1 2 3/0.25)"));
+        assert_eq!(Some(0.25), alpha("rgb(1 2 3 / 25%)"));
+        assert_eq!(Some(1.0), alpha("rgb(1 2 3 / 2)"));
+        assert_eq!(Some(0.0), alpha("rgba(1, 2, 3, -1)"));
+        assert_eq!(Some(0.2), alpha("#01020333"));
+        assert_eq!(None, alpha("rgb(1 2 3 / none)"));
+    }
+
+    #[test]
+    fn test_clamping() {
+        let components = |css| parse(css).unwrap().components;
+        let some = |c: [f32; 3]| c.map(Some);
+        assert_eq!(some([255.0, 0.0, 127.5]), components("rgb(300 -5 50%)"));
+        assert_eq!(some([100.0, -200.0, 0.0]), components("lab(120 -200 0)"));
+        assert_eq!(some([0.0, 0.0, 400.0]), components("oklch(-1 -1 400)"));
+        assert_eq!(some([-30.0, 0.0, 50.0]), components("hsl(-30 -5% 50%)"));
+    }
+
+    #[test]
+    fn test_invalid() {
+        for css in [
+            "",
+            "#",
+            "#12",
+            "#12345",
+            "#1234567",
+            "#12345g",
+            "rgb",
+            "rgb(",
+            "rgb()",
+            "rgb(1 2)",
+            "rgb(1 2 3 4)",
+            "rgb(1 2 3 /)",
+            "rgb(1 2 / 3)",
+            "rgb(1 2 3 / 4 5)",
+            "rgb(1, 2 3)",
+            "rgb(1, 2, 3, 4, 5)",
+            "rgb(1, 2%, 3)",
+            "rgb(none, 2, 3)",
+            "rgb(1 2 3deg)",
+            "rgb(1 2 3px)",
+            "rgb(1 2 e3)",
+            "rgb(1 2 inf)",
+            "rgb(1 2 nan)",
+            "rgb(1 2 3",
+            "rgba 1 2 3)",
+            "hsl(120, 100, 50)",
+            "hsl(120% 100% 50%)",
+            "hwb(120, 0%, 0%)",
+            "lab(50, 0, 0)",
+            "lch(50 0 0%)",
+            "cmyk(1 2 3)",
+        ] {
+            assert_eq!(None, parse(css), "{}", css);
+        }
+    }
+}
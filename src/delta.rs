@@ -0,0 +1,204 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions calculating colour difference between colours in CIELAB colour
+//! space: simple Euclidean distance (CIE76) and CIEDE2000.
+//!
+//! Colours are given as `[L*, a*, b*]` triples as returned by functions in
+//! [`crate::lab`] module, i.e. with lightness in the range from zero to
+//! a hundred and signed a* and b* coordinates.
+
+/// Calculates CIE76 colour difference between two colours in CIELAB colour
+/// space.
+///
+/// CIE76 is simply the Euclidean distance between the colours.  Difference of
+/// about 2.3 corresponds to a just noticeable difference, however the formula
+/// isn’t perceptually uniform and overestimates differences in saturated
+/// colours.  See [`delta_e_2000()`] for a more accurate metric.
+///
+/// # Example
+/// ```
+/// use srgb::delta::delta_e_76;
+/// use srgb::lab::lab_from_u8;
+///
+/// let a = lab_from_u8([212, 33, 61]);
+/// assert!(delta_e_76(a, lab_from_u8([212, 33, 62])) < 1.0);
+/// assert!(delta_e_76(a, lab_from_u8([33, 61, 212])) > 50.0);
+/// assert_eq!(5.0, delta_e_76([50.0, 3.0, 0.0], [50.0, 0.0, 4.0]));
+/// ```
+pub fn delta_e_76(
+    lab_a: impl Into<[f32; 3]>,
+    lab_b: impl Into<[f32; 3]>,
+) -> f32 {
+    let ([l1, a1, b1], [l2, a2, b2]) = (lab_a.into(), lab_b.into());
+    let (dl, da, db) = (l2 - l1, a2 - a1, b2 - b1);
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+
+/// Calculates CIEDE2000 colour difference between two colours in CIELAB
+/// colour space.
+///
+/// Difference of about one is the smallest difference a human observer can
+/// notice.  Calculations are done in double precision.
+///
+/// # Example
+/// ```
+/// use srgb::delta::delta_e_2000;
+///
+/// let got = delta_e_2000([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485]);
+/// approx::assert_abs_diff_eq!(2.0425, got, epsilon = 0.0001);
+/// ```
+pub fn delta_e_2000(
+    lab1: impl Into<[f32; 3]>,
+    lab2: impl Into<[f32; 3]>,
+) -> f32 {
+    let [l1, a1, b1] = lab1.into().map(f64::from);
+    let [l2, a2, b2] = lab2.into().map(f64::from);
+
+    let pow7 = |v: f64| v.powi(7);
+    const POW25_7: f64 = 6103515625.0;
+
+    let c_bar = (a1.hypot(b1) + a2.hypot(b2)) * 0.5;
+    let g = 0.5 * (1.0 - (pow7(c_bar) / (pow7(c_bar) + POW25_7)).sqrt());
+    let (a1, a2) = ((1.0 + g) * a1, (1.0 + g) * a2);
+    let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+    let hue = |a: f64, b: f64| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+    };
+    let (h1, h2) = (hue(a1, b1), hue(a2, b2));
+
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else if h2 - h1 < -180.0 {
+        h2 - h1 + 360.0
+    } else {
+        h2 - h1
+    };
+    let dh = 2.0 * (c1 * c2).sqrt() * (dh * 0.5).to_radians().sin();
+
+    let l_bar = (l1 + l2) * 0.5;
+    let c_bar = (c1 + c2) * 0.5;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) * 0.5
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) * 0.5
+    } else {
+        (h1 + h2 - 360.0) * 0.5
+    };
+
+    let cos = |deg: f64| deg.to_radians().cos();
+    let t = 1.0 - 0.17 * cos(h_bar - 30.0) +
+        0.24 * cos(2.0 * h_bar) +
+        0.32 * cos(3.0 * h_bar + 6.0) -
+        0.20 * cos(4.0 * h_bar - 63.0);
+    let d_theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+    let rc = 2.0 * (pow7(c_bar) / (pow7(c_bar) + POW25_7)).sqrt();
+    let l50 = (l_bar - 50.0).powi(2);
+    let sl = 1.0 + 0.015 * l50 / (20.0 + l50).sqrt();
+    let sc = 1.0 + 0.045 * c_bar;
+    let sh = 1.0 + 0.015 * c_bar * t;
+    let rt = -(2.0 * d_theta).to_radians().sin() * rc;
+
+    let (l, c, h) = (dl / sl, dc / sc, dh / sh);
+    (l * l + c * c + h * h + rt * c * h).sqrt() as f32
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_delta_e_2000() {
+        // Test data from Sharma, Wu and Dalal, ‘The CIEDE2000 Color-Difference
+        // Formula: Implementation Notes, Supplementary Test Data, and
+        // Mathematical Observations’.
+        const CASES: [([f32; 3], [f32; 3], f32); 34] = [
+            ([50.0, 2.6772, -79.7751], [50.0, 0.0, -82.7485], 2.0425),
+            ([50.0, 3.1571, -77.2803], [50.0, 0.0, -82.7485], 2.8615),
+            ([50.0, 2.8361, -74.0200], [50.0, 0.0, -82.7485], 3.4412),
+            ([50.0, -1.3802, -84.2814], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, -1.1848, -84.8006], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, -0.9009, -85.5211], [50.0, 0.0, -82.7485], 1.0000),
+            ([50.0, 0.0, 0.0], [50.0, -1.0, 2.0], 2.3669),
+            ([50.0, -1.0, 2.0], [50.0, 0.0, 0.0], 2.3669),
+            ([50.0, 2.49, -0.001], [50.0, -2.49, 0.0009], 7.1792),
+            ([50.0, 2.49, -0.001], [50.0, -2.49, 0.0010], 7.1792),
+            ([50.0, 2.49, -0.001], [50.0, -2.49, 0.0011], 7.2195),
+            ([50.0, 2.49, -0.001], [50.0, -2.49, 0.0012], 7.2195),
+            ([50.0, -0.001, 2.49], [50.0, 0.0009, -2.49], 4.8045),
+            ([50.0, -0.001, 2.49], [50.0, 0.0010, -2.49], 4.8045),
+            ([50.0, -0.001, 2.49], [50.0, 0.0011, -2.49], 4.7461),
+            ([50.0, 2.5, 0.0], [50.0, 0.0, -2.5], 4.3065),
+            ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 27.1492),
+            ([50.0, 2.5, 0.0], [61.0, -5.0, 29.0], 22.8977),
+            ([50.0, 2.5, 0.0], [56.0, -27.0, -3.0], 31.9030),
+            ([50.0, 2.5, 0.0], [58.0, 24.0, 15.0], 19.4535),
+            ([50.0, 2.5, 0.0], [50.0, 3.1736, 0.5854], 1.0000),
+            ([50.0, 2.5, 0.0], [50.0, 3.2972, 0.0], 1.0000),
+            ([50.0, 2.5, 0.0], [50.0, 1.8634, 0.5757], 1.0000),
+            ([50.0, 2.5, 0.0], [50.0, 3.2592, 0.3350], 1.0000),
+            (
+                [60.2574, -34.0099, 36.2677],
+                [60.4626, -34.1751, 39.4387],
+                1.2644,
+            ),
+            (
+                [63.0109, -31.0961, -5.8663],
+                [62.8187, -29.7946, -4.0864],
+                1.2630,
+            ),
+            ([61.2901, 3.7196, -5.3901], [61.4292, 2.2480, -4.9620], 1.8731),
+            ([35.0831, -44.1164, 3.7933], [35.0232, -40.0716, 1.5901], 1.8645),
+            (
+                [22.7233, 20.0904, -46.6940],
+                [23.0331, 14.9730, -42.5619],
+                2.0373,
+            ),
+            ([36.4612, 47.8580, 18.3852], [36.2715, 50.5065, 21.2231], 1.4146),
+            ([90.8027, -2.0831, 1.4410], [91.1528, -1.6435, 0.0447], 1.4441),
+            ([90.9257, -0.5406, -0.9208], [88.6381, -0.8985, -0.7239], 1.5381),
+            ([6.7747, -0.2908, -2.4247], [5.8714, -0.0985, -2.2286], 0.6377),
+            ([2.0776, 0.0795, -1.1350], [0.9033, -0.0636, -0.5514], 0.9082),
+        ];
+        for (i, (a, b, want)) in CASES.iter().copied().enumerate() {
+            let got = delta_e_2000(a, b);
+            approx::assert_abs_diff_eq!(want, got, epsilon = 0.0001);
+            let got = delta_e_2000(b, a);
+            approx::assert_abs_diff_eq!(want, got, epsilon = 0.0001);
+            assert_eq!(0.0, delta_e_2000(a, a), "{}", i + 1);
+        }
+    }
+
+    #[test]
+    fn test_delta_e_76() {
+        let (a, b) = ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0]);
+        let got = delta_e_76(a, b);
+        approx::assert_abs_diff_eq!(36.8680, got, epsilon = 0.0001);
+        assert_eq!(delta_e_76(a, b), delta_e_76(b, a));
+        assert_eq!(0.0, delta_e_76(a, a));
+    }
+}
@@ -0,0 +1,173 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions rendering colour diagrams.
+//!
+//! The diagrams are meant for visual inspection of colours and colour spaces
+//! and double as an illustration of how conversion functions provided by the
+//! crate fit together.
+
+
+/// Range of `x` chromaticity coordinate covered by [`render_cie1931()`].
+const X_RANGE: f32 = 0.8;
+
+/// Range of `y` chromaticity coordinate covered by [`render_cie1931()`].
+const Y_RANGE: f32 = 0.9;
+
+/// Colour of the area outside of the spectral locus.
+const BACKGROUND: [u8; 3] = [0, 0, 0];
+
+/// Colour of the spectral locus outline.
+const OUTLINE: [u8; 3] = [255, 255, 255];
+
+
+/// Renders CIE 1931 chromaticity diagram as a 24-bit sRGB image.
+///
+/// The image of given dimensions is returned row by row starting with the top
+/// row.  It covers `x` chromaticity coordinates from 0 to 0.8 (left to right)
+/// and `y` coordinates from 0 to 0.9 (bottom to top).  Chromaticities inside
+/// of the spectral locus (as tabulated in [`crate::spectral::CMF_1931`]) are
+/// painted with the brightest colour of that chromaticity.  Colours outside
+/// of the sRGB gamut are desaturated towards white until they fit.  Area
+/// outside of the locus is black and the locus itself is outlined in white.
+///
+/// Returns an empty vector if either dimension is zero.
+///
+/// # Example
+/// ```
+/// let (width, height) = (80, 90);
+/// let image = srgb::diagram::render_cie1931(width, height);
+/// assert_eq!(width * height, image.len());
+/// // Top-left corner lies outside of the spectral locus.
+/// assert_eq!([0, 0, 0], image[0]);
+/// // D65 white point at x = 0.3127, y = 0.3290.
+/// let [r, g, b] = image[(90 - 33) * width + 31];
+/// assert!(r > 240 && g > 240 && b > 240);
+/// ```
+pub fn render_cie1931(width: usize, height: usize) -> Vec<[u8; 3]> {
+    let scale = [width as f32 / X_RANGE, height as f32 / Y_RANGE];
+    let locus = crate::spectral::CMF_1931
+        .iter()
+        .map(|[x, y, z]| {
+            let sum = x + y + z;
+            [x / sum * scale[0], (Y_RANGE - y / sum) * scale[1]]
+        })
+        .collect::<Vec<_>>();
+
+    let mut image = Vec::with_capacity(width * height);
+    for row in 0..height {
+        for col in 0..width {
+            let point = [col as f32 + 0.5, row as f32 + 0.5];
+            image.push(if is_near_outline(&locus, point) {
+                OUTLINE
+            } else if is_inside(&locus, point) {
+                let x = point[0] / scale[0];
+                let y = Y_RANGE - point[1] / scale[1];
+                colour_from_xy(x, y)
+            } else {
+                BACKGROUND
+            });
+        }
+    }
+    image
+}
+
+/// Returns the brightest displayable colour with given chromaticity.
+fn colour_from_xy(x: f32, y: f32) -> [u8; 3] {
+    let xyz = [x / y, 1.0, (1.0 - x - y) / y];
+    let linear = crate::xyz::linear_from_xyz(xyz);
+    crate::gamma::u8_from_linear(crate::gamut::desaturate_into_gamut(linear))
+}
+
+/// Returns whether point is within half a pixel from the closed polygon.
+fn is_near_outline(polygon: &[[f32; 2]], point: [f32; 2]) -> bool {
+    edges(polygon).any(|(a, b)| {
+        let ab = [b[0] - a[0], b[1] - a[1]];
+        let ap = [point[0] - a[0], point[1] - a[1]];
+        let len = ab[0] * ab[0] + ab[1] * ab[1];
+        let t = if len > 0.0 {
+            ((ap[0] * ab[0] + ap[1] * ab[1]) / len).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let dx = ap[0] - t * ab[0];
+        let dy = ap[1] - t * ab[1];
+        dx * dx + dy * dy <= 0.25
+    })
+}
+
+/// Returns whether point lies inside of the closed polygon using the even-odd
+/// rule.
+fn is_inside(polygon: &[[f32; 2]], point: [f32; 2]) -> bool {
+    edges(polygon)
+        .filter(|(a, b)| {
+            (a[1] > point[1]) != (b[1] > point[1]) &&
+                point[0] <
+                    a[0] + (point[1] - a[1]) / (b[1] - a[1]) *
+                        (b[0] - a[0])
+        })
+        .count() %
+        2 ==
+        1
+}
+
+/// Returns iterator over edges of a closed polygon.
+fn edges(
+    polygon: &[[f32; 2]],
+) -> impl Iterator<Item = (&[f32; 2], &[f32; 2])> + '_ {
+    polygon.iter().zip(polygon.iter().cycle().skip(1))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_render_cie1931() {
+        assert_eq!(Vec::<[u8; 3]>::new(), render_cie1931(0, 10));
+        assert_eq!(Vec::<[u8; 3]>::new(), render_cie1931(10, 0));
+
+        let (width, height) = (400, 450);
+        let image = render_cie1931(width, height);
+        assert_eq!(width * height, image.len());
+        let pixel = |x: f32, y: f32| {
+            let col = (x / X_RANGE * width as f32) as usize;
+            let row = ((Y_RANGE - y) / Y_RANGE * height as f32) as usize;
+            image[row * width + col]
+        };
+
+        let [r, g, b] = pixel(0.3127, 0.3290);
+        assert!(r >= 250 && g >= 250 && b >= 250, "{:?}", [r, g, b]);
+        assert_eq!(BACKGROUND, pixel(0.7, 0.8));
+        assert_eq!(BACKGROUND, pixel(0.01, 0.01));
+
+        // Near the sRGB primaries the dominant channel is the right one.
+        let [r, g, b] = pixel(0.60, 0.33);
+        assert!(r > g && r > b, "{:?}", [r, g, b]);
+        let [r, g, b] = pixel(0.30, 0.55);
+        assert!(g > r && g > b, "{:?}", [r, g, b]);
+        let [r, g, b] = pixel(0.16, 0.08);
+        assert!(b > r && b > g, "{:?}", [r, g, b]);
+
+        // Every row crossing the locus starts with an outline.
+        for row in (40..height - 10).step_by(10) {
+            let row = &image[row * width..(row + 1) * width];
+            let first = row.iter().find(|rgb| **rgb != BACKGROUND);
+            assert_eq!(Some(&OUTLINE), first);
+        }
+    }
+}
@@ -0,0 +1,339 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Dithering used when quantising colours.
+//!
+//! Ordered dithering patterns are used by
+//! [`crate::gamma::compress_u8_slice_dithered()`] function.
+//! [`ScanlineDitherer`] implements Floyd–Steinberg error diffusion for images
+//! processed one row at a time.
+
+/// A dithering pattern.
+///
+/// The pattern is defined by a threshold for each pixel.  When quantising,
+/// threshold is added to the value (scaled such that difference between
+/// consecutive quantisation levels is one) before rounding it down.  Provided
+/// thresholds are evenly distributed over the `[0, 1)` range, the mean output
+/// over an area matches the original value.
+pub trait Dither {
+    /// Returns threshold for pixel at given coordinates.
+    ///
+    /// The value must be in the `[0, 1)` range.  Implementations of patterns
+    /// defined by a tile repeat it across the whole plane.
+    fn threshold(&self, x: usize, y: usize) -> f32;
+}
+
+/// Ordered dithering with 2×2 Bayer matrix.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Bayer2;
+
+/// Ordered dithering with 4×4 Bayer matrix.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Bayer4;
+
+/// Ordered dithering with 8×8 Bayer matrix.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Bayer8;
+
+/// Ordered dithering with 16×16 blue noise tile.
+///
+/// The tile has been generated with the void-and-cluster algorithm (with
+/// Gaussian filter of σ = 1.5).  Compared to Bayer matrices, blue noise
+/// produces less regular patterns which are less noticeable to human eye.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BlueNoise;
+
+impl Dither for Bayer2 {
+    #[inline]
+    fn threshold(&self, x: usize, y: usize) -> f32 {
+        tile_threshold(&BAYER_2, x, y)
+    }
+}
+
+impl Dither for Bayer4 {
+    #[inline]
+    fn threshold(&self, x: usize, y: usize) -> f32 {
+        tile_threshold(&BAYER_4, x, y)
+    }
+}
+
+impl Dither for Bayer8 {
+    #[inline]
+    fn threshold(&self, x: usize, y: usize) -> f32 {
+        tile_threshold(&BAYER_8, x, y)
+    }
+}
+
+impl Dither for BlueNoise {
+    #[inline]
+    fn threshold(&self, x: usize, y: usize) -> f32 {
+        tile_threshold(&BLUE_NOISE_16, x, y)
+    }
+}
+
+/// Floyd–Steinberg error diffusion quantiser processing an image row by row.
+///
+/// The ditherer converts linear sRGB colours into 24-bit sRGB colours
+/// diffusing quantisation error of each pixel onto its not yet processed
+/// neighbours: 7/16 to the right, and 3/16, 5/16 and 1/16 to the bottom-left,
+/// bottom and bottom-right respectively.  Errors are measured in linear space
+/// such that average light output of an area matches the source.
+///
+/// Only errors carried into the next row are kept which means images can be
+/// converted while being streamed (e.g. from a decoder or to an encoder)
+/// without holding the whole image in memory.
+///
+/// # Example
+/// ```
+/// use srgb::dither::ScanlineDitherer;
+///
+/// // Linear value whose compressed value is half way between 127 and 128.
+/// let grey = srgb::gamma::expand_normalised(127.5 / 255.0);
+/// let mut ditherer = ScanlineDitherer::new(4);
+/// let mut out = [[0; 3]; 4];
+/// let mut seen = [0; 256];
+/// for _ in 0..4 {
+///     ditherer.process_row(&[[grey; 3]; 4], &mut out);
+///     for rgb in out.iter() {
+///         seen[usize::from(rgb[0])] += 1;
+///     }
+/// }
+/// // Output alternates between the two nearest codes.
+/// assert_eq!(16, seen[127] + seen[128]);
+/// assert!(seen[127] > 4 && seen[128] > 4);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ScanlineDitherer {
+    /// Errors diffused onto the row processed next.
+    current: Vec<[f32; 3]>,
+    /// Errors diffused onto the row after the next one, i.e. accumulated
+    /// while processing a row.
+    next: Vec<[f32; 3]>,
+}
+
+impl ScanlineDitherer {
+    /// Creates a new ditherer for images of given width.
+    pub fn new(width: usize) -> Self {
+        Self { current: vec![[0.0; 3]; width], next: vec![[0.0; 3]; width] }
+    }
+
+    /// Returns width of rows the ditherer processes.
+    pub fn width(&self) -> usize { self.current.len() }
+
+    /// Quantises a row of linear sRGB colours into 24-bit sRGB colours.
+    ///
+    /// Rows must be given in order from top to bottom.  Errors from the
+    /// previous row are taken into account and errors of this row are stored
+    /// for the next call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if length of `linear` or `out` doesn’t equal width of the
+    /// ditherer.
+    pub fn process_row(&mut self, linear: &[[f32; 3]], out: &mut [[u8; 3]]) {
+        let width = self.width();
+        assert_eq!(width, linear.len());
+        assert_eq!(width, out.len());
+        for x in 0..width {
+            let mut error = [0.0; 3];
+            for (chan, error) in error.iter_mut().enumerate() {
+                let value = linear[x][chan] + self.current[x][chan];
+                let code = crate::gamma::compress_u8(value);
+                out[x][chan] = code;
+                *error = value - crate::gamma::expand_u8(code);
+            }
+            let diffuse = |row: &mut [[f32; 3]], x: usize, weight: f32| {
+                if let Some(pixel) = row.get_mut(x) {
+                    for (value, error) in pixel.iter_mut().zip(error.iter()) {
+                        *value = crate::maths::mul_add(*error, weight, *value);
+                    }
+                }
+            };
+            diffuse(&mut self.current, x + 1, 7.0 / 16.0);
+            diffuse(&mut self.next, x.wrapping_sub(1), 3.0 / 16.0);
+            diffuse(&mut self.next, x, 5.0 / 16.0);
+            diffuse(&mut self.next, x + 1, 1.0 / 16.0);
+        }
+        core::mem::swap(&mut self.current, &mut self.next);
+        self.next.fill([0.0; 3]);
+    }
+}
+
+
+/// Returns threshold at given coordinates for a tile holding ranks of each
+/// position, i.e. a permutation of integers from zero to N² - 1.
+#[inline]
+fn tile_threshold<const N: usize>(
+    tile: &[[u8; N]; N],
+    x: usize,
+    y: usize,
+) -> f32 {
+    (tile[y % N][x % N] as f32 + 0.5) / (N * N) as f32
+}
+
+const BAYER_2: [[u8; 2]; 2] = [[0, 2], [3, 1]];
+
+const BAYER_4: [[u8; 4]; 4] =
+    [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]];
+
+const BAYER_8: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+const BLUE_NOISE_16: [[u8; 16]; 16] = [
+    [120, 61, 134, 223, 84, 33, 168, 12, 113, 225, 63, 246, 185, 233, 88, 169],
+    [23, 206, 181, 17, 109, 214, 58, 140, 201, 24, 161, 93, 34, 133, 14, 221],
+    [144, 73, 250, 49, 158, 187, 81, 251, 100, 51, 142, 210, 172, 57, 191, 106],
+    [42, 167, 101, 126, 220, 3, 121, 40, 170, 231, 82, 8, 114, 254, 80, 232],
+    [212, 11, 195, 31, 72, 239, 152, 196, 16, 127, 188, 222, 45, 157, 26, 128],
+    [154, 87, 235, 143, 179, 94, 54, 108, 237, 65, 29, 105, 139, 207, 184, 66],
+    [248, 47, 115, 62, 209, 20, 164, 217, 79, 146, 178, 243, 69, 90, 1, 118],
+    [30, 190, 173, 6, 131, 255, 41, 136, 10, 204, 43, 159, 22, 229, 162, 218],
+    [77, 148, 99, 226, 74, 182, 117, 192, 86, 247, 119, 97, 197, 130, 53, 103],
+    [242, 19, 198, 44, 155, 96, 59, 230, 28, 165, 60, 5, 240, 39, 175, 202],
+    [137, 64, 122, 238, 25, 211, 0, 149, 104, 224, 135, 183, 151, 71, 112, 9],
+    [91, 213, 166, 85, 186, 111, 249, 174, 48, 75, 208, 32, 89, 205, 236, 160],
+    [37, 252, 18, 55, 138, 38, 78, 123, 194, 13, 107, 253, 124, 15, 56, 189],
+    [
+        76, 145, 110, 228, 203, 163, 219, 21, 241, 141, 171, 50, 156, 227, 102,
+        129,
+    ],
+    [2, 199, 176, 68, 7, 98, 52, 150, 92, 36, 215, 83, 200, 27, 177, 216],
+    [244, 95, 35, 153, 245, 125, 193, 234, 70, 180, 132, 4, 116, 67, 147, 46],
+];
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check_tile<const N: usize>(dither: impl Dither, tile: &[[u8; N]; N]) {
+        let mut seen = [false; 256];
+        for (y, row) in tile.iter().enumerate() {
+            for (x, want) in row.iter().enumerate() {
+                let threshold = dither.threshold(x, y);
+                assert!((0.0..1.0).contains(&threshold));
+                assert_eq!(threshold, dither.threshold(x + N, y + 3 * N));
+                let rank = threshold * (N * N) as f32 - 0.5;
+                assert_eq!(*want as f32, rank);
+                assert!(!seen[rank as usize]);
+                seen[rank as usize] = true;
+            }
+        }
+        assert_eq!(N * N, seen.iter().filter(|seen| **seen).count());
+    }
+
+    #[test]
+    fn test_thresholds() {
+        assert_eq!(0.125, Bayer2.threshold(0, 0));
+        assert_eq!(0.625, Bayer2.threshold(1, 0));
+        assert_eq!(0.875, Bayer2.threshold(0, 1));
+        assert_eq!(0.375, Bayer2.threshold(1, 1));
+
+        check_tile(Bayer2, &BAYER_2);
+        check_tile(Bayer4, &BAYER_4);
+        check_tile(Bayer8, &BAYER_8);
+        check_tile(BlueNoise, &BLUE_NOISE_16);
+    }
+
+    /// Floyd–Steinberg dithering of a whole image held in memory.
+    fn dither_image(src: &[[f32; 3]], width: usize) -> Vec<[u8; 3]> {
+        let mut image = src.to_vec();
+        let mut out = vec![[0; 3]; src.len()];
+        let height = src.len() / width;
+        for y in 0..height {
+            for x in 0..width {
+                let idx = y * width + x;
+                let value = image[idx];
+                out[idx] = crate::gamma::u8_from_linear(value);
+                let error = [0, 1, 2].map(|chan| {
+                    value[chan] - crate::gamma::expand_u8(out[idx][chan])
+                });
+                let mut diffuse = |x: usize, y: usize, weight: f32| {
+                    if x < width && y < height {
+                        let pixel = &mut image[y * width + x];
+                        for (value, error) in pixel.iter_mut().zip(error) {
+                            *value =
+                                crate::maths::mul_add(error, weight, *value);
+                        }
+                    }
+                };
+                diffuse(x + 1, y, 7.0 / 16.0);
+                diffuse(x.wrapping_sub(1), y + 1, 3.0 / 16.0);
+                diffuse(x, y + 1, 5.0 / 16.0);
+                diffuse(x + 1, y + 1, 1.0 / 16.0);
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn test_scanline_ditherer() {
+        let (width, height) = (37, 23);
+        let src = (0..width * height)
+            .map(|i| {
+                let [r, g, b] =
+                    crate::from_u32((i as u32).wrapping_mul(0x9e3779b9));
+                let (x, y) = (i % width, i / width);
+                crate::arr_map([r, g, b], |v: u8| {
+                    let v = v as f32 / 255.0;
+                    (v + x as f32 / width as f32 + y as f32 / height as f32) /
+                        3.0
+                })
+            })
+            .collect::<Vec<_>>();
+        let want = dither_image(&src, width);
+
+        let mut ditherer = ScanlineDitherer::new(width);
+        let mut got = vec![[0; 3]; src.len()];
+        for (src, out) in src.chunks(width).zip(got.chunks_mut(width)) {
+            ditherer.process_row(src, out);
+        }
+        assert_eq!(want, got);
+    }
+
+    #[test]
+    fn test_scanline_ditherer_mean() {
+        // Average light of dithered flat area matches the source.
+        let width = 64;
+        for value in [0.001, 0.01, 0.2, 0.5, 0.77] {
+            let mut ditherer = ScanlineDitherer::new(width);
+            let mut out = vec![[0; 3]; width];
+            let mut sum = 0.0;
+            for _ in 0..64 {
+                ditherer.process_row(&vec![[value; 3]; width], &mut out);
+                sum += out
+                    .iter()
+                    .map(|rgb| crate::gamma::expand_u8(rgb[0]) as f64)
+                    .sum::<f64>();
+            }
+            let mean = sum / (64 * width) as f64;
+            approx::assert_abs_diff_eq!(value as f64, mean, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scanline_ditherer_width_mismatch() {
+        ScanlineDitherer::new(3).process_row(&[[0.0; 3]; 2], &mut [[0; 3]; 2]);
+    }
+}
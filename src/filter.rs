@@ -0,0 +1,186 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for gamma-correct image resampling.
+//!
+//! Averaging gamma-encoded sRGB values produces images which are too dark
+//! (e.g. a black and white checkerboard averages to 128 rather than 188).
+//! Functions in this module perform filtering in linear space which avoids
+//! the problem.
+//!
+//! Images are given as slices of 24-bit sRGB colours in row-major order.
+
+/// Generates a full mipmap chain for an image.
+///
+/// Returns a vector of `(width, height, pixels)` triples starting with the
+/// base image and ending with a 1×1 image.  Each level is produced by
+/// averaging 2×2 blocks of the previous level in linear space.  Dimensions of
+/// consecutive levels are halved and rounded down (but never go below one).
+/// When a dimension is odd, the last row or column of the previous level is
+/// folded into its neighbour’s block so that all pixels contribute.
+///
+/// Intermediate levels are kept in linear space so that rounding errors don’t
+/// accumulate down the chain.
+///
+/// # Panics
+///
+/// Panics if length of `base` is not `width * height`.
+///
+/// # Example
+/// ```
+/// let (black, white) = ([0, 0, 0], [255, 255, 255]);
+/// let base = [black, white, white, black];
+/// let mips = srgb::filter::generate_mipmaps(&base, 2, 2);
+/// assert_eq!(2, mips.len());
+/// assert_eq!((2, 2, base.to_vec()), mips[0]);
+/// assert_eq!((1, 1, vec![[188, 188, 188]]), mips[1]);
+/// ```
+pub fn generate_mipmaps(
+    base: &[[u8; 3]],
+    width: usize,
+    height: usize,
+) -> Vec<(usize, usize, Vec<[u8; 3]>)> {
+    assert_eq!(width * height, base.len());
+    let mut mips = vec![(width, height, base.to_vec())];
+    let (mut width, mut height) = (width, height);
+    if width == 0 || height == 0 {
+        return mips;
+    }
+    let mut linear = crate::gamma::expand_u8_vec(base.iter().copied());
+    while width > 1 || height > 1 {
+        let (w, h) = ((width / 2).max(1), (height / 2).max(1));
+        linear = downscale_linear(&linear, width, height, w, h);
+        mips.push((
+            w,
+            h,
+            crate::gamma::compress_u8_vec(linear.iter().copied()),
+        ));
+        width = w;
+        height = h;
+    }
+    mips
+}
+
+/// Downscales an image in linear space using box filter.
+///
+/// Each destination pixel is an average of source pixels it covers.  Source
+/// dimensions must not be smaller than destination dimensions.
+fn downscale_linear(
+    src: &[[f32; 3]],
+    src_width: usize,
+    src_height: usize,
+    width: usize,
+    height: usize,
+) -> Vec<[f32; 3]> {
+    let range = |pos: usize, src_len: usize, len: usize| {
+        (pos * src_len / len)..((pos + 1) * src_len / len)
+    };
+    let mut dst = Vec::with_capacity(width * height);
+    for y in 0..height {
+        let rows = range(y, src_height, height);
+        for x in 0..width {
+            let cols = range(x, src_width, width);
+            let mut sum = [0.0; 3];
+            for row in rows.clone() {
+                for pixel in &src[row * src_width..][cols.clone()] {
+                    for (sum, value) in sum.iter_mut().zip(pixel.iter()) {
+                        *sum += *value;
+                    }
+                }
+            }
+            let count = (rows.len() * cols.len()) as f32;
+            dst.push(sum.map(|sum| sum / count));
+        }
+    }
+    dst
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dimensions(
+        mips: &[(usize, usize, Vec<[u8; 3]>)],
+    ) -> Vec<(usize, usize)> {
+        mips.iter()
+            .map(|(width, height, pixels)| {
+                assert_eq!(width * height, pixels.len());
+                (*width, *height)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let mips = generate_mipmaps(&[[0; 3]; 8 * 4], 8, 4);
+        assert_eq!(vec![(8, 4), (4, 2), (2, 1), (1, 1)], dimensions(&mips));
+        let mips = generate_mipmaps(&[[0; 3]; 7 * 3], 7, 3);
+        assert_eq!(vec![(7, 3), (3, 1), (1, 1)], dimensions(&mips));
+        let mips = generate_mipmaps(&[[0; 3]; 5], 1, 5);
+        assert_eq!(vec![(1, 5), (1, 2), (1, 1)], dimensions(&mips));
+        let mips = generate_mipmaps(&[[0; 3]], 1, 1);
+        assert_eq!(vec![(1, 1)], dimensions(&mips));
+        let mips = generate_mipmaps(&[], 0, 3);
+        assert_eq!(vec![(0, 3)], dimensions(&mips));
+    }
+
+    #[test]
+    fn test_flat() {
+        for rgb in [[0, 0, 0], [212, 33, 61], [255, 255, 255], [1, 128, 254]] {
+            for (width, height) in [(16, 16), (13, 7), (1, 9)] {
+                let base = vec![rgb; width * height];
+                for (w, h, pixels) in generate_mipmaps(&base, width, height) {
+                    assert!(
+                        pixels.iter().all(|pixel| *pixel == rgb),
+                        "{:?} {}×{}: {:?}",
+                        rgb,
+                        w,
+                        h,
+                        pixels
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_checkerboard() {
+        let (width, height) = (16, 8);
+        let base = (0..width * height)
+            .map(|i| {
+                let (x, y) = (i % width, i / width);
+                if (x + y) % 2 == 0 {
+                    [0, 0, 0]
+                } else {
+                    [255, 255, 255]
+                }
+            })
+            .collect::<Vec<_>>();
+        let grey = crate::gamma::u8_from_linear([0.5; 3]);
+        assert_eq!([188; 3], grey);
+        let mips = generate_mipmaps(&base, width, height);
+        assert_eq!(5, mips.len());
+        for (w, h, pixels) in &mips[1..] {
+            assert!(
+                pixels.iter().all(|pixel| *pixel == grey),
+                "{}×{}: {:?}",
+                w,
+                h,
+                pixels
+            );
+        }
+    }
+}
@@ -0,0 +1,456 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+#![allow(clippy::neg_cmp_op_on_partial_ord)]
+
+//! Functions for handling colours which lie outside of the sRGB gamut.
+
+/// Tolerance used when checking whether linear sRGB colour is in gamut.
+///
+/// Conversions between colour spaces aren’t exact and colours which are
+/// supposed to lie on the edge of the gamut may end up slightly outside of it.
+const EPSILON: f32 = 0.00001;
+
+/// Returns whether given linear sRGB colour lies within the sRGB gamut.
+///
+/// That is, returns whether all components are within the range from zero to
+/// one (with a small tolerance for rounding errors).
+///
+/// # Example
+/// ```
+/// assert!(srgb::gamut::is_in_gamut([0.0, 0.5, 1.0]));
+/// assert!(!srgb::gamut::is_in_gamut([1.2, 0.5, 0.0]));
+/// assert!(!srgb::gamut::is_in_gamut([-0.1, 0.5, 0.0]));
+/// ```
+pub fn is_in_gamut(linear: impl Into<[f32; 3]>) -> bool {
+    linear.into().iter().all(|v| (-EPSILON..=1.0 + EPSILON).contains(v))
+}
+
+/// Description of how far outside of the sRGB gamut a linear colour lies.
+///
+/// Returned by [`excursion()`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GamutExcursion {
+    /// Per-channel excursion.  Negative value indicates how far below zero
+    /// the component is, positive value how far above one it is.  Zero means
+    /// the component is within the range.
+    pub channels: [f32; 3],
+    /// Overall measure of how far outside of the gamut the colour is.  This
+    /// is the Euclidean distance between the colour and the colour with
+    /// components clipped to the range from zero to one, i.e. length of the
+    /// `channels` vector.
+    pub distance: f32,
+}
+
+impl GamutExcursion {
+    /// Returns whether the colour lies within the gamut, i.e. whether all
+    /// excursions are zero.
+    pub fn is_in_gamut(&self) -> bool { self.distance == 0.0 }
+}
+
+/// Reports how far outside of the sRGB gamut a linear sRGB colour lies.
+///
+/// This is a diagnostic tool which helps to determine why a colour (e.g.
+/// converted from a wide-gamut colour space) clips when converted into sRGB
+/// and in which direction.  Unlike [`is_in_gamut()`], no tolerance is applied.
+/// NaN components are reported as NaN excursions.
+///
+/// # Example
+/// ```
+/// use srgb::gamut::excursion;
+///
+/// assert!(excursion([0.0, 0.5, 1.0]).is_in_gamut());
+///
+/// let got = excursion([1.5, 0.5, -0.25]);
+/// assert_eq!([0.5, 0.0, -0.25], got.channels);
+/// assert_eq!(0.559017, got.distance);
+/// ```
+pub fn excursion(linear: impl Into<[f32; 3]>) -> GamutExcursion {
+    let channels = crate::arr_map(linear, |v| {
+        if v < 0.0 {
+            v
+        } else if v > 1.0 {
+            v - 1.0
+        } else if v.is_nan() {
+            v
+        } else {
+            0.0
+        }
+    });
+    let [r, g, b] = channels;
+    let distance = r.hypot(g).hypot(b);
+    GamutExcursion { channels, distance }
+}
+
+/// Converts Oklch colour into 24-bit sRGB colour mapping it into sRGB gamut if
+/// necessary.
+///
+/// The colour is given as lightness `l` (in the range from zero to one), chroma
+/// `c` and hue `h` in degrees.  If the colour cannot be represented in sRGB,
+/// its chroma is reduced (while lightness and hue are held constant) until it
+/// can.  This is the gamut mapping approach used by CSS Color Module Level 4
+/// for `oklch()` colours.  Lightness outside of the range from zero to one
+/// produces black or white respectively.
+///
+/// # Example
+/// ```
+/// // In-gamut colours are simply converted.
+/// assert_eq!([212, 33, 61], srgb::gamut::to_srgb_oklch(0.5624, 0.2091, 20.3));
+/// // Out-of-gamut colours have their chroma reduced.
+/// assert_eq!([255, 18, 247], srgb::gamut::to_srgb_oklch(0.7, 0.4, 330.0));
+/// ```
+pub fn to_srgb_oklch(l: f32, c: f32, h: f32) -> [u8; 3] {
+    if !(l > 0.0) {
+        return [0, 0, 0];
+    } else if l >= 1.0 {
+        return [255, 255, 255];
+    }
+
+    let (sin, cos) = h.to_radians().sin_cos();
+    let linear_at =
+        |c: f32| crate::oklab::linear_from_oklab([l, c * cos, c * sin]);
+
+    let mut linear = linear_at(c);
+    if !is_in_gamut(linear) {
+        let (mut lo, mut hi) = (0.0, c);
+        linear = linear_at(lo);
+        while hi - lo > EPSILON {
+            let mid = (lo + hi) * 0.5;
+            let candidate = linear_at(mid);
+            if is_in_gamut(candidate) {
+                lo = mid;
+                linear = candidate;
+            } else {
+                hi = mid;
+            }
+        }
+    }
+    crate::gamma::u8_from_linear(linear)
+}
+
+/// Returns the highest relative luminance of a fully saturated sRGB colour
+/// with given Oklch hue (in degrees).
+///
+/// Fully saturated colours lie on the edges of the sRGB cube with one
+/// component equal zero and another equal one.  Among the colours with given
+/// hue, those are the ones with largest chroma (i.e. the cusp of the gamut)
+/// and their luminance depends strongly on the hue: yellow is almost as
+/// bright as white while blue is very dark.  This is useful when designing
+/// palettes with constant hue.
+///
+/// Oklch hue isn’t monotonic along the edges of the cube near blue primary so
+/// hues from around 264.05° to 264.21° correspond to more than one fully
+/// saturated colour.  In that case the brightest of them is considered and as
+/// a result the function has a small discontinuity at around 264.21°.
+///
+/// The hue is taken modulo 360; for non-finite hue returns NaN.
+///
+/// # Example
+/// ```
+/// use srgb::gamut::max_luminance_for_hue;
+///
+/// let [_, a, b] = srgb::oklab::oklab_from_linear([1.0, 1.0, 0.0]);
+/// let yellow = b.atan2(a).to_degrees();
+/// let got = max_luminance_for_hue(yellow);
+/// approx::assert_abs_diff_eq!(0.9278, got, epsilon = 0.001);
+/// ```
+pub fn max_luminance_for_hue(h: f32) -> f32 {
+    /// Number of samples taken along each edge of the cube.
+    const STEPS: u32 = 64;
+
+    if !h.is_finite() {
+        return f32::NAN;
+    }
+    // Position along the hexagon formed by fully saturated colours from red
+    // (0) through yellow (1), green (2), cyan (3), blue (4) and magenta (5)
+    // back to red (6).
+    let colour_at = |pos: f32| {
+        let t = pos.fract();
+        match pos as u32 {
+            0 => [1.0, t, 0.0],
+            1 => [1.0 - t, 1.0, 0.0],
+            2 => [0.0, 1.0, t],
+            3 => [0.0, 1.0 - t, 1.0],
+            4 => [t, 0.0, 1.0],
+            _ => [1.0, 0.0, 1.0 - t],
+        }
+    };
+    // Difference between hue at given position and the target hue normalised
+    // to the range from -180 to 180.
+    let delta_at = |pos: f32| {
+        let [_, a, b] = crate::oklab::oklab_from_linear(colour_at(pos % 6.0));
+        (b.atan2(a).to_degrees() - h + 180.0).rem_euclid(360.0) - 180.0
+    };
+    let [yr, yg, yb] = crate::xyz::XYZ_FROM_SRGB_MATRIX[1];
+
+    let mut result = f32::NAN;
+    let mut lo = 0.0;
+    let mut lo_delta = delta_at(lo);
+    for step in 1..=6 * STEPS {
+        let hi = step as f32 / STEPS as f32;
+        let hi_delta = delta_at(hi);
+        // Look for segments where the hue crosses the target.  Change of
+        // sign with a large jump means the segment crosses the opposite hue.
+        if (lo_delta < 0.0) != (hi_delta < 0.0) &&
+            (hi_delta - lo_delta).abs() < 180.0
+        {
+            let (mut a, mut b) = (lo, hi);
+            for _ in 0..24 {
+                let mid = (a + b) * 0.5;
+                if (delta_at(mid) < 0.0) == (lo_delta < 0.0) {
+                    a = mid;
+                } else {
+                    b = mid;
+                }
+            }
+            let [r, g, b] = colour_at(a);
+            let y = crate::maths::mul_add(
+                yr,
+                r,
+                crate::maths::mul_add(yg, g, yb * b),
+            );
+            result = result.max(y);
+        }
+        lo = hi;
+        lo_delta = hi_delta;
+    }
+    result
+}
+
+/// Brings over-range linear sRGB colour into range by scaling it uniformly.
+///
+/// If the largest component of the colour exceeds one, all components are
+/// divided by it so that the largest becomes exactly one.  This preserves
+/// ratios between components (and thus hue and saturation) and, among all
+/// transformations which do, keeps relative luminance the highest.  By
+/// comparison, clipping each component separately shifts hue of the colour
+/// (e.g. an overexposed orange turns yellow).
+///
+/// Colours whose components are all at most one are returned unchanged.  The
+/// function doesn’t alter negative components; see [`to_srgb_oklch()`] for
+/// a general gamut mapping.
+///
+/// # Example
+/// ```
+/// use srgb::gamut::clamp_preserve_luminance;
+///
+/// let in_range = [0.5, 0.25, 0.125];
+/// assert_eq!(in_range, clamp_preserve_luminance(in_range));
+/// assert_eq!([1.0, 0.5, 0.25], clamp_preserve_luminance([2.0, 1.0, 0.5]));
+/// ```
+pub fn clamp_preserve_luminance(linear: impl Into<[f32; 3]>) -> [f32; 3] {
+    let linear = linear.into();
+    let max = linear.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    if max > 1.0 {
+        crate::arr_map(linear, |v| v / max)
+    } else {
+        linear
+    }
+}
+
+/// Brings linear sRGB colour into gamut by desaturating it and, if necessary,
+/// scaling it down.
+///
+/// White is added to the colour until no component is negative and then all
+/// components are divided by the largest one if it exceeds one.
+pub(crate) fn desaturate_into_gamut(linear: [f32; 3]) -> [f32; 3] {
+    let [r, g, b] = linear;
+    let white = -r.min(g).min(b).min(0.0);
+    let (r, g, b) = (r + white, g + white, b + white);
+    let scale = r.max(g).max(b).max(1.0);
+    [r / scale, g / scale, b / scale]
+}
+
+/// Returns the largest chroma for which Oklch colour with given lightness `l`
+/// and hue `h` (in degrees) lies within the sRGB gamut.
+///
+/// Returns zero if lightness is outside of the range from zero to one.
+pub(crate) fn max_chroma(l: f32, h: f32) -> f32 {
+    if !(l > 0.0 && l < 1.0) {
+        return 0.0;
+    }
+    let (sin, cos) = h.to_radians().sin_cos();
+    let (mut lo, mut hi) = (0.0, MAX_CHROMA);
+    while hi - lo > EPSILON {
+        let mid = (lo + hi) * 0.5;
+        let linear = crate::oklab::linear_from_oklab([l, mid * cos, mid * sin]);
+        if is_in_gamut(linear) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Upper bound for chroma of any colour within the sRGB gamut.
+///
+/// The most chromatic sRGB colour, magenta (`#ff00ff`), has chroma of about
+/// 0.3225.
+const MAX_CHROMA: f32 = 0.4;
+
+
+#[cfg(test)]
+mod test {
+    fn oklch_from_u8(rgb: [u8; 3]) -> [f32; 3] {
+        let linear = crate::gamma::linear_from_u8(rgb);
+        let oklab = crate::oklab::oklab_from_linear(linear);
+        crate::oklab::oklch_from_oklab_deg(oklab)
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_max_luminance_for_hue() {
+        use super::max_luminance_for_hue;
+
+        let hue_of = |linear: [f32; 3]| {
+            let [_, a, b] = crate::oklab::oklab_from_linear(linear);
+            b.atan2(a).to_degrees()
+        };
+        let [yr, yg, yb] = crate::xyz::XYZ_FROM_SRGB_MATRIX[1];
+        for (linear, want) in [
+            ([1.0, 0.0, 0.0], yr),
+            ([1.0, 1.0, 0.0], yr + yg),
+            ([0.0, 1.0, 0.0], yg),
+            ([0.0, 1.0, 1.0], yg + yb),
+            ([1.0, 0.0, 1.0], yr + yb),
+        ] {
+            let got = max_luminance_for_hue(hue_of(linear));
+            approx::assert_abs_diff_eq!(want, got, epsilon = 0.0001);
+            let got = max_luminance_for_hue(hue_of(linear) + 360.0);
+            approx::assert_abs_diff_eq!(want, got, epsilon = 0.0001);
+        }
+        // Because of non-monotonic hue near the blue primary, its hue
+        // corresponds to a brighter colour as well.  Slightly further away
+        // the result is close to luminance of the blue primary.
+        let blue = hue_of([0.0, 0.0, 1.0]);
+        assert!(max_luminance_for_hue(blue) > yb);
+        let got = max_luminance_for_hue(blue + 1.0);
+        approx::assert_abs_diff_eq!(yb, got, epsilon = 0.005);
+
+        // Apart from the discontinuity near blue, the function is continuous.
+        let mut prev = max_luminance_for_hue(0.0);
+        for i in 1..=3600 {
+            let h = i as f32 / 10.0;
+            let got = max_luminance_for_hue(h);
+            let max = if (2638..=2643).contains(&i) { 0.02 } else { 0.005 };
+            assert!((got - prev).abs() < max, "{}: {} → {}", h, prev, got);
+            prev = got;
+        }
+
+        assert!(max_luminance_for_hue(f32::NAN).is_nan());
+        assert!(max_luminance_for_hue(f32::INFINITY).is_nan());
+    }
+
+    #[test]
+    fn test_is_in_gamut() {
+        assert!(super::is_in_gamut([0.0, 0.0, 0.0]));
+        assert!(super::is_in_gamut([1.0, 1.0, 1.0]));
+        assert!(super::is_in_gamut([-0.000001, 0.5, 1.000001]));
+        assert!(!super::is_in_gamut([0.0, 0.0, 1.1]));
+        assert!(!super::is_in_gamut([0.0, f32::NAN, 0.0]));
+    }
+
+    #[test]
+    fn test_to_srgb_oklch_in_gamut() {
+        for rgb in [[212, 33, 61], [233, 232, 231], [0, 0, 255], [10, 200, 30]]
+        {
+            let [l, c, h] = oklch_from_u8(rgb);
+            assert_eq!(rgb, super::to_srgb_oklch(l, c, h), "{:?}", [l, c, h]);
+        }
+    }
+
+    #[test]
+    fn test_to_srgb_oklch_out_of_gamut() {
+        assert_eq!([0, 0, 0], super::to_srgb_oklch(0.0, 0.4, 30.0));
+        assert_eq!([255, 255, 255], super::to_srgb_oklch(1.0, 0.4, 30.0));
+        for h in [0.0, 30.0, 90.0, 150.0, 200.0, 270.0, 330.0] {
+            let rgb = super::to_srgb_oklch(0.7, 0.4, h);
+            let [l, c, got_h] = oklch_from_u8(rgb);
+            approx::assert_abs_diff_eq!(0.7, l, epsilon = 0.01);
+            assert!(c < 0.4, "h: {}; c: {}", h, c);
+            let diff = (got_h - h + 180.0).rem_euclid(360.0) - 180.0;
+            assert!(diff.abs() < 2.0, "h: {}; got: {}", h, got_h);
+        }
+    }
+
+    #[test]
+    fn test_clamp_preserve_luminance() {
+        use super::clamp_preserve_luminance;
+
+        for rgb in [[0.0, 0.0, 0.0], [1.0, 1.0, 1.0], [0.2, 1.0, 0.7]] {
+            assert_eq!(rgb, clamp_preserve_luminance(rgb));
+        }
+
+        let got = clamp_preserve_luminance([0.4, 2.0, 0.8]);
+        assert_eq!([0.2, 1.0, 0.4], got);
+        assert!(super::is_in_gamut(got));
+
+        let got = clamp_preserve_luminance([3.0, 1.5, 0.3]);
+        approx::assert_abs_diff_eq!(&[1.0, 0.5, 0.1][..], &got[..]);
+    }
+
+    #[test]
+    fn test_excursion() {
+        use super::excursion;
+
+        for rgb in [[0.0, 0.0, 0.0], [1.0, 1.0, 1.0], [0.2, 1.0, 0.7]] {
+            let got = excursion(rgb);
+            assert_eq!([0.0; 3], got.channels);
+            assert_eq!(0.0, got.distance);
+            assert!(got.is_in_gamut());
+        }
+
+        let got = excursion([1.25, -0.5, 0.5]);
+        assert_eq!([0.25, -0.5, 0.0], got.channels);
+        approx::assert_abs_diff_eq!(0.5590170, got.distance);
+        assert!(!got.is_in_gamut());
+
+        let got = excursion([2.0, 3.0, -2.0]);
+        assert_eq!([1.0, 2.0, -2.0], got.channels);
+        assert_eq!(3.0, got.distance);
+
+        let got = excursion([f32::NAN, 0.5, 0.5]);
+        assert!(got.channels[0].is_nan());
+        assert!(!got.is_in_gamut());
+
+        // Vivid green which lies outside of sRGB gamut.
+        let linear = crate::oklab::linear_from_oklab([0.85, -0.3, 0.2]);
+        let got = excursion(linear);
+        assert!(got.channels[0] < 0.0, "{:?}", got);
+        assert!(!got.is_in_gamut());
+    }
+
+    #[test]
+    fn test_max_chroma() {
+        assert_eq!(0.0, super::max_chroma(0.0, 30.0));
+        assert_eq!(0.0, super::max_chroma(1.0, 30.0));
+        for l in [0.2, 0.5, 0.8] {
+            for h in [0.0, 30.0, 90.0, 150.0, 200.0, 270.0, 330.0] {
+                let c = super::max_chroma(l, h);
+                let (sin, cos) = f32::to_radians(h).sin_cos();
+                let at = |c: f32| {
+                    crate::oklab::linear_from_oklab([l, c * cos, c * sin])
+                };
+                assert!(super::is_in_gamut(at(c)), "l: {}; h: {}", l, h);
+                assert!(
+                    !super::is_in_gamut(at(c + 0.001)),
+                    "l: {}; h: {}",
+                    l,
+                    h
+                );
+            }
+        }
+    }
+}
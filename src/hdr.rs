@@ -0,0 +1,107 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Transfer functions used for high dynamic range content.
+//!
+//! The module implements Hybrid Log-Gamma (HLG) curve as defined in ARIB
+//! STD-B67 and ITU-R BT.2100.  HLG is used by broadcasters since its lower
+//! part matches conventional gamma curve making the signal viewable on
+//! standard dynamic range displays.  See [`crate::rec2020`] module for the
+//! colour space HDR content is usually encoded in.
+
+/// The `a` constant of the HLG curve.
+const HLG_A: f32 = 0.17883277;
+/// The `b` constant of the HLG curve, `1 - 4a`.
+const HLG_B: f32 = 0.28466892;
+/// The `c` constant of the HLG curve, `0.5 - a ln(4a)`.
+const HLG_C: f32 = 0.55991073;
+
+/// Applies HLG opto-electronic transfer function to a scene-linear component
+/// value.
+///
+/// The argument is normalised scene light in the range from zero to one.  The
+/// curve is a square root up to 1/12 (which maps to 0.5) and logarithmic
+/// above that.  Negative values are treated as zero.
+///
+/// # Example
+/// ```
+/// use srgb::hdr::hlg_oetf;
+///
+/// assert_eq!(0.0, hlg_oetf(0.0));
+/// assert_eq!(0.5, hlg_oetf(1.0 / 12.0));
+/// approx::assert_abs_diff_eq!(1.0, hlg_oetf(1.0), epsilon = 1e-6);
+/// ```
+pub fn hlg_oetf(linear: f32) -> f32 {
+    // max() also maps NaN to zero.
+    let linear = linear.max(0.0);
+    if linear <= 1.0 / 12.0 {
+        (3.0 * linear).sqrt()
+    } else {
+        HLG_A * (12.0 * linear - HLG_B).ln() + HLG_C
+    }
+}
+
+/// Converts an HLG-encoded component value into scene-linear light.
+///
+/// This is an inverse of [`hlg_oetf()`].  Note that BT.2100 HLG EOTF also
+/// includes the OOTF which depends on the display’s peak luminance; this
+/// function returns scene light and leaves applying the system gamma to the
+/// caller.  Negative values are treated as zero.
+///
+/// # Example
+/// ```
+/// use srgb::hdr::hlg_eotf;
+///
+/// assert_eq!(0.0, hlg_eotf(0.0));
+/// assert_eq!(1.0 / 12.0, hlg_eotf(0.5));
+/// approx::assert_abs_diff_eq!(1.0, hlg_eotf(1.0), epsilon = 1e-6);
+/// ```
+pub fn hlg_eotf(encoded: f32) -> f32 {
+    let encoded = encoded.max(0.0);
+    if encoded <= 0.5 {
+        encoded * encoded / 3.0
+    } else {
+        (((encoded - HLG_C) / HLG_A).exp() + HLG_B) / 12.0
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hlg_constants() {
+        approx::assert_abs_diff_eq!(1.0 - 4.0 * HLG_A, HLG_B);
+        let c = 0.5 - HLG_A * (4.0 * HLG_A).ln();
+        approx::assert_abs_diff_eq!(c, HLG_C, epsilon = 1e-7);
+    }
+
+    #[test]
+    fn test_hlg_reversible() {
+        let mut prev = -1.0;
+        for i in 0..=1000 {
+            let v = i as f32 / 1000.0;
+            let e = hlg_oetf(v);
+            assert!(prev < e, "{}: {} ≥ {}", v, prev, e);
+            assert!((0.0..=1.0).contains(&e), "{}: {}", v, e);
+            prev = e;
+            approx::assert_abs_diff_eq!(v, hlg_eotf(e), epsilon = 1e-6);
+        }
+        assert_eq!(0.0, hlg_oetf(-1.0));
+        assert_eq!(0.0, hlg_oetf(f32::NAN));
+        assert_eq!(0.0, hlg_eotf(-1.0));
+    }
+}
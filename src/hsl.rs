@@ -0,0 +1,217 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for converting between sRGB and hue, saturation and lightness
+//! triples.
+//!
+//! [`hsl_from_normalised()`] and [`normalised_from_hsl()`] (along with their
+//! 24-bit wrappers) implement the classic HSL model (as used by e.g. GIMP or
+//! CSS `hsl()` notation).  It’s a simple reparametrisation of gamma-encoded
+//! sRGB values and is **not** perceptual: colours with equal HSL lightness may
+//! look very differently bright.  [`vivid_from_hsl()`] on the other hand
+//! interprets the triple in Oklch colour space.
+
+/// Converts an sRGB colour in normalised representation into HSL.
+///
+/// The colour is given as gamma-encoded components in the range from zero to
+/// one.  The result is given as `[h, s, l]` triple where hue is in degrees in
+/// the range from zero (inclusive) to 360 (exclusive) while saturation and
+/// lightness are in the range from zero to one.  For achromatic colours (where
+/// all components are equal) hue and saturation are zero.
+///
+/// The calculations are done on gamma-encoded values thus the result is not
+/// perceptual.  See [module documentation](self).
+///
+/// # Example
+/// ```
+/// use srgb::hsl::hsl_from_normalised;
+///
+/// assert_eq!([0.0, 1.0, 0.5], hsl_from_normalised([1.0, 0.0, 0.0]));
+/// assert_eq!([240.0, 1.0, 0.25], hsl_from_normalised([0.0, 0.0, 0.5]));
+/// assert_eq!([0.0, 0.0, 0.75], hsl_from_normalised([0.75, 0.75, 0.75]));
+/// ```
+pub fn hsl_from_normalised(normalised: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [r, g, b] = normalised.into();
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) * 0.5;
+    let d = max - min;
+    if d <= 0.0 {
+        return [0.0, 0.0, l];
+    }
+    // Clamp since rounding errors may push saturation slightly above one.
+    let s = (d / (1.0 - (2.0 * l - 1.0).abs())).min(1.0);
+    let h = if max == r {
+        (g - b) / d
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    [crate::maths::wrap_angle(h * 60.0, 360.0), s, l]
+}
+
+/// Converts an HSL triple into sRGB colour in normalised representation.
+///
+/// This is an inverse of [`hsl_from_normalised()`].  Hue is given in degrees
+/// and may be any angle, i.e. it doesn’t have to be in the range from zero to
+/// 360.  The result is gamma-encoded.
+///
+/// # Example
+/// ```
+/// use srgb::hsl::normalised_from_hsl;
+///
+/// assert_eq!([1.0, 0.0, 0.0], normalised_from_hsl([0.0, 1.0, 0.5]));
+/// assert_eq!([0.0, 0.0, 0.5], normalised_from_hsl([-120.0, 1.0, 0.25]));
+/// ```
+pub fn normalised_from_hsl(hsl: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [h, s, l] = hsl.into();
+    let h = h.rem_euclid(360.0) / 30.0;
+    let a = s * l.min(1.0 - l);
+    let f = |n: f32| {
+        let k = (n + h).rem_euclid(12.0);
+        l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+    [f(0.0), f(8.0), f(4.0)]
+}
+
+/// Converts a 24-bit sRGB colour into HSL.
+///
+/// This is a convenience function which normalises the colour and calls
+/// [`hsl_from_normalised()`].
+///
+/// # Example
+/// ```
+/// let [h, s, l] = srgb::hsl::hsl_from_u8([212, 33, 61]);
+/// approx::assert_abs_diff_eq!(350.61, h, epsilon = 0.01);
+/// approx::assert_abs_diff_eq!(0.7306, s, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.4804, l, epsilon = 0.0001);
+/// ```
+pub fn hsl_from_u8(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
+    hsl_from_normalised(crate::normalised_from_u8(rgb))
+}
+
+/// Converts an HSL triple into 24-bit sRGB colour.
+///
+/// This is a convenience function which calls [`normalised_from_hsl()`] and
+/// converts the result into 24-bit representation.
+///
+/// # Example
+/// ```
+/// use srgb::hsl::{hsl_from_u8, u8_from_hsl};
+///
+/// assert_eq!([212, 33, 61], u8_from_hsl(hsl_from_u8([212, 33, 61])));
+/// assert_eq!([128, 128, 128], u8_from_hsl([42.0, 0.0, 0.5]));
+/// ```
+pub fn u8_from_hsl(hsl: impl Into<[f32; 3]>) -> [u8; 3] {
+    crate::u8_from_normalised(normalised_from_hsl(hsl))
+}
+
+/// Converts a hue, saturation and lightness triple into a vivid 24-bit sRGB
+/// colour.
+///
+/// The colour is given as `[h, s, l]` where hue `h` is in degrees while
+/// saturation `s` and lightness `l` are in the range from zero to one.
+///
+/// **This deviates from strict HSL.**  Rather than interpreting the triple in
+/// the HSL cylinder of sRGB (which yields colours of uneven perceived
+/// brightness and often muddy tints), the components are interpreted in Oklch
+/// colour space: `l` is used as Oklab lightness, `h` as Oklab hue and `s`
+/// scales chroma relative to the maximum chroma displayable in sRGB for given
+/// lightness and hue.  In particular, saturation of one produces the most
+/// vivid colour of given lightness and hue which lies within sRGB gamut.
+///
+/// Saturation outside of the range from zero to one is clamped.  Lightness of
+/// zero or less produces black while lightness of one or more produces white.
+///
+/// # Example
+/// ```
+/// assert_eq!([255, 255, 255], srgb::hsl::vivid_from_hsl([0.0, 1.0, 1.0]));
+/// assert_eq!([128, 128, 128], srgb::hsl::vivid_from_hsl([0.0, 0.0, 0.6]));
+/// assert_eq!([230, 0, 122], srgb::hsl::vivid_from_hsl([0.0, 1.0, 0.6]));
+/// ```
+pub fn vivid_from_hsl(hsl: impl Into<[f32; 3]>) -> [u8; 3] {
+    let [h, s, l] = hsl.into();
+    let c = s.clamp(0.0, 1.0) * crate::gamut::max_chroma(l, h);
+    crate::gamut::to_srgb_oklch(l, c, h)
+}
+
+
+#[cfg(test)]
+mod test {
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_reversible_conversion() {
+        for c in (0..(1 << 24)).step_by(97) {
+            let rgb = crate::from_u32(c);
+            let [h, s, l] = super::hsl_from_u8(rgb);
+            assert!((0.0..360.0).contains(&h), "{:?} → {}", rgb, h);
+            assert!((0.0..=1.0).contains(&s), "{:?} → {}", rgb, s);
+            assert!((0.0..=1.0).contains(&l), "{:?} → {}", rgb, l);
+            assert_eq!(rgb, super::u8_from_hsl([h, s, l]));
+            assert_eq!(rgb, super::u8_from_hsl([h - 360.0, s, l]));
+            assert_eq!(rgb, super::u8_from_hsl([h + 720.0, s, l]));
+        }
+    }
+
+    #[test]
+    fn test_achromatic() {
+        for v in 0..=255 {
+            let [h, s, l] = super::hsl_from_u8([v, v, v]);
+            assert_eq!([0.0, 0.0], [h, s], "{}", v);
+            assert_eq!(v as f32 / 255.0, l);
+        }
+    }
+
+    fn oklch_from_u8(rgb: [u8; 3]) -> [f32; 3] {
+        let linear = crate::gamma::linear_from_u8(rgb);
+        let oklab = crate::oklab::oklab_from_linear(linear);
+        crate::oklab::oklch_from_oklab_deg(oklab)
+    }
+
+    #[test]
+    fn test_vivid_on_gamut_boundary() {
+        for l in [0.3, 0.5, 0.7] {
+            for h in [0.0, 30.0, 90.0, 150.0, 200.0, 270.0, 330.0] {
+                let rgb = super::vivid_from_hsl([h, 1.0, l]);
+                // Colour on the gamut boundary has at least one component
+                // saturated.
+                assert!(
+                    rgb.iter().any(|&v| v <= 1 || v >= 254),
+                    "h: {}; l: {}; got: {:?}",
+                    h,
+                    l,
+                    rgb
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_vivid_hue_consistency() {
+        for h in [0.0, 30.0, 90.0, 150.0, 200.0, 270.0, 330.0] {
+            let mut prev_c = 0.0;
+            for s in [0.25, 0.5, 0.75, 1.0] {
+                let rgb = super::vivid_from_hsl([h, s, 0.6]);
+                let [l, c, got_h] = oklch_from_u8(rgb);
+                approx::assert_abs_diff_eq!(0.6, l, epsilon = 0.01);
+                let diff = (got_h - h + 180.0).rem_euclid(360.0) - 180.0;
+                assert!(diff.abs() < 2.0, "h: {}; s: {}; got: {}", h, s, got_h);
+                assert!(prev_c < c, "h: {}; s: {}; c: {}", h, s, c);
+                prev_c = c;
+            }
+        }
+    }
+}
@@ -0,0 +1,147 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for converting between sRGB and hue, saturation and value (also
+//! known as HSB) triples.
+//!
+//! Like classic HSL (see [`crate::hsl`] module), HSV is a reparametrisation of
+//! gamma-encoded sRGB values and is not perceptual.
+
+/// Converts an sRGB colour in normalised representation into HSV.
+///
+/// The colour is given as gamma-encoded components in the range from zero to
+/// one.  The result is given as `[h, s, v]` triple where hue is in degrees in
+/// the range from zero (inclusive) to 360 (exclusive) while saturation and
+/// value are in the range from zero to one.  For achromatic colours (where all
+/// components are equal) hue and saturation are zero and value is equal to
+/// the components.
+///
+/// # Example
+/// ```
+/// use srgb::hsv::hsv_from_normalised;
+///
+/// assert_eq!([0.0, 1.0, 1.0], hsv_from_normalised([1.0, 0.0, 0.0]));
+/// assert_eq!([240.0, 1.0, 0.5], hsv_from_normalised([0.0, 0.0, 0.5]));
+/// assert_eq!([0.0, 0.0, 0.75], hsv_from_normalised([0.75, 0.75, 0.75]));
+/// ```
+pub fn hsv_from_normalised(normalised: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [r, g, b] = normalised.into();
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d = max - min;
+    if d <= 0.0 {
+        return [0.0, 0.0, max];
+    }
+    let h = if max == r {
+        (g - b) / d
+    } else if max == g {
+        (b - r) / d + 2.0
+    } else {
+        (r - g) / d + 4.0
+    };
+    [crate::maths::wrap_angle(h * 60.0, 360.0), d / max, max]
+}
+
+/// Converts an HSV triple into sRGB colour in normalised representation.
+///
+/// This is an inverse of [`hsv_from_normalised()`].  Hue is given in degrees
+/// and may be any angle, i.e. it doesn’t have to be in the range from zero to
+/// 360.  The result is gamma-encoded.
+///
+/// # Example
+/// ```
+/// use srgb::hsv::normalised_from_hsv;
+///
+/// assert_eq!([1.0, 0.0, 0.0], normalised_from_hsv([0.0, 1.0, 1.0]));
+/// assert_eq!([0.0, 0.0, 0.5], normalised_from_hsv([-120.0, 1.0, 0.5]));
+/// ```
+pub fn normalised_from_hsv(hsv: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [h, s, v] = hsv.into();
+    let h = h.rem_euclid(360.0) / 60.0;
+    let c = v * s;
+    let min = v - c;
+    // Component which is neither the largest nor the smallest.
+    let mid = |k: f32| min + c * k;
+    match h as u8 {
+        0 => [v, mid(h), min],
+        1 => [mid(2.0 - h), v, min],
+        2 => [min, v, mid(h - 2.0)],
+        3 => [min, mid(4.0 - h), v],
+        4 => [mid(h - 4.0), min, v],
+        _ => [v, min, mid(6.0 - h)],
+    }
+}
+
+/// Converts a 24-bit sRGB colour into HSV.
+///
+/// This is a convenience function which normalises the colour and calls
+/// [`hsv_from_normalised()`].
+///
+/// # Example
+/// ```
+/// let [h, s, v] = srgb::hsv::hsv_from_u8([212, 33, 61]);
+/// approx::assert_abs_diff_eq!(350.61, h, epsilon = 0.01);
+/// approx::assert_abs_diff_eq!(0.8443, s, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.8314, v, epsilon = 0.0001);
+/// ```
+pub fn hsv_from_u8(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
+    hsv_from_normalised(crate::normalised_from_u8(rgb))
+}
+
+/// Converts an HSV triple into 24-bit sRGB colour.
+///
+/// This is a convenience function which calls [`normalised_from_hsv()`] and
+/// converts the result into 24-bit representation.
+///
+/// # Example
+/// ```
+/// use srgb::hsv::{hsv_from_u8, u8_from_hsv};
+///
+/// assert_eq!([212, 33, 61], u8_from_hsv(hsv_from_u8([212, 33, 61])));
+/// assert_eq!([128, 128, 128], u8_from_hsv([42.0, 0.0, 128.0 / 255.0]));
+/// ```
+pub fn u8_from_hsv(hsv: impl Into<[f32; 3]>) -> [u8; 3] {
+    crate::u8_from_normalised(normalised_from_hsv(hsv))
+}
+
+
+#[cfg(test)]
+mod test {
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_reversible_conversion() {
+        for c in (0..(1 << 24)).step_by(7) {
+            let rgb = crate::from_u32(c);
+            let src = crate::normalised_from_u8(rgb);
+            let [h, s, v] = super::hsv_from_normalised(src);
+            assert!((0.0..360.0).contains(&h), "{:?} → {}", rgb, h);
+            assert!((0.0..=1.0).contains(&s), "{:?} → {}", rgb, s);
+            assert_eq!(src.iter().copied().fold(0.0, f32::max), v);
+            let dst = super::normalised_from_hsv([h, s, v]);
+            approx::assert_abs_diff_eq!(&src[..], &dst[..], epsilon = 1e-6);
+            assert_eq!(rgb, crate::u8_from_normalised(dst));
+        }
+    }
+
+    #[test]
+    fn test_achromatic() {
+        for v in 0..=255 {
+            let [h, s, got] = super::hsv_from_u8([v, v, v]);
+            assert_eq!([0.0, 0.0], [h, s], "{}", v);
+            assert_eq!(v as f32 / 255.0, got);
+            assert_eq!([v, v, v], super::u8_from_hsv([h, s, got]));
+        }
+    }
+}
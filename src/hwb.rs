@@ -0,0 +1,135 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for converting between sRGB and hue, whiteness and blackness
+//! triples as defined in CSS Color Module Level 4.
+//!
+//! Like classic HSL (see [`crate::hsl`] module), HWB is a reparametrisation of
+//! gamma-encoded sRGB values and is not perceptual.
+
+/// Converts an sRGB colour in normalised representation into HWB.
+///
+/// The colour is given as gamma-encoded components in the range from zero to
+/// one.  The result is given as `[h, w, b]` triple where hue is in degrees in
+/// the range from zero (inclusive) to 360 (exclusive) while whiteness and
+/// blackness are in the range from zero to one.  For achromatic colours hue is
+/// zero.
+///
+/// # Example
+/// ```
+/// use srgb::hwb::hwb_from_normalised;
+///
+/// assert_eq!([0.0, 0.0, 0.0], hwb_from_normalised([1.0, 0.0, 0.0]));
+/// assert_eq!([120.0, 0.25, 0.5], hwb_from_normalised([0.25, 0.5, 0.25]));
+/// assert_eq!([0.0, 0.75, 0.25], hwb_from_normalised([0.75, 0.75, 0.75]));
+/// ```
+pub fn hwb_from_normalised(normalised: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [r, g, b] = normalised.into();
+    let [h, _, _] = crate::hsv::hsv_from_normalised([r, g, b]);
+    [h, r.min(g).min(b), 1.0 - r.max(g).max(b)]
+}
+
+/// Converts an HWB triple into sRGB colour in normalised representation.
+///
+/// This is an inverse of [`hwb_from_normalised()`].  Hue is given in degrees
+/// and may be any angle, i.e. it doesn’t have to be in the range from zero to
+/// 360.  The result is gamma-encoded.
+///
+/// If sum of whiteness and blackness is one or more, the result is a shade of
+/// grey with all components equal `w / (w + b)`.  This matches CSS which
+/// normalises whiteness and blackness in such case.
+///
+/// # Example
+/// ```
+/// use srgb::hwb::normalised_from_hwb;
+///
+/// assert_eq!([1.0, 0.0, 0.0], normalised_from_hwb([0.0, 0.0, 0.0]));
+/// assert_eq!([0.25, 0.5, 0.25], normalised_from_hwb([120.0, 0.25, 0.5]));
+/// assert_eq!([0.6, 0.6, 0.6], normalised_from_hwb([120.0, 0.6, 0.4]));
+/// assert_eq!([0.25, 0.25, 0.25], normalised_from_hwb([42.0, 1.0, 3.0]));
+/// ```
+pub fn normalised_from_hwb(hwb: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [h, w, b] = hwb.into();
+    if w + b >= 1.0 {
+        return [w / (w + b); 3];
+    }
+    let rgb = crate::hsl::normalised_from_hsl([h, 1.0, 0.5]);
+    rgb.map(|v| v * (1.0 - w - b) + w)
+}
+
+/// Converts a 24-bit sRGB colour into HWB.
+///
+/// This is a convenience function which normalises the colour and calls
+/// [`hwb_from_normalised()`].
+///
+/// # Example
+/// ```
+/// let [h, w, b] = srgb::hwb::hwb_from_u8([212, 33, 61]);
+/// approx::assert_abs_diff_eq!(350.61, h, epsilon = 0.01);
+/// approx::assert_abs_diff_eq!(0.1294, w, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.1686, b, epsilon = 0.0001);
+/// ```
+pub fn hwb_from_u8(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
+    hwb_from_normalised(crate::normalised_from_u8(rgb))
+}
+
+/// Converts an HWB triple into 24-bit sRGB colour.
+///
+/// This is a convenience function which calls [`normalised_from_hwb()`] and
+/// converts the result into 24-bit representation.
+///
+/// # Example
+/// ```
+/// use srgb::hwb::{hwb_from_u8, u8_from_hwb};
+///
+/// assert_eq!([212, 33, 61], u8_from_hwb(hwb_from_u8([212, 33, 61])));
+/// assert_eq!([0, 128, 0], u8_from_hwb([120.0, 0.0, 0.498]));
+/// ```
+pub fn u8_from_hwb(hwb: impl Into<[f32; 3]>) -> [u8; 3] {
+    crate::u8_from_normalised(normalised_from_hwb(hwb))
+}
+
+
+#[cfg(test)]
+mod test {
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_reversible_conversion() {
+        for c in (0..(1 << 24)).step_by(97) {
+            let rgb = crate::from_u32(c);
+            let [h, w, b] = super::hwb_from_u8(rgb);
+            assert!((0.0..360.0).contains(&h), "{:?} → {}", rgb, h);
+            assert!(w + b <= 1.0, "{:?} → {} + {}", rgb, w, b);
+            assert_eq!(rgb, super::u8_from_hwb([h, w, b]));
+            assert_eq!(rgb, super::u8_from_hwb([h - 360.0, w, b]));
+        }
+    }
+
+    #[test]
+    fn test_grey() {
+        let cases =
+            [(1.0, 0.0), (0.0, 1.0), (0.5, 0.5), (2.0, 2.0), (3.0, 1.0)];
+        for (w, b) in cases {
+            let want = w / (w + b);
+            assert_eq!([want; 3], super::normalised_from_hwb([42.0, w, b]));
+        }
+        for v in 0..=255 {
+            let [h, w, b] = super::hwb_from_u8([v, v, v]);
+            assert_eq!(0.0, h);
+            approx::assert_abs_diff_eq!(1.0, w + b, epsilon = 1e-6);
+            assert_eq!([v, v, v], super::u8_from_hwb([h, w, b]));
+        }
+    }
+}
@@ -0,0 +1,253 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions implementing conversion into CIELAB colour space and calculating
+//! CIEDE2000 colour difference of images.
+//!
+//! CIELAB coordinates are calculated relative to the D65 white point (see
+//! [`crate::xyz::D65_XYZ`]) which is the white point of sRGB colour space.
+//! Functions with `_wp` suffix take the reference white explicitly which is
+//! useful when working with e.g. ICC profiles which use D50 white point.
+
+/// Converts a colour in XYZ colour space into CIELAB colour space.
+///
+/// The XYZ colour space should be such where white colour has Y coordinate
+/// equal one.  The result is given as `[L*, a*, b*]` triple where lightness is
+/// in the range from zero to a hundred.
+///
+/// # Example
+/// ```
+/// use srgb::lab::lab_from_xyz;
+///
+/// let [l, a, b] = lab_from_xyz(srgb::xyz::D65_XYZ);
+/// approx::assert_abs_diff_eq!(100.0, l, epsilon = 0.001);
+/// approx::assert_abs_diff_eq!(0.0, a, epsilon = 0.001);
+/// approx::assert_abs_diff_eq!(0.0, b, epsilon = 0.001);
+/// ```
+pub fn lab_from_xyz(xyz: impl Into<[f32; 3]>) -> [f32; 3] {
+    lab_from_xyz_wp(xyz, crate::xyz::D65_XYZ)
+}
+
+/// Converts a colour in XYZ colour space into CIELAB colour space relative to
+/// given white point.
+///
+/// This is like [`lab_from_xyz()`] except that the reference white is given
+/// explicitly as XYZ coordinates of the white point (normalised such that Y
+/// equals one).
+///
+/// # Example
+/// ```
+/// use srgb::lab::lab_from_xyz_wp;
+/// use srgb::xyz::D50_XYZ;
+///
+/// assert_eq!([100.0, 0.0, 0.0], lab_from_xyz_wp(D50_XYZ, D50_XYZ));
+/// ```
+pub fn lab_from_xyz_wp(
+    xyz: impl Into<[f32; 3]>,
+    white: impl Into<[f32; 3]>,
+) -> [f32; 3] {
+    let [x, y, z] = xyz.into();
+    let [wx, wy, wz] = white.into();
+    let (fx, fy, fz) = (f(x / wx), f(y / wy), f(z / wz));
+    [116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz)]
+}
+
+/// Converts a colour in CIELAB colour space into XYZ colour space.
+///
+/// This is an inverse of [`lab_from_xyz()`].  The result is given in XYZ
+/// colour space where white colour has Y coordinate equal one.
+///
+/// # Example
+/// ```
+/// use srgb::lab::xyz_from_lab;
+/// use srgb::xyz::D65_XYZ;
+///
+/// let xyz = xyz_from_lab([100.0, 0.0, 0.0]);
+/// approx::assert_abs_diff_eq!(&D65_XYZ[..], &xyz[..], epsilon = 0.0001);
+/// assert_eq!([0.0, 0.0, 0.0], xyz_from_lab([0.0, 0.0, 0.0]));
+/// ```
+pub fn xyz_from_lab(lab: impl Into<[f32; 3]>) -> [f32; 3] {
+    xyz_from_lab_wp(lab, crate::xyz::D65_XYZ)
+}
+
+/// Converts a colour in CIELAB colour space relative to given white point into
+/// XYZ colour space.
+///
+/// This is an inverse of [`lab_from_xyz_wp()`].
+///
+/// # Example
+/// ```
+/// use srgb::lab::{lab_from_xyz_wp, xyz_from_lab_wp};
+/// use srgb::xyz::D50_XYZ;
+///
+/// let xyz = [0.4, 0.3, 0.2];
+/// let got = xyz_from_lab_wp(lab_from_xyz_wp(xyz, D50_XYZ), D50_XYZ);
+/// approx::assert_abs_diff_eq!(&xyz[..], &got[..], epsilon = 0.0001);
+/// ```
+pub fn xyz_from_lab_wp(
+    lab: impl Into<[f32; 3]>,
+    white: impl Into<[f32; 3]>,
+) -> [f32; 3] {
+    let [l, a, b] = lab.into();
+    let [wx, wy, wz] = white.into();
+    let fy = (l + 16.0) / 116.0;
+    let (fx, fz) = (fy + a / 500.0, fy - b / 200.0);
+    [f_inv(fx) * wx, f_inv(fy) * wy, f_inv(fz) * wz]
+}
+
+/// Converts a 24-bit sRGB colour into CIELAB colour space.
+///
+/// This is just a convenience function which wraps gamma (see
+/// [`crate::gamma`] module), XYZ (see [`crate::xyz`] module) and
+/// [`lab_from_xyz()`] conversions together.
+///
+/// # Example
+/// ```
+/// let [l, a, b] = srgb::lab::lab_from_u8([212, 33, 61]);
+/// approx::assert_abs_diff_eq!(46.21, l, epsilon = 0.01);
+/// approx::assert_abs_diff_eq!(66.66, a, epsilon = 0.01);
+/// approx::assert_abs_diff_eq!(31.63, b, epsilon = 0.01);
+/// ```
+pub fn lab_from_u8(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
+    lab_from_xyz(crate::xyz_from_u8(rgb))
+}
+
+/// Non-linear function used in XYZ → CIELAB conversion.
+fn f(t: f32) -> f32 {
+    const DELTA: f32 = 6.0 / 29.0;
+    if t > DELTA * DELTA * DELTA {
+        t.cbrt()
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+/// Inverse of [`f()`] used in CIELAB → XYZ conversion.
+fn f_inv(t: f32) -> f32 {
+    const DELTA: f32 = 6.0 / 29.0;
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+
+#[doc(inline)]
+pub use crate::delta::delta_e_2000;
+
+
+/// Calculates per-pixel CIEDE2000 colour difference between two images.
+///
+/// `a` and `b` are images given as 24-bit sRGB colours and the difference
+/// between corresponding pixels is stored in `out`.  This is equivalent to
+/// calling [`delta_e_2000()`] for each pair of pixels converted with
+/// [`lab_from_u8()`].  Use [`mean_delta_e()`] and [`max_delta_e()`] to
+/// summarise the result.
+///
+/// # Panics
+///
+/// Panics if `a`, `b` and `out` don’t all have the same length.
+///
+/// # Example
+/// ```
+/// use srgb::lab::{delta_e_2000_slice, max_delta_e, mean_delta_e};
+///
+/// let a = [[212, 33, 61], [0, 0, 0], [255, 255, 255]];
+/// let b = [[212, 33, 61], [255, 255, 255], [255, 255, 255]];
+/// let mut out = [0.0; 3];
+/// delta_e_2000_slice(&a, &b, &mut out);
+/// assert_eq!([0.0, 100.0, 0.0], out);
+/// approx::assert_abs_diff_eq!(33.333332, mean_delta_e(&out));
+/// assert_eq!(100.0, max_delta_e(&out));
+/// ```
+pub fn delta_e_2000_slice(a: &[[u8; 3]], b: &[[u8; 3]], out: &mut [f32]) {
+    assert_eq!(a.len(), b.len());
+    assert_eq!(a.len(), out.len());
+    for ((a, b), out) in a.iter().zip(b.iter()).zip(out.iter_mut()) {
+        *out = if a == b {
+            0.0
+        } else {
+            delta_e_2000(lab_from_u8(*a), lab_from_u8(*b))
+        };
+    }
+}
+
+/// Returns the arithmetic mean of colour differences.
+///
+/// Returns zero if `values` is empty.  See [`delta_e_2000_slice()`] for an
+/// example.
+pub fn mean_delta_e(values: &[f32]) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    let sum = values.iter().map(|v| *v as f64).sum::<f64>();
+    (sum / values.len() as f64) as f32
+}
+
+/// Returns the largest of colour differences.
+///
+/// Returns zero if `values` is empty.  See [`delta_e_2000_slice()`] for an
+/// example.
+pub fn max_delta_e(values: &[f32]) -> f32 {
+    values.iter().copied().fold(0.0, f32::max)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reversible_conversion() {
+        use crate::xyz::D50_XYZ;
+
+        for c in 0..(16 * 16 * 16) {
+            let r = (c & 15) as f32 / 15.0;
+            let g = ((c >> 4) & 15) as f32 / 15.0;
+            let b = ((c >> 8) & 15) as f32 / 15.0;
+            let src = crate::xyz::xyz_from_linear([r, g, b]);
+            let dst = xyz_from_lab(lab_from_xyz(src));
+            approx::assert_abs_diff_eq!(&src[..], &dst[..], epsilon = 0.0001);
+            let dst = xyz_from_lab_wp(lab_from_xyz_wp(src, D50_XYZ), D50_XYZ);
+            approx::assert_abs_diff_eq!(&src[..], &dst[..], epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_delta_e_2000_slice() {
+        let a =
+            (0..1000).map(|i| crate::from_u32(i * 16777)).collect::<Vec<_>>();
+        let mut out = vec![1.0; a.len()];
+        delta_e_2000_slice(&a, &a, &mut out);
+        assert!(out.iter().all(|v| *v == 0.0));
+        assert_eq!(0.0, mean_delta_e(&out));
+        assert_eq!(0.0, max_delta_e(&out));
+
+        let mut b = a.clone();
+        b[42] = [b[42][0] ^ 0x80, b[42][1], b[42][2]];
+        let want = delta_e_2000(lab_from_u8(a[42]), lab_from_u8(b[42]));
+        assert!(want > 1.0, "{}", want);
+        delta_e_2000_slice(&a, &b, &mut out);
+        for (i, got) in out.iter().enumerate() {
+            assert_eq!(if i == 42 { want } else { 0.0 }, *got, "{}", i);
+        }
+        approx::assert_abs_diff_eq!(want / 1000.0, mean_delta_e(&out));
+        assert_eq!(want, max_delta_e(&out));
+
+        assert_eq!(0.0, mean_delta_e(&[]));
+        assert_eq!(0.0, max_delta_e(&[]));
+    }
+}
@@ -0,0 +1,115 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions implementing conversion between CIELAB and its polar form,
+//! CIELCh(ab).
+//!
+//! CIELCh describes colour by lightness, chroma and hue.  Lightness is the
+//! same as CIELAB L* while chroma and hue are polar coordinates of the a* and
+//! b* axes.  Hue is given in degrees.  See [`crate::lab`] module for conversion
+//! into CIELAB.
+
+/// Chroma below which colour is treated as achromatic when converting into
+/// CIELCh.
+///
+/// Like [`crate::oklab::ACHROMATIC_CHROMA`] this hides rounding errors
+/// which make greys have non-zero a* and b* coordinates.  Colours whose chroma
+/// is below this threshold get hue of zero.
+pub const ACHROMATIC_CHROMA: f32 = 1e-3;
+
+/// Converts a colour in CIELAB colour space into CIELCh.
+///
+/// The result is given as `[L*, C*, h]` triple where lightness is passed
+/// through unchanged, chroma is non-negative and hue is in degrees in the range
+/// from zero (inclusive) to 360 (exclusive).  Chroma is unbounded; in
+/// particular, highly saturated sRGB colours have chroma exceeding a hundred.
+///
+/// For achromatic colours hue is undefined and zero is returned.  See
+/// [`ACHROMATIC_CHROMA`].
+///
+/// # Example
+/// ```
+/// use srgb::lch::lch_from_lab;
+///
+/// let [l, c, h] = lch_from_lab(srgb::lab::lab_from_u8([255, 0, 0]));
+/// approx::assert_abs_diff_eq!(53.24, l, epsilon = 0.01);
+/// approx::assert_abs_diff_eq!(104.55, c, epsilon = 0.01);
+/// approx::assert_abs_diff_eq!(40.0, h, epsilon = 0.01);
+/// ```
+pub fn lch_from_lab(lab: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [l, a, b] = lab.into();
+    let c = a.hypot(b);
+    if c < ACHROMATIC_CHROMA {
+        return [l, c, 0.0];
+    }
+    [l, c, crate::maths::wrap_angle(b.atan2(a).to_degrees(), 360.0)]
+}
+
+/// Converts a colour in CIELCh colour space into CIELAB.
+///
+/// This is an inverse of [`lch_from_lab()`].  Hue is given in degrees and may
+/// be any angle; it’s normalised to the range from zero to 360 before the
+/// conversion which makes it possible to interpolate hue without wrapping it
+/// first.  Lightness is passed through unchanged.
+///
+/// # Example
+/// ```
+/// use srgb::lch::lab_from_lch;
+///
+/// let [l, a, b] = lab_from_lch([50.0, 20.0, -270.0]);
+/// assert_eq!(50.0, l);
+/// approx::assert_abs_diff_eq!(0.0, a, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(20.0, b, epsilon = 0.0001);
+/// ```
+pub fn lab_from_lch(lch: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [l, c, h] = lch.into();
+    let (sin, cos) = h.rem_euclid(360.0).to_radians().sin_cos();
+    [l, c * cos, c * sin]
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reversible_conversion() {
+        for l in [0.0, 25.0, 50.0, 100.0] {
+            for c in [1.0, 30.0, 150.0] {
+                for h in (-720..720).step_by(15) {
+                    let h = h as f32;
+                    let lab = lab_from_lch([l, c, h]);
+                    let [got_l, got_c, got_h] = lch_from_lab(lab);
+                    assert_eq!(l, got_l);
+                    approx::assert_abs_diff_eq!(c, got_c, epsilon = 0.0001);
+                    assert!((0.0..360.0).contains(&got_h), "{}", got_h);
+                    let diff = (got_h - h + 180.0).rem_euclid(360.0) - 180.0;
+                    assert!(diff.abs() < 0.001, "{} vs {}", h, got_h);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_achromatic() {
+        for v in 0..=255 {
+            let lab = crate::lab::lab_from_u8([v, v, v]);
+            let [l, c, h] = lch_from_lab(lab);
+            assert_eq!(lab[0], l);
+            assert!(c < ACHROMATIC_CHROMA, "{}: {}", v, c);
+            assert_eq!(0.0, h, "{}", v);
+        }
+    }
+}
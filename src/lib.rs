@@ -14,14 +14,56 @@
  * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
 
 #![doc = include_str!("../README.md")]
+#![cfg_attr(feature = "portable-simd", feature(portable_simd))]
 #![allow(clippy::excessive_precision)]
 #![allow(clippy::needless_doctest_main)]
 
+pub mod adapt;
+pub mod alpha;
+pub mod analyse;
+#[cfg(feature = "bytemuck")]
+pub mod buffer;
+pub mod css;
+pub mod delta;
+pub mod diagram;
+pub mod dither;
+pub mod filter;
 pub mod gamma;
+pub mod gamut;
+pub mod hdr;
+pub mod hsl;
+pub mod hsv;
+pub mod hwb;
+pub mod lab;
+pub mod lch;
+pub mod lms;
+pub mod luv;
+pub mod oklab;
+pub mod oklch;
+pub mod p3;
+#[cfg(feature = "palette")]
+pub mod palette;
+pub mod photometric;
+pub mod quantise;
+pub mod rct;
+pub mod rec2020;
+pub mod sample;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod space;
+pub mod spectral;
+pub mod stats;
+pub mod term;
+pub mod transfer;
+pub mod types;
+pub mod wcag;
 pub mod xyz;
+pub mod ycocg;
 
 mod maths;
 
+pub use maths::{active_backend, inverse, Backend, SingularMatrix};
+
 
 /// Converts a 24-bit sRGB colour (also known as true colour) into normalised
 /// representation.
@@ -71,10 +113,225 @@ pub fn u8_from_normalised(normalised: impl Into<[f32; 3]>) -> [u8; 3] {
 }
 
 
+/// Unpacks a 24-bit sRGB colour from a `0xRRGGBB` integer.
+///
+/// The red component is taken from bits 16–23, green from bits 8–15 and blue
+/// from bits 0–7.  The most significant byte is ignored.  Note that this
+/// describes the numeric value of the integer and not its representation in
+/// memory, i.e. the result doesn’t depend on platform’s endianness.
+///
+/// # Example
+/// ```
+/// assert_eq!([212, 33, 61], srgb::from_u32(0xd4213d));
+/// assert_eq!([212, 33, 61], srgb::from_u32(0xffd4213d));
+/// ```
+pub fn from_u32(packed: u32) -> [u8; 3] {
+    let [_, r, g, b] = packed.to_be_bytes();
+    [r, g, b]
+}
+
+/// Packs a 24-bit sRGB colour into a `0xRRGGBB` integer.
+///
+/// This is an inverse of [`from_u32()`].  The most significant byte of the
+/// result is always zero.
+///
+/// # Example
+/// ```
+/// assert_eq!(0xd4213d, srgb::to_u32([212, 33, 61]));
+/// ```
+pub fn to_u32(rgb: impl Into<[u8; 3]>) -> u32 {
+    let [r, g, b] = rgb.into();
+    u32::from_be_bytes([0, r, g, b])
+}
+
+/// Unpacks an sRGB colour with alpha channel from a `0xAARRGGBB` integer.
+///
+/// The alpha component is taken from bits 24–31, red from bits 16–23, green
+/// from bits 8–15 and blue from bits 0–7.  The result is returned in `[red,
+/// green, blue, alpha]` order.  As with [`from_u32()`], this describes the
+/// numeric value of the integer and not its representation in memory.
+///
+/// # Example
+/// ```
+/// assert_eq!([212, 33, 61, 128], srgb::from_argb_u32(0x80d4213d));
+/// ```
+pub fn from_argb_u32(packed: u32) -> [u8; 4] {
+    let [a, r, g, b] = packed.to_be_bytes();
+    [r, g, b, a]
+}
+
+/// Packs an sRGB colour with alpha channel into a `0xAARRGGBB` integer.
+///
+/// The colour is given in `[red, green, blue, alpha]` order.  This is an
+/// inverse of [`from_argb_u32()`].
+///
+/// # Example
+/// ```
+/// assert_eq!(0x80d4213d, srgb::to_argb_u32([212, 33, 61, 128]));
+/// ```
+pub fn to_argb_u32(rgba: [u8; 4]) -> u32 {
+    let [r, g, b, a] = rgba;
+    u32::from_be_bytes([a, r, g, b])
+}
+
+/// Unpacks an sRGB colour with alpha channel from a `0xRRGGBBAA` integer.
+///
+/// The red component is taken from bits 24–31, green from bits 16–23, blue
+/// from bits 8–15 and alpha from bits 0–7.  The result is returned in `[red,
+/// green, blue, alpha]` order.  As with [`from_u32()`], this describes the
+/// numeric value of the integer and not its representation in memory.
+///
+/// # Example
+/// ```
+/// assert_eq!([212, 33, 61, 128], srgb::from_rgba_u32(0xd4213d80));
+/// ```
+pub fn from_rgba_u32(packed: u32) -> [u8; 4] { packed.to_be_bytes() }
+
+/// Packs an sRGB colour with alpha channel into a `0xRRGGBBAA` integer.
+///
+/// The colour is given in `[red, green, blue, alpha]` order.  This is an
+/// inverse of [`from_rgba_u32()`].
+///
+/// # Example
+/// ```
+/// assert_eq!(0xd4213d80, srgb::to_rgba_u32([212, 33, 61, 128]));
+/// ```
+pub fn to_rgba_u32(rgba: [u8; 4]) -> u32 { u32::from_be_bytes(rgba) }
+
+/// Converts a colour with single precision components into one with double
+/// precision components.
+///
+/// This is a lossless per-component cast useful when mixing single and double
+/// precision code.  See [`to_f32()`] for the inverse.
+///
+/// # Example
+/// ```
+/// assert_eq!([0.5, 0.25, 1.0], srgb::to_f64([0.5, 0.25, 1.0]));
+/// ```
+#[inline]
+pub fn to_f64(value: impl Into<[f32; 3]>) -> [f64; 3] {
+    arr_map(value, f64::from)
+}
+
+/// Converts a colour with double precision components into one with single
+/// precision components.
+///
+/// This is a per-component cast which rounds each component to the nearest
+/// `f32` value and thus is lossy.  Values too large to be represented become
+/// infinities.  See [`to_f64()`] for the inverse.
+///
+/// # Example
+/// ```
+/// assert_eq!([0.5, 0.1, 1.0], srgb::to_f32([0.5, 0.1, 1.0]));
+/// ```
+#[inline]
+pub fn to_f32(value: impl Into<[f64; 3]>) -> [f32; 3] {
+    arr_map(value, |v| v as f32)
+}
+
+
+/// Parses a 24-bit sRGB colour from a hexadecimal string.
+///
+/// Accepts `#rrggbb` and `#rgb` notations with the leading hash being
+/// optional.  Digits may be lower- or upper-case.  Returns `None` if the
+/// string is not in one of those formats.  In the short notation, each digit
+/// is repeated, i.e. `#abc` is equivalent to `#aabbcc`.
+///
+/// This is a `const fn` and thus can be used to define constants.  See also
+/// [`srgb!`] macro which validates the string at compile time.
+///
+/// # Example
+/// ```
+/// assert_eq!(Some([212, 33, 61]), srgb::parse_hex("#d4213d"));
+/// assert_eq!(Some([212, 33, 61]), srgb::parse_hex("D4213D"));
+/// assert_eq!(Some([255, 0, 204]), srgb::parse_hex("#f0c"));
+/// assert_eq!(None, srgb::parse_hex("#d4213"));
+/// assert_eq!(None, srgb::parse_hex("#d4213g"));
+/// ```
+pub const fn parse_hex(hex: &str) -> Option<[u8; 3]> {
+    const fn digit(ch: u8) -> Option<u8> {
+        match ch {
+            b'0'..=b'9' => Some(ch - b'0'),
+            b'a'..=b'f' => Some(ch - b'a' + 10),
+            b'A'..=b'F' => Some(ch - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let bytes = hex.as_bytes();
+    let (start, len) = match bytes {
+        [b'#', ..] => (1, bytes.len() - 1),
+        _ => (0, bytes.len()),
+    };
+    let short = match len {
+        3 => true,
+        6 => false,
+        _ => return None,
+    };
+
+    let mut rgb = [0; 3];
+    let mut i = 0;
+    while i < len {
+        let value = match digit(bytes[start + i]) {
+            Some(value) => value,
+            None => return None,
+        };
+        if short {
+            rgb[i] = value * 17;
+        } else {
+            rgb[i / 2] = rgb[i / 2] * 16 + value;
+        }
+        i += 1;
+    }
+    Some(rgb)
+}
+
+/// Creates a 24-bit sRGB colour constant.
+///
+/// The macro accepts either a hexadecimal string literal (in formats accepted
+/// by [`parse_hex()`]) or three components.  In both cases the result is
+/// evaluated at compile time and invalid colours result in build errors.
+///
+/// # Example
+/// ```
+/// const RED: [u8; 3] = srgb::srgb!("#d4213d");
+/// assert_eq!([212, 33, 61], RED);
+/// assert_eq!([255, 0, 204], srgb::srgb!("f0c"));
+/// assert_eq!([212, 33, 61], srgb::srgb!(212, 33, 61));
+/// ```
+///
+/// Invalid hexadecimal strings are rejected at compile time:
+///
+/// ```compile_fail
+/// let _ = srgb::srgb!("#d4213g");
+/// ```
+///
+/// As are out-of-range components:
+///
+/// ```compile_fail
+/// let _ = srgb::srgb!(212, 33, 256);
+/// ```
+#[macro_export]
+macro_rules! srgb {
+    ($hex:literal) => {{
+        const RGB: [u8; 3] = match $crate::parse_hex($hex) {
+            Some(rgb) => rgb,
+            None => panic!(concat!("invalid sRGB colour: ", $hex)),
+        };
+        RGB
+    }};
+    ($r:expr, $g:expr, $b:expr $(,)?) => {{
+        const RGB: [u8; 3] = [$r, $g, $b];
+        RGB
+    }};
+}
+
+
 /// Converts a colour in an XYZ colour space into 24-bit sRGB representation.
 ///
 /// This is just a convenience function which wraps gamma (see [`gamma`] module)
-/// and XYZ (see [`xyz`] module) conversions function together.
+/// and XYZ (see [`xyz`] module) conversions function together.  It always uses
+/// sRGB transfer function; see [`transfer`] module for other encodings.
 pub fn u8_from_xyz(xyz: impl Into<[f32; 3]>) -> [u8; 3] {
     gamma::u8_from_linear(xyz::linear_from_xyz(xyz))
 }
@@ -82,7 +339,8 @@ pub fn u8_from_xyz(xyz: impl Into<[f32; 3]>) -> [u8; 3] {
 /// Converts a 24-bit sRGB colour into XYZ colour space.
 ///
 /// This is just a convenience function which wraps gamma (see [`gamma`] module)
-/// and XYZ (see [`xyz`] module) conversions function together.
+/// and XYZ (see [`xyz`] module) conversions function together.  It always uses
+/// sRGB transfer function; see [`transfer`] module for other encodings.
 pub fn xyz_from_u8(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
     xyz::xyz_from_linear(gamma::linear_from_u8(rgb))
 }
@@ -104,6 +362,732 @@ pub fn xyz_from_normalised(rgb: impl Into<[f32; 3]>) -> [f32; 3] {
     xyz::xyz_from_linear(gamma::linear_from_normalised(rgb))
 }
 
+/// Calculates relative luminance of a colour in linear sRGB space.
+///
+/// The result is the CIE Y coordinate, i.e. the same as the second component
+/// of [`xyz::xyz_from_linear()`] result, with white having luminance equal
+/// one.  Calculating just the luminance avoids computing the two other
+/// coordinates.
+///
+/// # Example
+/// ```
+/// assert_eq!(1.0, srgb::luminance_from_linear([1.0, 1.0, 1.0]));
+/// assert_eq!(0.0, srgb::luminance_from_linear([0.0, 0.0, 0.0]));
+/// approx::assert_abs_diff_eq!(
+///     0.2126,
+///     srgb::luminance_from_linear([1.0, 0.0, 0.0]),
+///     epsilon = 0.0001
+/// );
+/// ```
+pub fn luminance_from_linear(linear: impl Into<[f32; 3]>) -> f32 {
+    let [r, g, b] = linear.into();
+    let [kr, kg, kb] = xyz::XYZ_FROM_SRGB_MATRIX[1];
+    maths::mul_add(kb, b, maths::mul_add(kg, g, kr * r))
+}
+
+/// Calculates relative luminance of a 24-bit sRGB colour.
+///
+/// This is a convenience function which performs gamma expansion followed by
+/// [`luminance_from_linear()`].  The result is the CIE Y coordinate with white
+/// having luminance equal one.
+///
+/// # Example
+/// ```
+/// assert_eq!(1.0, srgb::luminance_from_u8([255, 255, 255]));
+/// let y = srgb::luminance_from_u8([212, 33, 61]);
+/// approx::assert_abs_diff_eq!(srgb::xyz_from_u8([212, 33, 61])[1], y);
+/// ```
+pub fn luminance_from_u8(rgb: impl Into<[u8; 3]>) -> f32 {
+    luminance_from_linear(gamma::linear_from_u8(rgb))
+}
+
+
+/// Finds 24-bit sRGB colour with given chromaticity and luminance.
+///
+/// `chromaticity` is given as CIE `(x, y)` coordinates and `luminance` is the
+/// Y coordinate of the colour in XYZ colour space (with white having
+/// luminance of one).  Returns `None` if such colour lies outside of the sRGB
+/// gamut, e.g. if it’s too bright for given saturation.  This is useful for
+/// generating test patterns and palettes of colours with equal luminance.
+///
+/// # Example
+/// ```
+/// let d65 = (0.312713, 0.329016);
+/// assert_eq!(Some([188, 188, 188]), srgb::with_luminance(d65, 0.5));
+///
+/// // sRGB red primary.
+/// let red = (0.64, 0.33);
+/// assert_eq!(Some([255, 0, 0]), srgb::with_luminance(red, 0.2126));
+/// assert_eq!(None, srgb::with_luminance(red, 0.5));
+/// ```
+pub fn with_luminance(
+    chromaticity: (f32, f32),
+    luminance: f32,
+) -> Option<[u8; 3]> {
+    let (x, y) = chromaticity;
+    let scale = luminance / y;
+    let xyz = [x * scale, luminance, (1.0 - x - y) * scale];
+    let linear = xyz::linear_from_xyz(xyz);
+    (y > 0.0 && gamut::is_in_gamut(linear))
+        .then(|| gamma::u8_from_linear(linear))
+}
+
+/// Trade-off between speed and accuracy of conversions.
+///
+/// The crate offers several implementations of some of the conversions which
+/// differ in speed and precision.  This enum selects between them in functions
+/// such as [`u8_from_linear_with()`] and [`u8_from_xyz_with()`].  Precisely:
+///
+/// * Expansion of 8-bit values is the same at all levels (see
+///   [`gamma::expand_u8()`]).  By default it uses a lookup table which is both
+///   the fastest and the most accurate method.  With `no-lut` feature it
+///   calculates the value with the sRGB formula in double precision instead.
+/// * [`Quality::Fast`] compresses linear values into 8-bit ones with
+///   [`gamma::compress_u8()`] which uses an approximation of the sRGB formula.
+///   It occasionally rounds to a neighbouring value.
+/// * [`Quality::Balanced`] compresses linear values with
+///   [`gamma::compress_u8_precise()`] which uses the exact formula.
+/// * [`Quality::Precise`] compresses the same way as `Balanced` but in
+///   addition performs matrix multiplications (e.g. when converting from XYZ
+///   colour space) in double precision.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Quality {
+    /// Use the fastest methods.
+    Fast,
+    /// Use exact formulæ with single precision.
+    #[default]
+    Balanced,
+    /// Use exact formulæ with additional precision where possible.
+    Precise,
+}
+
+/// Converts an sRGB colour in linear space to a 24-bit sRGB colour using
+/// conversion of given quality.
+///
+/// With [`Quality::Fast`] this is equivalent to [`gamma::u8_from_linear()`],
+/// otherwise each component is compressed with
+/// [`gamma::compress_u8_precise()`].
+///
+/// # Example
+/// ```
+/// use srgb::Quality;
+///
+/// let linear = [0.6583748, 0.015208514, 0.046665084];
+/// let got = srgb::u8_from_linear_with(linear, Quality::Fast);
+/// assert_eq!([212, 33, 61], got);
+/// let got = srgb::u8_from_linear_with(linear, Quality::Precise);
+/// assert_eq!([212, 33, 61], got);
+/// ```
+pub fn u8_from_linear_with(
+    linear: impl Into<[f32; 3]>,
+    quality: Quality,
+) -> [u8; 3] {
+    match quality {
+        Quality::Fast => gamma::u8_from_linear(linear),
+        Quality::Balanced | Quality::Precise => {
+            arr_map(linear, gamma::compress_u8_precise)
+        }
+    }
+}
+
+/// Converts a colour in an XYZ colour space into 24-bit sRGB representation
+/// using conversion of given quality.
+///
+/// With [`Quality::Fast`] this is equivalent to [`u8_from_xyz()`].  See
+/// [`Quality`] for description of the other levels.
+///
+/// # Example
+/// ```
+/// use srgb::Quality;
+///
+/// let xyz = [0.28538015, 0.154248, 0.058890354];
+/// let got = srgb::u8_from_xyz_with(xyz, Quality::Fast);
+/// assert_eq!([212, 33, 61], got);
+/// let got = srgb::u8_from_xyz_with(xyz, Quality::Precise);
+/// assert_eq!([212, 33, 61], got);
+/// ```
+pub fn u8_from_xyz_with(xyz: impl Into<[f32; 3]>, quality: Quality) -> [u8; 3] {
+    let linear = match quality {
+        Quality::Fast | Quality::Balanced => xyz::linear_from_xyz(xyz),
+        Quality::Precise => {
+            maths::matrix_product_f64(&xyz::SRGB_FROM_XYZ_MATRIX, xyz.into())
+        }
+    };
+    u8_from_linear_with(linear, quality)
+}
+
+/// Converts a 24-bit sRGB colour into XYZ colour space using conversion of
+/// given quality.
+///
+/// With [`Quality::Fast`] and [`Quality::Balanced`] this is equivalent to
+/// [`xyz_from_u8()`].  With [`Quality::Precise`] the matrix multiplication is
+/// done in double precision.
+///
+/// # Example
+/// ```
+/// use srgb::Quality;
+///
+/// assert_eq!(
+///     [0.28538015, 0.154248, 0.058890354],
+///     srgb::xyz_from_u8_with([212, 33, 61], Quality::Precise)
+/// );
+/// ```
+pub fn xyz_from_u8_with(rgb: impl Into<[u8; 3]>, quality: Quality) -> [f32; 3] {
+    let linear = gamma::linear_from_u8(rgb);
+    match quality {
+        Quality::Fast | Quality::Balanced => xyz::xyz_from_linear(linear),
+        Quality::Precise => {
+            maths::matrix_product_f64(&xyz::XYZ_FROM_SRGB_MATRIX, linear)
+        }
+    }
+}
+
+
+/// Converts a 24-bit sRGB colour into a 16-bit grey suitable for saving as
+/// a 16-bit greyscale PNG image.
+///
+/// The colour is expanded into linear sRGB, its relative luminance (the Y
+/// coordinate in XYZ colour space) is calculated and the result is gamma
+/// compressed with sRGB transfer function at 16-bit precision.  Note that the
+/// output is thus a gamma-encoded grey and not linear luminance.  This matches
+/// how greyscale PNG images tagged as sRGB are interpreted.
+///
+/// # Example
+/// ```
+/// assert_eq!(65535, srgb::gray16_from_rgb([255, 255, 255]));
+/// assert_eq!(32896, srgb::gray16_from_rgb([128, 128, 128]));
+/// assert_eq!(0, srgb::gray16_from_rgb([0, 0, 0]));
+/// assert_eq!(28127, srgb::gray16_from_rgb([212, 33, 61]));
+/// ```
+pub fn gray16_from_rgb(rgb: impl Into<[u8; 3]>) -> u16 {
+    gamma::compress_u16(luminance_from_u8(rgb))
+}
+
+/// Converts a 16-bit sRGB-encoded grey into a 24-bit sRGB colour.
+///
+/// This is an inverse of [`gray16_from_rgb()`] in the sense that it returns
+/// a grey colour of the same luminance.  Since both representations use the
+/// same transfer function, this is just a rescaling of the value with
+/// rounding to the nearest 8-bit value.
+///
+/// # Example
+/// ```
+/// assert_eq!([255, 255, 255], srgb::rgb_from_gray16(65535));
+/// assert_eq!([128, 128, 128], srgb::rgb_from_gray16(32896));
+/// assert_eq!([0, 0, 0], srgb::rgb_from_gray16(0));
+/// ```
+pub fn rgb_from_gray16(gray: u16) -> [u8; 3] {
+    let v = ((gray as u32 * 255 + 32767) / 65535) as u8;
+    [v, v, v]
+}
+
+/// Converts a buffer of colours in an XYZ colour space into 24-bit sRGB
+/// representation.
+///
+/// This is equivalent to calling [`u8_from_xyz()`] on each element of `src`
+/// and storing results in `dst` but avoids per-colour overhead and doesn’t
+/// need an intermediate buffer for linear sRGB values.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// # Example
+/// ```
+/// let src = [[0.8071875, 0.82, 0.9353125], [0.2990163, 0.16, 0.0655738]];
+/// let mut dst = [[0; 3]; 2];
+/// srgb::u8_from_xyz_slice(&src, &mut dst);
+/// assert_eq!([[242, 230, 239], [217, 30, 65]], dst);
+/// ```
+pub fn u8_from_xyz_slice(src: &[[f32; 3]], dst: &mut [[u8; 3]]) {
+    maths::matrix_product_map(
+        &xyz::SRGB_FROM_XYZ_MATRIX,
+        src,
+        dst,
+        |xyz| xyz,
+        gamma::u8_from_linear,
+    )
+}
+
+/// Converts a buffer of 24-bit sRGB colours into XYZ colour space.
+///
+/// This is equivalent to calling [`xyz_from_u8()`] on each element of `src`
+/// and storing results in `dst` but avoids per-colour overhead and doesn’t
+/// need an intermediate buffer for linear sRGB values.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// # Example
+/// ```
+/// let src = [[242, 230, 239], [217, 30, 65]];
+/// let mut dst = [[0.0; 3]; 2];
+/// srgb::xyz_from_u8_slice(&src, &mut dst);
+/// assert_eq!(srgb::xyz_from_u8(src[0]), dst[0]);
+/// assert_eq!(srgb::xyz_from_u8(src[1]), dst[1]);
+/// ```
+pub fn xyz_from_u8_slice(src: &[[u8; 3]], dst: &mut [[f32; 3]]) {
+    maths::matrix_product_map(
+        &xyz::XYZ_FROM_SRGB_MATRIX,
+        src,
+        dst,
+        gamma::linear_from_u8,
+        |xyz| xyz,
+    )
+}
+
+
+/// Makes a 24-bit sRGB colour lighter by increasing its perceptual lightness.
+///
+/// The `amount` is added to colour’s lightness in Oklab colour space (where
+/// black has lightness zero and white has lightness one) while hue and chroma
+/// are held constant.  If resulting colour lies outside of sRGB gamut, its
+/// chroma is reduced until it can be represented (see
+/// [`gamut::to_srgb_oklch()`]).  Unlike scaling RGB components, this produces
+/// tints which look like the same colour.
+///
+/// # Example
+/// ```
+/// assert_eq!([249, 75, 90], srgb::lighten([212, 33, 61], 0.1));
+/// assert_eq!([255, 255, 255], srgb::lighten([255, 255, 255], 0.1));
+/// ```
+pub fn lighten(rgb: impl Into<[u8; 3]>, amount: f32) -> [u8; 3] {
+    adjust_lightness(rgb.into(), amount)
+}
+
+/// Makes a 24-bit sRGB colour darker by decreasing its perceptual lightness.
+///
+/// This is equivalent to [`lighten()`] with negated `amount`.
+///
+/// # Example
+/// ```
+/// assert_eq!([168, 0, 40], srgb::darken([212, 33, 61], 0.1));
+/// assert_eq!([0, 0, 0], srgb::darken([0, 0, 0], 0.1));
+/// ```
+pub fn darken(rgb: impl Into<[u8; 3]>, amount: f32) -> [u8; 3] {
+    adjust_lightness(rgb.into(), -amount)
+}
+
+fn adjust_lightness(rgb: [u8; 3], delta: f32) -> [u8; 3] {
+    let oklab = oklab::oklab_from_linear(gamma::linear_from_u8(rgb));
+    let [l, c, h] = oklab::oklch_from_oklab_deg(oklab);
+    gamut::to_srgb_oklch(l + delta, c, h)
+}
+
+
+
+/// Colour space in which colours are interpolated by [`mix_in()`].
+///
+/// The choice of the space affects how intermediate colours look like:
+///
+/// * Linear sRGB corresponds to physically mixing light.  It’s the correct
+///   space for operations such as blurring, resizing or compositing but
+///   perceived lightness doesn’t change uniformly and hues of intermediate
+///   colours may drift away from hues of the end points (e.g. blending red
+///   with blue quickly turns into magenta).
+/// * Oklab is a perceptual colour space.  Lightness changes uniformly and
+///   intermediate colours keep hue closer to end points which makes it
+///   well-suited for gradients and palettes.  On the other hand, chroma in the
+///   middle of a blend between distant hues may drop producing duller colours.
+/// * CIE XYZ is a linear transformation of linear sRGB and mixing in it
+///   produces the same colours as mixing in linear sRGB (up to rounding
+///   errors).  It’s provided for completeness.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WorkingSpace {
+    /// Linear sRGB colour space.
+    LinearSrgb,
+    /// Oklab colour space (see [`oklab`] module).
+    Oklab,
+    /// CIE XYZ colour space (see [`xyz`] module).
+    Xyz,
+}
+
+/// Mixes two 24-bit sRGB colours in linear sRGB colour space.
+///
+/// `t` is the weight of the second colour such that zero results in colour
+/// `a` and one results in colour `b`.  Values outside of the range from zero
+/// to one extrapolate with components of the result saturating at the edges
+/// of the sRGB gamut.
+///
+/// This is equivalent to [`mix_in()`] with [`WorkingSpace::LinearSrgb`].
+///
+/// # Example
+/// ```
+/// assert_eq!([188, 0, 188], srgb::mix([255, 0, 0], [0, 0, 255], 0.5));
+/// assert_eq!([255, 0, 0], srgb::mix([255, 0, 0], [0, 0, 255], 0.0));
+/// ```
+pub fn mix(a: impl Into<[u8; 3]>, b: impl Into<[u8; 3]>, t: f32) -> [u8; 3] {
+    let a = gamma::linear_from_u8(a);
+    let b = gamma::linear_from_u8(b);
+    gamma::u8_from_linear(lerp(a, b, t))
+}
+
+/// Mixes two 24-bit sRGB colours in given working colour space.
+///
+/// `t` is the weight of the second colour such that zero results in colour
+/// `a` and one results in colour `b`.  See [`WorkingSpace`] for discussion of
+/// how the choice of the space affects the result.  Intermediate colours which
+/// fall outside of the sRGB gamut are clamped.
+///
+/// # Example
+/// ```
+/// use srgb::WorkingSpace;
+///
+/// let (red, blue) = ([255, 0, 0], [0, 0, 255]);
+/// let got = srgb::mix_in(WorkingSpace::LinearSrgb, red, blue, 0.5);
+/// assert_eq!([188, 0, 188], got);
+/// let got = srgb::mix_in(WorkingSpace::Oklab, red, blue, 0.5);
+/// assert_eq!([140, 83, 162], got);
+/// ```
+pub fn mix_in(
+    space: WorkingSpace,
+    a: impl Into<[u8; 3]>,
+    b: impl Into<[u8; 3]>,
+    t: f32,
+) -> [u8; 3] {
+    let a = gamma::linear_from_u8(a);
+    let b = gamma::linear_from_u8(b);
+    let linear = match space {
+        WorkingSpace::LinearSrgb => lerp(a, b, t),
+        WorkingSpace::Oklab => oklab::linear_from_oklab(lerp(
+            oklab::oklab_from_linear(a),
+            oklab::oklab_from_linear(b),
+            t,
+        )),
+        WorkingSpace::Xyz => xyz::linear_from_xyz(lerp(
+            xyz::xyz_from_linear(a),
+            xyz::xyz_from_linear(b),
+            t,
+        )),
+    };
+    gamma::u8_from_linear(linear)
+}
+
+fn lerp(a: [f32; 3], b: [f32; 3], t: f32) -> [f32; 3] {
+    [
+        maths::mul_add(b[0] - a[0], t, a[0]),
+        maths::mul_add(b[1] - a[1], t, a[1]),
+        maths::mul_add(b[2] - a[2], t, a[2]),
+    ]
+}
+
+/// Blends anti-aliased text of colour `fg` drawn over `bg` background.
+///
+/// `coverage` is the fraction of the pixel covered by the glyph as produced
+/// by font rasterisers and is clamped to the range from zero to one.  The
+/// blending is done in linear space which is equivalent to [`mix()`] with
+/// `coverage` used as weight of the text colour.
+///
+/// Blending in gamma-encoded space (which is what many renderers do) makes
+/// dark text on light background look too heavy and light text on dark
+/// background too thin.  Blending in linear space fixes this though some
+/// people find resulting thin strokes too light.  [`text_blend_with()`]
+/// allows adjusting that.
+///
+/// # Example
+/// ```
+/// let (fg, bg) = ([0, 0, 0], [255, 255, 255]);
+/// assert_eq!([255, 255, 255], srgb::text_blend(fg, bg, 0.0));
+/// assert_eq!([188, 188, 188], srgb::text_blend(fg, bg, 0.5));
+/// assert_eq!([0, 0, 0], srgb::text_blend(fg, bg, 1.0));
+/// ```
+pub fn text_blend(
+    fg: impl Into<[u8; 3]>,
+    bg: impl Into<[u8; 3]>,
+    coverage: f32,
+) -> [u8; 3] {
+    mix(bg, fg, coverage.clamp(0.0, 1.0))
+}
+
+/// Blends anti-aliased text with adjusted coverage.
+///
+/// Like [`text_blend()`] but before blending the coverage is raised to the
+/// power of `1 / gamma`.  Gamma of one results in plain linear blending
+/// while values greater than one emphasise partially covered pixels thus
+/// making text look bolder (similarly to stem darkening or gamma adjustment
+/// done by many font renderers).  Values in 1.2–1.8 range are common.
+/// Coverage of zero and one are not affected by the adjustment.
+///
+/// # Example
+/// ```
+/// let (fg, bg) = ([0, 0, 0], [255, 255, 255]);
+/// assert_eq!([188, 188, 188], srgb::text_blend_with(fg, bg, 0.5, 1.0));
+/// assert_eq!([164, 164, 164], srgb::text_blend_with(fg, bg, 0.5, 1.5));
+/// assert_eq!([0, 0, 0], srgb::text_blend_with(fg, bg, 1.0, 1.5));
+/// ```
+pub fn text_blend_with(
+    fg: impl Into<[u8; 3]>,
+    bg: impl Into<[u8; 3]>,
+    coverage: f32,
+    gamma: f32,
+) -> [u8; 3] {
+    let coverage = coverage.clamp(0.0, 1.0).powf(gamma.recip());
+    mix(bg, fg, coverage)
+}
+
+/// Adds light of two 24-bit sRGB colours.
+///
+/// Light adds in linear space thus both colours are expanded, added and the
+/// sum compressed back with components saturating at one.  This is useful
+/// for additive effects such as light maps or bloom.  Note that saturating
+/// addition of gamma-encoded values produces results which are too bright
+/// (e.g. adding two 128 greys results in white).
+///
+/// # Example
+/// ```
+/// assert_eq!([212, 33, 61], srgb::add_lights([212, 33, 61], [0, 0, 0]));
+/// assert_eq!([176, 176, 176], srgb::add_lights([128; 3], [128; 3]));
+/// assert_eq!([255, 255, 255], srgb::add_lights([200; 3], [200; 3]));
+/// ```
+pub fn add_lights(a: impl Into<[u8; 3]>, b: impl Into<[u8; 3]>) -> [u8; 3] {
+    sum_lights(&[a.into(), b.into()])
+}
+
+/// Adds light of any number of 24-bit sRGB colours.
+///
+/// Like [`add_lights()`] but sums all colours in the slice.  The sum is
+/// accumulated in linear space and saturated only once at the end.  Empty
+/// slice results in black.
+///
+/// # Example
+/// ```
+/// let lights = [[128, 0, 0], [0, 128, 0], [0, 0, 128], [128, 0, 0]];
+/// assert_eq!([176, 128, 128], srgb::sum_lights(&lights));
+/// assert_eq!([0, 0, 0], srgb::sum_lights(&[]));
+/// ```
+pub fn sum_lights(lights: &[[u8; 3]]) -> [u8; 3] {
+    let mut sum = [0.0f32; 3];
+    for rgb in lights {
+        let linear = gamma::linear_from_u8(*rgb);
+        for (sum, value) in sum.iter_mut().zip(linear.iter()) {
+            *sum += *value;
+        }
+    }
+    gamma::u8_from_linear(sum)
+}
+
+/// Corrects a 24-bit sRGB colour which had sRGB gamma compression applied
+/// twice.
+///
+/// A common mistake is treating already encoded sRGB values as linear and
+/// encoding them again.  The result looks washed out with shadows lifted.
+/// This function reverses the mistake by applying one gamma expansion and
+/// interpreting the result as encoded value.  Since the second encoding
+/// merges some of the light codes, the original colour may be recovered
+/// with an error of one.  See [`analyse::likely_double_encoded()`] for
+/// a heuristic detecting whether an image suffers from the problem.
+///
+/// # Example
+/// ```
+/// use srgb::gamma::compress_u8;
+///
+/// let rgb = [212, 33, 61];
+/// let double = rgb.map(|v| compress_u8(v as f32 / 255.0));
+/// assert_eq!([235, 101, 134], double);
+/// assert_eq!(rgb, srgb::undo_double_encode(double));
+/// ```
+pub fn undo_double_encode(rgb: impl Into<[u8; 3]>) -> [u8; 3] {
+    u8_from_normalised(gamma::linear_from_u8(rgb))
+}
+
+/// Calculates lightness contrast between text and background colour using the
+/// APCA algorithm.
+///
+/// APCA (Accessible Perceptual Contrast Algorithm) is the contrast method
+/// proposed for WCAG 3.  Unlike WCAG 2 contrast ratio it’s not symmetric:
+/// dark text on light background results in positive values while light text
+/// on dark background results in negative values.  The result is the Lc value
+/// ranging from about -108 to about 106 where zero means no contrast.
+///
+/// This implements version 0.0.98G-4g of the algorithm.  Note that APCA
+/// estimates screen luminance with a simple 2.4 exponent rather than with
+/// sRGB transfer function and soft-clamps very dark colours.
+///
+/// # Example
+/// ```
+/// let (grey, white) = ([0x88; 3], [0xff; 3]);
+/// approx::assert_abs_diff_eq!(
+///     63.06,
+///     srgb::apca_contrast(grey, white),
+///     epsilon = 0.01
+/// );
+/// approx::assert_abs_diff_eq!(
+///     -68.54,
+///     srgb::apca_contrast(white, grey),
+///     epsilon = 0.01
+/// );
+/// ```
+pub fn apca_contrast(text: impl Into<[u8; 3]>, bg: impl Into<[u8; 3]>) -> f32 {
+    const BLACK_THRESHOLD: f32 = 0.022;
+    const BLACK_CLAMP: f32 = 1.414;
+    const SCALE: f32 = 1.14;
+    const OFFSET: f32 = 0.027;
+    const LOW_CLIP: f32 = 0.1;
+    const DELTA_Y_MIN: f32 = 0.0005;
+
+    fn luminance(rgb: [u8; 3]) -> f32 {
+        const COEFFICIENTS: [f32; 3] = [0.2126729, 0.7151522, 0.0721750];
+        let y = rgb
+            .iter()
+            .zip(COEFFICIENTS.iter())
+            .map(|(v, k)| k * (*v as f32 / 255.0).powf(2.4))
+            .sum::<f32>();
+        if y < BLACK_THRESHOLD {
+            y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP)
+        } else {
+            y
+        }
+    }
+
+    let text = luminance(text.into());
+    let bg = luminance(bg.into());
+    if (bg - text).abs() < DELTA_Y_MIN {
+        return 0.0;
+    }
+    let contrast = if bg > text {
+        // Dark text on light background.
+        let sapc = (bg.powf(0.56) - text.powf(0.57)) * SCALE;
+        if sapc < LOW_CLIP {
+            0.0
+        } else {
+            sapc - OFFSET
+        }
+    } else {
+        // Light text on dark background.
+        let sapc = (bg.powf(0.65) - text.powf(0.62)) * SCALE;
+        if sapc > -LOW_CLIP {
+            0.0
+        } else {
+            sapc + OFFSET
+        }
+    };
+    contrast * 100.0
+}
+
+/// Type of colour vision deficiency simulated by [`simulate_cvd()`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CvdType {
+    /// Missing or anomalous long-wavelength (red) cones.
+    Protanopia,
+    /// Missing or anomalous medium-wavelength (green) cones.
+    Deuteranopia,
+    /// Missing or anomalous short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+/// Simulates how a 24-bit sRGB colour is seen by a person with colour vision
+/// deficiency.
+///
+/// The simulation is done in LMS colour space (see [`lms`] module) following
+/// method by Viénot, Brettel and Mollon: response of the missing cone is
+/// replaced by a combination of responses of the remaining cones chosen such
+/// that neutral colours are unaffected.  For protanopia and deuteranopia
+/// blue primary and for tritanopia red primary are preserved as well.
+///
+/// `severity` in the range from zero to one interpolates between normal
+/// vision and complete dichromacy which approximates anomalous trichromacy
+/// (i.e. protanomaly, deuteranomaly and tritanomaly).  Values outside of the
+/// range are clamped.
+///
+/// # Example
+/// ```
+/// use srgb::CvdType;
+///
+/// let (red, grey) = ([212, 33, 61], [128, 128, 128]);
+/// let kind = CvdType::Deuteranopia;
+/// assert_eq!(red, srgb::simulate_cvd(red, kind, 0.0));
+/// assert_eq!([131, 131, 47], srgb::simulate_cvd(red, kind, 1.0));
+/// assert_eq!(grey, srgb::simulate_cvd(grey, kind, 1.0));
+/// ```
+pub fn simulate_cvd(
+    rgb: impl Into<[u8; 3]>,
+    kind: CvdType,
+    severity: f32,
+) -> [u8; 3] {
+    let rgb = rgb.into();
+    let severity = severity.clamp(0.0, 1.0);
+    if severity == 0.0 {
+        return rgb;
+    }
+    let matrix = match kind {
+        CvdType::Protanopia => &lms::PROTANOPIA_MATRIX,
+        CvdType::Deuteranopia => &lms::DEUTERANOPIA_MATRIX,
+        CvdType::Tritanopia => &lms::TRITANOPIA_MATRIX,
+    };
+    let linear = gamma::linear_from_u8(rgb);
+    let simulated = maths::matrix_product(matrix, linear);
+    gamma::u8_from_linear(lerp(linear, simulated, severity))
+}
+
+/// Intermediate representation a 24-bit sRGB colour can be converted through.
+///
+/// Used by [`is_roundtrip_stable_u8()`] and [`unstable_u8()`] to describe the
+/// conversion pipeline being analysed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Space {
+    /// Linear sRGB with components stored as `f32`.
+    Linear,
+    /// CIE XYZ colour space (see [`xyz`] module).
+    Xyz,
+    /// Oklab colour space (see [`oklab`] module).
+    Oklab,
+    /// 8-bit Rec.709 encoding with components in the [16, 235] range (see
+    /// [`gamma::compress_rec709_8bit()`]).
+    Rec709,
+}
+
+/// Returns whether a 24-bit sRGB colour maps to itself after being converted
+/// into given intermediate representation and back.
+///
+/// The colour is first expanded into linear sRGB, then converted into and
+/// out of the `via` representation and finally compressed back into 24-bit
+/// sRGB.  Floating point representations lose very little information and
+/// only a handful of colours (if any) aren’t stable.  On the other hand,
+/// 8-bit Rec.709 has fewer levels than 8-bit sRGB and many colours don’t
+/// survive conversion through it.
+///
+/// # Example
+/// ```
+/// use srgb::Space;
+///
+/// assert!(srgb::is_roundtrip_stable_u8([212, 33, 61], Space::Xyz));
+/// assert!(srgb::is_roundtrip_stable_u8([212, 33, 61], Space::Oklab));
+/// assert!(!srgb::is_roundtrip_stable_u8([1, 1, 1], Space::Rec709));
+/// ```
+pub fn is_roundtrip_stable_u8(rgb: impl Into<[u8; 3]>, via: Space) -> bool {
+    let rgb = rgb.into();
+    let linear = gamma::linear_from_u8(rgb);
+    let linear = match via {
+        Space::Linear => linear,
+        Space::Xyz => xyz::linear_from_xyz(xyz::xyz_from_linear(linear)),
+        Space::Oklab => {
+            oklab::linear_from_oklab(oklab::oklab_from_linear(linear))
+        }
+        Space::Rec709 => arr_map(linear, |v| {
+            gamma::expand_rec709_8bit(gamma::compress_rec709_8bit(v))
+        }),
+    };
+    gamma::u8_from_linear(linear) == rgb
+}
+
+/// Returns an iterator over all 24-bit sRGB colours which don’t map to
+/// themselves after being converted through given intermediate representation.
+///
+/// See [`is_roundtrip_stable_u8()`] for description of the conversion.  Note
+/// that the iterator checks all 2<sup>24</sup> colours and thus exhausting it
+/// takes a while.
+///
+/// # Example
+/// ```no_run
+/// let count = srgb::unstable_u8(srgb::Space::Xyz).count();
+/// println!("{} colours don’t survive trip through XYZ", count);
+/// ```
+pub fn unstable_u8(via: Space) -> impl Iterator<Item = [u8; 3]> {
+    (0..(1 << 24))
+        .map(from_u32)
+        .filter(move |rgb| !is_roundtrip_stable_u8(*rgb, via))
+}
 
 pub(crate) fn arr_map<F: Copy, T: Copy, Fun: Fn(F) -> T>(
     arr: impl Into<[F; 3]>,
@@ -137,6 +1121,422 @@ mod test {
         error.sum() * 1e15
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_luminance() {
+        for c in (0..(1 << 24)).step_by(101) {
+            let rgb = crate::from_u32(c);
+            let want = super::xyz_from_u8(rgb)[1];
+            let got = super::luminance_from_u8(rgb);
+            approx::assert_ulps_eq!(want, got, max_ulps = 2);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_parse_hex() {
+        const RED: [u8; 3] = crate::srgb!("#d4213d");
+        assert_eq!([212, 33, 61], RED);
+        assert_eq!([212, 33, 61], crate::srgb!("d4213d"));
+        assert_eq!([0x11, 0xaa, 0xff], crate::srgb!("#1aF"));
+        assert_eq!([212, 33, 61], crate::srgb!(212, 33, 61));
+
+        for c in (0..=0xffffff_u32).step_by(997) {
+            let hex = format!("#{:06x}", c);
+            assert_eq!(Some(super::from_u32(c)), super::parse_hex(&hex));
+            let hex = format!("{:06X}", c);
+            assert_eq!(Some(super::from_u32(c)), super::parse_hex(&hex));
+        }
+        for hex in [
+            "", "#", "#12", "#1234", "#12345", "#1234567", "##123", "+12345",
+            "12345g",
+        ] {
+            assert_eq!(None, super::parse_hex(hex), "{}", hex);
+        }
+    }
+
+    #[test]
+    fn test_gray16() {
+        for v in 0..=255 {
+            let gray = super::gray16_from_rgb([v, v, v]);
+            assert!(gray.abs_diff(v as u16 * 257) <= 1, "{}: {}", v, gray);
+            assert_eq!([v, v, v], super::rgb_from_gray16(gray));
+        }
+        let red = super::gray16_from_rgb([255, 0, 0]);
+        let green = super::gray16_from_rgb([0, 255, 0]);
+        let blue = super::gray16_from_rgb([0, 0, 255]);
+        assert!(blue < red && red < green, "{} {} {}", red, green, blue);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_quality() {
+        use super::Quality;
+
+        for c in (0..(1 << 24)).step_by(997) {
+            let rgb = super::from_u32(c);
+            let linear = crate::gamma::linear_from_u8(rgb);
+            let xyz = super::xyz_from_u8(rgb);
+
+            let precise =
+                crate::arr_map(linear, crate::gamma::compress_u8_precise);
+            let fast = crate::gamma::u8_from_linear(linear);
+            assert_eq!(fast, super::u8_from_linear_with(linear, Quality::Fast));
+            assert_eq!(
+                precise,
+                super::u8_from_linear_with(linear, Quality::Balanced)
+            );
+            assert_eq!(
+                precise,
+                super::u8_from_linear_with(linear, Quality::Precise)
+            );
+
+            assert_eq!(
+                super::u8_from_xyz(xyz),
+                super::u8_from_xyz_with(xyz, Quality::Fast)
+            );
+            for quality in [Quality::Fast, Quality::Balanced, Quality::Precise]
+            {
+                let got = super::xyz_from_u8_with(rgb, quality);
+                approx::assert_abs_diff_eq!(
+                    &xyz[..],
+                    &got[..],
+                    epsilon = 0.000001
+                );
+                assert_eq!(rgb, super::u8_from_xyz_with(got, quality));
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_luminance() {
+        let [x, y, _] = crate::xyz::D65_xyY;
+        for luminance in [0.0, 0.1, 0.25, 0.5, 0.75, 1.0] {
+            let rgb = super::with_luminance((x, y), luminance).unwrap();
+            assert!(rgb[0] == rgb[1] && rgb[1] == rgb[2], "{:?}", rgb);
+            let [_, got, _] = super::xyz_from_u8(rgb);
+            approx::assert_abs_diff_eq!(luminance, got, epsilon = 0.005);
+        }
+        assert_eq!(None, super::with_luminance((x, y), 1.1));
+        assert_eq!(None, super::with_luminance((x, y), -0.1));
+
+        for i in 0..3 {
+            let [x, y, _] = crate::xyz::PRIMARIES_xyY[i];
+            let max = crate::xyz::XYZ_FROM_SRGB_MATRIX[1][i];
+            assert!(super::with_luminance((x, y), max * 0.99).is_some());
+            assert_eq!(None, super::with_luminance((x, y), max * 1.01));
+        }
+
+        assert_eq!(None, super::with_luminance((0.3, 0.0), 0.5));
+        assert_eq!(None, super::with_luminance((0.3, f32::NAN), 0.5));
+    }
+
+    #[test]
+    fn test_mix_in() {
+        use super::WorkingSpace;
+
+        fn hue(rgb: [u8; 3]) -> f32 {
+            let linear = crate::gamma::linear_from_u8(rgb);
+            let [_, a, b] = crate::oklab::oklab_from_linear(linear);
+            b.atan2(a).to_degrees()
+        }
+
+        fn hue_diff(a: f32, b: f32) -> f32 {
+            ((a - b + 180.0).rem_euclid(360.0) - 180.0).abs()
+        }
+
+        let colours = [[255, 0, 0], [0, 0, 255], [212, 33, 61], [10, 200, 30]];
+        for a in colours {
+            for b in colours {
+                for t in [0.0, 0.25, 0.5, 0.75, 1.0] {
+                    let want = super::mix(a, b, t);
+                    let got = super::mix_in(WorkingSpace::LinearSrgb, a, b, t);
+                    assert_eq!(want, got);
+                    let got = super::mix_in(WorkingSpace::Xyz, a, b, t);
+                    for (want, got) in want.iter().zip(got.iter()) {
+                        assert!(want.abs_diff(*got) <= 1, "{:?}", (a, b, t));
+                    }
+                }
+                for space in [WorkingSpace::Oklab, WorkingSpace::Xyz] {
+                    assert_eq!(a, super::mix_in(space, a, b, 0.0));
+                    assert_eq!(b, super::mix_in(space, a, b, 1.0));
+                }
+            }
+        }
+
+        // Near the end points of a red to blue blend, Oklab keeps the hue
+        // closer to the end point’s hue than linear sRGB.
+        let (red, blue) = ([255, 0, 0], [0, 0, 255]);
+        for (t, end) in [(0.1, red), (0.2, red), (0.8, blue), (0.9, blue)] {
+            let linear = super::mix_in(WorkingSpace::LinearSrgb, red, blue, t);
+            let oklab = super::mix_in(WorkingSpace::Oklab, red, blue, t);
+            let linear = hue_diff(hue(end), hue(linear));
+            let oklab = hue_diff(hue(end), hue(oklab));
+            assert!(oklab < linear, "t: {}; {} vs {}", t, oklab, linear);
+        }
+    }
+
+    #[test]
+    fn test_text_blend() {
+        for (fg, bg) in [
+            ([0, 0, 0], [255, 255, 255]),
+            ([255, 255, 255], [0, 0, 0]),
+            ([212, 33, 61], [17, 200, 99]),
+        ] {
+            assert_eq!(bg, super::text_blend(fg, bg, 0.0));
+            assert_eq!(bg, super::text_blend(fg, bg, -1.0));
+            assert_eq!(fg, super::text_blend(fg, bg, 1.0));
+            assert_eq!(fg, super::text_blend(fg, bg, 2.0));
+            assert_eq!(super::mix(fg, bg, 0.5), super::text_blend(fg, bg, 0.5));
+            for gamma in [1.0, 1.4, 2.2] {
+                assert_eq!(bg, super::text_blend_with(fg, bg, 0.0, gamma));
+                assert_eq!(fg, super::text_blend_with(fg, bg, 1.0, gamma));
+            }
+            for coverage in [0.1, 0.25, 0.5, 0.9] {
+                assert_eq!(
+                    super::text_blend(fg, bg, coverage),
+                    super::text_blend_with(fg, bg, coverage, 1.0)
+                );
+            }
+        }
+
+        // Gamma above one makes partially covered pixels darker for dark
+        // text on light background.
+        let (fg, bg) = ([0, 0, 0], [255, 255, 255]);
+        let mut prev = 255;
+        for gamma in [1.0, 1.2, 1.8, 2.2] {
+            let [got, ..] = super::text_blend_with(fg, bg, 0.3, gamma);
+            assert!(got < prev, "gamma: {}; {} >= {}", gamma, got, prev);
+            prev = got;
+        }
+    }
+
+    #[test]
+    fn test_apca_contrast() {
+        use super::apca_contrast;
+
+        // Reference values from APCA documentation.
+        for (text, bg, want) in [
+            ([0x88; 3], [0xff; 3], 63.056469930209424),
+            ([0xff; 3], [0x88; 3], -68.54146436644962),
+            ([0x00; 3], [0xaa; 3], 58.146262578561334),
+            ([0xaa; 3], [0x00; 3], -56.24113336839742),
+            ([0x11, 0x22, 0x33], [0xdd, 0xee, 0xff], 91.66830811481631),
+            ([0xdd, 0xee, 0xff], [0x11, 0x22, 0x33], -93.06770049484275),
+        ] {
+            let got = apca_contrast(text, bg);
+            approx::assert_abs_diff_eq!(want, got, epsilon = 0.001);
+        }
+
+        assert_eq!(0.0, apca_contrast([212, 33, 61], [212, 33, 61]));
+        assert_eq!(0.0, apca_contrast([0, 0, 0], [1, 1, 1]));
+        assert!(apca_contrast([0, 0, 0], [255, 255, 255]) > 105.0);
+        assert!(apca_contrast([255, 255, 255], [0, 0, 0]) < -107.0);
+    }
+
+    #[test]
+    fn test_lights() {
+        for c in (0..(1 << 24)).step_by(9973) {
+            let rgb = super::from_u32(c);
+            assert_eq!(rgb, super::add_lights(rgb, [0, 0, 0]));
+            assert_eq!(rgb, super::add_lights([0, 0, 0], rgb));
+            assert_eq!(rgb, super::sum_lights(&[rgb]));
+            assert_eq!([255; 3], super::add_lights(rgb, [255; 3]));
+        }
+
+        // Adding two half-brightness greys doubles linear value.
+        let half = crate::gamma::compress_u8(0.25);
+        let full = crate::gamma::compress_u8(0.5);
+        assert_eq!([full; 3], super::add_lights([half; 3], [half; 3]));
+        assert_eq!([255; 3], super::sum_lights(&[[full; 3]; 3]));
+
+        let (a, b) = ([212, 33, 61], [17, 200, 99]);
+        let want = crate::gamma::u8_from_linear(super::arr_map(
+            [0, 1, 2],
+            |i: usize| {
+                crate::gamma::expand_u8(a[i]) + crate::gamma::expand_u8(b[i])
+            },
+        ));
+        assert_eq!(want, super::add_lights(a, b));
+        assert_eq!(want, super::sum_lights(&[a, [0; 3], b]));
+    }
+
+    #[test]
+    fn test_simulate_cvd() {
+        use super::{simulate_cvd, CvdType};
+
+        let kinds =
+            [CvdType::Protanopia, CvdType::Deuteranopia, CvdType::Tritanopia];
+        for kind in kinds {
+            for c in (0..(1 << 24)).step_by(9973) {
+                let rgb = super::from_u32(c);
+                assert_eq!(rgb, simulate_cvd(rgb, kind, 0.0));
+                assert_eq!(rgb, simulate_cvd(rgb, kind, -1.0));
+                assert_eq!(
+                    simulate_cvd(rgb, kind, 1.0),
+                    simulate_cvd(rgb, kind, 2.0)
+                );
+            }
+            // Greys are unaffected.
+            for v in (0..=255).step_by(5) {
+                let got = simulate_cvd([v; 3], kind, 1.0);
+                for got in got {
+                    assert!((got as i32 - v as i32).abs() <= 1, "{:?}", got);
+                }
+            }
+        }
+
+        // Red and green become less distinguishable for protans and deutans.
+        let delta_e = |a: [u8; 3], b: [u8; 3]| {
+            crate::delta::delta_e_2000(
+                crate::lab::lab_from_u8(a),
+                crate::lab::lab_from_u8(b),
+            )
+        };
+        let (red, green) = ([200, 50, 40], [90, 160, 40]);
+        let normal = delta_e(red, green);
+        for kind in [CvdType::Protanopia, CvdType::Deuteranopia] {
+            let mut prev = normal;
+            for severity in [0.25, 0.5, 0.75, 1.0] {
+                let got = delta_e(
+                    simulate_cvd(red, kind, severity),
+                    simulate_cvd(green, kind, severity),
+                );
+                assert!(
+                    got < prev,
+                    "{:?} {}: {} ≥ {}",
+                    kind,
+                    severity,
+                    got,
+                    prev
+                );
+                prev = got;
+            }
+            assert!(prev < normal / 3.0, "{:?}: {} vs {}", kind, prev, normal);
+        }
+
+        // Blue and yellow become less distinguishable for tritans.
+        let (blue, yellow) = ([40, 60, 200], [160, 160, 60]);
+        let got = delta_e(
+            simulate_cvd(blue, CvdType::Tritanopia, 1.0),
+            simulate_cvd(yellow, CvdType::Tritanopia, 1.0),
+        );
+        assert!(got < delta_e(blue, yellow), "{}", got);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_roundtrip_stable_u8() {
+        use super::Space;
+
+        for c in (0..(1 << 24)).step_by(4999) {
+            let rgb = super::from_u32(c);
+            for via in [Space::Linear, Space::Xyz, Space::Oklab] {
+                assert!(super::is_roundtrip_stable_u8(rgb, via), "{:?}", rgb);
+            }
+        }
+
+        // 8-bit Rec.709 has only 220 levels so at least 36 of 256 greys are
+        // bound to be lost.
+        let unstable = (0..=255)
+            .filter(|&v| {
+                !super::is_roundtrip_stable_u8([v, v, v], Space::Rec709)
+            })
+            .count();
+        assert_eq!(36, unstable);
+        assert_eq!(Some([0, 0, 1]), super::unstable_u8(Space::Rec709).next());
+    }
+
+    #[test]
+    fn test_f64() {
+        let values = [
+            0.0,
+            -0.0,
+            0.1,
+            1.0,
+            f32::MIN_POSITIVE / 3.0,
+            f32::MAX,
+            f32::EPSILON,
+            f32::INFINITY,
+        ];
+        for value in values {
+            let rgb = [value, -value, 1.0 - value];
+            let got = super::to_f32(super::to_f64(rgb));
+            assert_eq!(rgb.map(f32::to_bits), got.map(f32::to_bits));
+        }
+        let [nan, _, _] = super::to_f32(super::to_f64([f32::NAN, 0.0, 0.0]));
+        assert!(nan.is_nan());
+        assert_eq!(
+            [f32::INFINITY, 0.0, 0.1],
+            super::to_f32([1e300, 1e-300, 0.1])
+        );
+    }
+
+    #[test]
+    fn test_packed_u32() {
+        assert_eq!([0x12, 0x34, 0x56], super::from_u32(0x00123456));
+        assert_eq!([0x12, 0x34, 0x56], super::from_u32(0xff123456));
+        assert_eq!(0x00123456, super::to_u32([0x12, 0x34, 0x56]));
+
+        let rgba = [0x12, 0x34, 0x56, 0x78];
+        assert_eq!(rgba, super::from_argb_u32(0x78123456));
+        assert_eq!(0x78123456, super::to_argb_u32(rgba));
+        assert_eq!(rgba, super::from_rgba_u32(0x12345678));
+        assert_eq!(0x12345678, super::to_rgba_u32(rgba));
+
+        for packed in [0, 0xffffff, 0xd4213d, 0x010203] {
+            assert_eq!(packed, super::to_u32(super::from_u32(packed)));
+        }
+        for packed in [0, 0xffffffff, 0x80d4213d, 0x01020304] {
+            assert_eq!(
+                packed,
+                super::to_argb_u32(super::from_argb_u32(packed))
+            );
+            assert_eq!(
+                packed,
+                super::to_rgba_u32(super::from_rgba_u32(packed))
+            );
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_xyz_slice() {
+        let rgb: Vec<[u8; 3]> = (0..(1 << 15))
+            .map(|c| [(c << 3) as u8, (c >> 2) as u8, (c >> 7) as u8])
+            .collect();
+        let mut xyz = vec![[0.0; 3]; rgb.len()];
+        super::xyz_from_u8_slice(&rgb, &mut xyz);
+        for (rgb, xyz) in rgb.iter().zip(xyz.iter()) {
+            assert_eq!(super::xyz_from_u8(*rgb), *xyz);
+        }
+
+        let mut got = vec![[0; 3]; rgb.len()];
+        super::u8_from_xyz_slice(&xyz, &mut got);
+        for (xyz, got) in xyz.iter().zip(got.iter()) {
+            assert_eq!(super::u8_from_xyz(*xyz), *got);
+        }
+        assert_eq!(rgb, got);
+    }
+
+    #[test]
+    fn test_lighten_darken() {
+        for rgb in [[233, 232, 231], [128, 128, 128], [100, 150, 200]] {
+            let lighter = super::lighten(rgb, 0.05);
+            assert!(lighter.iter().zip(rgb.iter()).all(|(l, c)| l >= c));
+            let got = super::darken(lighter, 0.05);
+            for (want, got) in rgb.iter().zip(got.iter()) {
+                assert!(want.abs_diff(*got) <= 1, "{:?} {:?}", rgb, got);
+            }
+        }
+
+        assert_eq!([255, 255, 255], super::lighten([255, 255, 255], 0.5));
+        assert_eq!([255, 255, 255], super::lighten([250, 250, 250], 0.5));
+        assert_eq!([0, 0, 0], super::darken([0, 0, 0], 0.5));
+        assert_eq!([0, 0, 0], super::darken([5, 5, 5], 0.5));
+        assert_eq!([128, 128, 128], super::lighten([128, 128, 128], 0.0));
+    }
+
     #[test]
     fn test_grey_chromaticity_error_u8() {
         assert_eq!(
@@ -0,0 +1,91 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions and constants handling conversion between linear sRGB space and
+//! LMS colour space.
+//!
+//! LMS colour space represents responses of the long, medium and short
+//! wavelength cones of the human eye.  It’s used for chromatic adaptation and
+//! colour vision deficiency simulation (see [`crate::simulate_cvd()`]).  This
+//! module uses Hunt-Pointer-Estevez cone response matrix normalised to D65
+//! white point such that white has all responses equal.
+
+/// Converts a colour in linear sRGB space into LMS colour space.
+///
+/// # Example
+/// ```
+/// use srgb::lms::lms_from_linear;
+///
+/// // White has equal cone responses.
+/// let [l, m, s] = lms_from_linear([1.0, 1.0, 1.0]);
+/// approx::assert_abs_diff_eq!(1.0, l, epsilon = 0.001);
+/// approx::assert_abs_diff_eq!(1.0, m, epsilon = 0.001);
+/// approx::assert_abs_diff_eq!(1.0, s, epsilon = 0.001);
+/// ```
+pub fn lms_from_linear(linear: impl Into<[f32; 3]>) -> [f32; 3] {
+    crate::maths::matrix_product(&LMS_FROM_SRGB_MATRIX, linear.into())
+}
+
+/// Converts a colour in LMS colour space into linear sRGB space.
+///
+/// # Example
+/// ```
+/// use srgb::lms::{linear_from_lms, lms_from_linear};
+///
+/// let linear = [0.6583748, 0.015208514, 0.046665084];
+/// let got = linear_from_lms(lms_from_linear(linear));
+/// approx::assert_abs_diff_eq!(&linear[..], &got[..], epsilon = 0.00001);
+/// ```
+pub fn linear_from_lms(lms: impl Into<[f32; 3]>) -> [f32; 3] {
+    crate::maths::matrix_product(&SRGB_FROM_LMS_MATRIX, lms.into())
+}
+
+include!(concat!(env!("OUT_DIR"), "/lms_constants.rs"));
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reversible_conversion() {
+        for c in 0..(16 * 16 * 16) {
+            let src = [
+                (c & 15) as f32 / 15.0,
+                ((c >> 4) & 15) as f32 / 15.0,
+                ((c >> 8) & 15) as f32 / 15.0,
+            ];
+            let dst = linear_from_lms(lms_from_linear(src));
+            approx::assert_abs_diff_eq!(&src[..], &dst[..], epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_lms_from_xyz() {
+        for c in (0..(1 << 24)).step_by(9973) {
+            let linear = crate::gamma::linear_from_u8(crate::from_u32(c));
+            let want = crate::maths::matrix_product(
+                &LMS_FROM_XYZ_MATRIX,
+                crate::xyz::xyz_from_linear(linear),
+            );
+            let got = lms_from_linear(linear);
+            approx::assert_abs_diff_eq!(
+                &want[..],
+                &got[..],
+                epsilon = 0.000001
+            );
+        }
+    }
+}
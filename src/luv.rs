@@ -0,0 +1,162 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions implementing conversion into CIELUV colour space and CIE 1976
+//! u′v′ uniform chromaticity coordinates.
+//!
+//! CIELUV coordinates are calculated relative to the D65 white point (see
+//! [`crate::xyz::D65_XYZ`]) which is the white point of sRGB colour space.
+
+/// Converts a colour in XYZ colour space into CIE 1976 u′v′ chromaticity
+/// coordinates.
+///
+/// The result is given as `[u′, v′]` pair.  Black (for which the chromaticity
+/// is undefined) maps to chromaticity of the D65 white point.
+///
+/// # Example
+/// ```
+/// use srgb::luv::u_prime_v_prime_from_xyz;
+///
+/// let [u, v] = u_prime_v_prime_from_xyz(srgb::xyz::D65_XYZ);
+/// approx::assert_abs_diff_eq!(0.1978, u, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.4683, v, epsilon = 0.0001);
+/// assert_eq!([u, v], u_prime_v_prime_from_xyz([0.0, 0.0, 0.0]));
+/// ```
+pub fn u_prime_v_prime_from_xyz(xyz: impl Into<[f32; 3]>) -> [f32; 2] {
+    let denominator = |[x, y, z]: [f32; 3]| x + 15.0 * y + 3.0 * z;
+    let mut xyz = xyz.into();
+    let mut d = denominator(xyz);
+    if d == 0.0 {
+        xyz = crate::xyz::D65_XYZ;
+        d = denominator(xyz);
+    }
+    [4.0 * xyz[0] / d, 9.0 * xyz[1] / d]
+}
+
+/// Converts CIE 1976 u′v′ chromaticity coordinates and luminance into XYZ
+/// colour space.
+///
+/// This is an inverse of [`u_prime_v_prime_from_xyz()`] with the Y coordinate
+/// (which chromaticity doesn’t carry) given explicitly.  If `v′` is zero, the
+/// chromaticity is degenerate and the result is all zeros.
+///
+/// # Example
+/// ```
+/// use srgb::luv::{u_prime_v_prime_from_xyz, xyz_from_u_prime_v_prime};
+///
+/// let xyz = [0.4, 0.3, 0.2];
+/// let got = xyz_from_u_prime_v_prime(u_prime_v_prime_from_xyz(xyz), xyz[1]);
+/// approx::assert_abs_diff_eq!(&xyz[..], &got[..], epsilon = 0.00001);
+/// assert_eq!([0.0; 3], xyz_from_u_prime_v_prime([0.2, 0.0], 1.0));
+/// ```
+pub fn xyz_from_u_prime_v_prime(uv: [f32; 2], y: f32) -> [f32; 3] {
+    let [u, v] = uv;
+    if v == 0.0 {
+        return [0.0; 3];
+    }
+    let k = y / (4.0 * v);
+    [9.0 * u * k, y, (12.0 - 3.0 * u - 20.0 * v) * k]
+}
+
+/// Converts a colour in XYZ colour space into CIELUV colour space.
+///
+/// The XYZ colour space should be such where white colour has Y coordinate
+/// equal one.  The result is given as `[L*, u*, v*]` triple where lightness is
+/// in the range from zero to a hundred and is the same as CIELAB L*.
+///
+/// # Example
+/// ```
+/// use srgb::luv::luv_from_xyz;
+///
+/// let [l, u, v] = luv_from_xyz(srgb::xyz::D65_XYZ);
+/// approx::assert_abs_diff_eq!(100.0, l, epsilon = 0.001);
+/// approx::assert_abs_diff_eq!(0.0, u, epsilon = 0.001);
+/// approx::assert_abs_diff_eq!(0.0, v, epsilon = 0.001);
+/// assert_eq!([0.0, 0.0, 0.0], luv_from_xyz([0.0, 0.0, 0.0]));
+/// ```
+pub fn luv_from_xyz(xyz: impl Into<[f32; 3]>) -> [f32; 3] {
+    let xyz = xyz.into();
+    let [un, vn] = u_prime_v_prime_from_xyz(crate::xyz::D65_XYZ);
+    let [u, v] = u_prime_v_prime_from_xyz(xyz);
+    let t = xyz[1] / crate::xyz::D65_XYZ[1];
+    let l = if t > DELTA * DELTA * DELTA {
+        116.0 * t.cbrt() - 16.0
+    } else {
+        t * (29.0 / 3.0) * (29.0 / 3.0) * (29.0 / 3.0)
+    };
+    [l, 13.0 * l * (u - un), 13.0 * l * (v - vn)]
+}
+
+/// Converts a colour in CIELUV colour space into XYZ colour space.
+///
+/// This is an inverse of [`luv_from_xyz()`].  The result is given in XYZ
+/// colour space where white colour has Y coordinate equal one.
+///
+/// # Example
+/// ```
+/// use srgb::luv::{luv_from_xyz, xyz_from_luv};
+///
+/// let xyz = [0.4, 0.3, 0.2];
+/// let got = xyz_from_luv(luv_from_xyz(xyz));
+/// approx::assert_abs_diff_eq!(&xyz[..], &got[..], epsilon = 0.0001);
+/// ```
+pub fn xyz_from_luv(luv: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [l, u, v] = luv.into();
+    if l <= 0.0 {
+        return [0.0; 3];
+    }
+    let [un, vn] = u_prime_v_prime_from_xyz(crate::xyz::D65_XYZ);
+    let y = if l > 8.0 {
+        let f = (l + 16.0) / 116.0;
+        f * f * f
+    } else {
+        l * (3.0 / 29.0) * (3.0 / 29.0) * (3.0 / 29.0)
+    };
+    let uv = [u / (13.0 * l) + un, v / (13.0 * l) + vn];
+    xyz_from_u_prime_v_prime(uv, y * crate::xyz::D65_XYZ[1])
+}
+
+const DELTA: f32 = 6.0 / 29.0;
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reversible_conversion() {
+        for c in 1..(16 * 16 * 16) {
+            let r = (c & 15) as f32 / 15.0;
+            let g = ((c >> 4) & 15) as f32 / 15.0;
+            let b = ((c >> 8) & 15) as f32 / 15.0;
+            let src = crate::xyz::xyz_from_linear([r, g, b]);
+            let dst = xyz_from_luv(luv_from_xyz(src));
+            approx::assert_abs_diff_eq!(&src[..], &dst[..], epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_lightness_matches_lab() {
+        for y in [0.0, 0.001, 0.008856, 0.01, 0.18, 0.5, 1.0] {
+            let xyz = crate::xyz::denormalise_from_white(
+                [y, y, y],
+                crate::xyz::D65_XYZ,
+            );
+            let want = crate::lab::lab_from_xyz(xyz)[0];
+            let got = luv_from_xyz(xyz)[0];
+            approx::assert_abs_diff_eq!(want, got, epsilon = 0.0001);
+        }
+    }
+}
@@ -13,6 +13,13 @@
  * You should have received a copy of the GNU General Public License along with
  * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
 
+//! Matrix helpers and information about implementation of vector operations.
+//!
+//! Matrix products used by colour space conversions are vectorised where the
+//! CPU supports it.  The implementation is chosen at run time based on
+//! detected CPU features.  [`active_backend`] reports which one is used.
+//! [`inverse`] inverts user-supplied matrices.
+
 #[inline(always)]
 pub(crate) fn mul_add(a: f32, b: f32, c: f32) -> f32 {
     if cfg!(target_feature = "fma") {
@@ -22,9 +29,19 @@ pub(crate) fn mul_add(a: f32, b: f32, c: f32) -> f32 {
     }
 }
 
+/// Normalises angle to the range from zero (inclusive) to `full` (exclusive).
+pub(crate) fn wrap_angle(angle: f32, full: f32) -> f32 {
+    // rem_euclid may return `full` due to rounding of small negative values.
+    let angle = angle.rem_euclid(full);
+    if angle < full {
+        angle
+    } else {
+        0.0
+    }
+}
+
 
 #[inline]
-#[allow(dead_code)]
 fn dot_product_fallback(a: &[f32; 3], b: &[f32; 3]) -> f32 {
     mul_add(a[2], b[2], mul_add(a[1], b[1], a[0] * b[0]))
 }
@@ -37,14 +54,12 @@ mod sse {
     #[cfg(target_arch = "x86_64")]
     use core::arch::x86_64 as arch;
 
-    #[allow(dead_code)]
     #[target_feature(enable = "sse")]
     unsafe fn m128_from_array(arr: &[f32; 3]) -> arch::__m128 {
         arch::_mm_set_ps(arr[0], arr[1], arr[2], 0.0)
     }
 
     #[target_feature(enable = "sse4.1")]
-    #[allow(dead_code)]
     pub(super) unsafe fn dot_product_sse4_1(a: &[f32; 3], b: &[f32; 3]) -> f32 {
         let a = m128_from_array(a);
         let b = m128_from_array(b);
@@ -52,7 +67,6 @@ mod sse {
     }
 
     #[target_feature(enable = "sse")]
-    #[allow(dead_code)]
     pub(super) unsafe fn dot_product_sse(a: &[f32; 3], b: &[f32; 3]) -> f32 {
         let a = m128_from_array(a);
         let b = m128_from_array(b);
@@ -65,6 +79,44 @@ mod sse {
         arch::_mm_cvtss_f32(sums)
     }
 
+    /// Multiplies matrix by four columns at once.
+    ///
+    /// Rather than calculating dot products one at a time, columns are
+    /// transposed such that each register holds the same component of all
+    /// four columns.  Each row of the result is then a sum of three products
+    /// of a register and a broadcast matrix element.
+    #[target_feature(enable = "sse")]
+    pub(super) unsafe fn matrix_product_x4_sse(
+        matrix: &[[f32; 3]; 3],
+        columns: &[[f32; 3]; 4],
+    ) -> [[f32; 3]; 4] {
+        let mut channels = [arch::_mm_setzero_ps(); 3];
+        for (i, channel) in channels.iter_mut().enumerate() {
+            *channel = arch::_mm_set_ps(
+                columns[3][i],
+                columns[2][i],
+                columns[1][i],
+                columns[0][i],
+            );
+        }
+        let mut result = [[0.0; 3]; 4];
+        for (i, row) in matrix.iter().enumerate() {
+            let mut sum =
+                arch::_mm_mul_ps(arch::_mm_set1_ps(row[0]), channels[0]);
+            for (element, channel) in row.iter().zip(channels.iter()).skip(1) {
+                let element = arch::_mm_set1_ps(*element);
+                let product = arch::_mm_mul_ps(element, *channel);
+                sum = arch::_mm_add_ps(sum, product);
+            }
+            let mut values = [0.0; 4];
+            arch::_mm_storeu_ps(values.as_mut_ptr(), sum);
+            for (column, value) in result.iter_mut().zip(values) {
+                column[i] = value;
+            }
+        }
+        result
+    }
+
     pub(super) fn has_sse4_1() -> bool {
         cfg!(target_feature = "sse4.1") || is_x86_feature_detected!("sse4.1")
     }
@@ -75,6 +127,71 @@ mod sse {
 }
 
 
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use core::arch::aarch64 as arch;
+
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn dot_product_neon(a: &[f32; 3], b: &[f32; 3]) -> f32 {
+        let a = arch::vld1q_f32([a[0], a[1], a[2], 0.0].as_ptr());
+        let b = arch::vld1q_f32([b[0], b[1], b[2], 0.0].as_ptr());
+        // Pairwise addition of the products.  Since last lane is zero, this
+        // adds products in the same order as the fallback implementation.
+        arch::vaddvq_f32(arch::vmulq_f32(a, b))
+    }
+
+    /// Returns whether NEON instructions are available.
+    ///
+    /// NEON is part of the baseline aarch64 target so there’s no need for run
+    /// time detection.
+    pub(super) fn has_neon() -> bool { cfg!(target_feature = "neon") }
+}
+
+
+/// Implementation used for vectorised operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Backend {
+    /// Portable implementation with no explicit vectorisation.
+    Scalar,
+    /// Implementation using SSE instructions.
+    Sse,
+    /// Implementation using SSE 4.1 instructions.
+    Sse41,
+    /// Implementation using NEON instructions.
+    Neon,
+}
+
+/// Returns implementation which is used for vectorised operations on the
+/// current CPU.
+///
+/// The result depends on features the crate was built with as well as
+/// features the CPU supports detected at run time.  This is useful for
+/// diagnosing performance problems.
+///
+/// # Example
+/// ```
+/// use srgb::{active_backend, Backend};
+///
+/// let backend = active_backend();
+/// if cfg!(target_arch = "x86_64") {
+///     assert_ne!(Backend::Scalar, backend);
+/// }
+/// println!("Using {:?} backend", backend);
+/// ```
+pub fn active_backend() -> Backend {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if sse::has_sse() {
+        return if sse::has_sse4_1() { Backend::Sse41 } else { Backend::Sse };
+    }
+    #[cfg(target_arch = "aarch64")]
+    if neon::has_neon() {
+        return Backend::Neon;
+    }
+    Backend::Scalar
+}
+
+
 macro_rules! matrix_product_body {
     ($dot:path, $matrix:ident, $column:ident) => {
         [
@@ -104,13 +221,317 @@ pub(crate) fn matrix_product(
             }
         };
     }
+    #[cfg(target_arch = "aarch64")]
+    if neon::has_neon() {
+        // SAFETY: We’ve just checked whether CPU supports NEON.
+        return unsafe {
+            matrix_product_body!(neon::dot_product_neon, matrix, column)
+        };
+    }
     matrix_product_body!(dot_product_fallback, matrix, column)
 }
 
+/// Multiplies matrix by four columns.
+///
+/// This is equivalent to calling [`matrix_product`] for each column but where
+/// CPU supports SSE the four products are calculated in parallel.
+#[inline(always)]
+pub(crate) fn matrix_product_x4(
+    matrix: &[[f32; 3]; 3],
+    columns: &[[f32; 3]; 4],
+) -> [[f32; 3]; 4] {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if sse::has_sse() {
+        // SAFETY: We’ve just checked whether CPU supports SSE.
+        return unsafe { sse::matrix_product_x4_sse(matrix, columns) };
+    }
+    #[cfg(target_arch = "aarch64")]
+    if neon::has_neon() {
+        // SAFETY: We’ve just checked whether CPU supports NEON.
+        return columns.map(|column| unsafe {
+            matrix_product_body!(neon::dot_product_neon, matrix, column)
+        });
+    }
+    columns.map(|column| {
+        matrix_product_body!(dot_product_fallback, matrix, column)
+    })
+}
+
+/// Multiplies matrix by a column performing calculations in double precision.
+///
+/// This is slower than [`matrix_product`] but only rounds the result once.
+pub(crate) fn matrix_product_f64(
+    matrix: &[[f32; 3]; 3],
+    column: [f32; 3],
+) -> [f32; 3] {
+    let dot = |row: &[f32; 3]| {
+        row.iter()
+            .zip(column.iter())
+            .map(|(a, b)| *a as f64 * *b as f64)
+            .sum::<f64>() as f32
+    };
+    [dot(&matrix[0]), dot(&matrix[1]), dot(&matrix[2])]
+}
+
+/// Multiplies matrix by each column loaded from `src` and stores results in
+/// `dst`.
+///
+/// This is equivalent to calling [`matrix_product`] for each element but
+/// colours are processed four at a time with [`matrix_product_x4`] and CPU
+/// feature detection for the remaining ones is done once.
+///
+/// Panics if `src` and `dst` have different lengths.
+#[inline(always)]
+pub(crate) fn matrix_product_map<S: Copy, D>(
+    matrix: &[[f32; 3]; 3],
+    src: &[S],
+    dst: &mut [D],
+    load: impl Fn(S) -> [f32; 3],
+    store: impl Fn([f32; 3]) -> D,
+) {
+    assert_eq!(src.len(), dst.len());
+
+    // Process four colours at a time and handle the tail one by one.
+    let mut src = src.chunks_exact(4);
+    let mut dst = dst.chunks_exact_mut(4);
+    for (src, dst) in (&mut src).zip(&mut dst) {
+        let columns = [load(src[0]), load(src[1]), load(src[2]), load(src[3])];
+        let result = matrix_product_x4(matrix, &columns);
+        for (dst, column) in dst.iter_mut().zip(result) {
+            *dst = store(column);
+        }
+    }
+    let (src, dst) = (src.remainder(), dst.into_remainder());
+
+    macro_rules! run {
+        ($dot:path) => {
+            for (src, dst) in src.iter().zip(dst.iter_mut()) {
+                let column = load(*src);
+                *dst = store(matrix_product_body!($dot, matrix, column));
+            }
+        };
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if sse::has_sse() {
+        if sse::has_sse4_1() {
+            // SAFETY: We’ve just checked whether CPU supports SSE 4.1.
+            unsafe { run!(sse::dot_product_sse4_1) }
+        } else {
+            // SAFETY: We’ve just checked whether CPU supports SSE.
+            unsafe { run!(sse::dot_product_sse) }
+        }
+        return;
+    }
+    #[cfg(target_arch = "aarch64")]
+    if neon::has_neon() {
+        // SAFETY: We’ve just checked whether CPU supports NEON.
+        unsafe { run!(neon::dot_product_neon) }
+        return;
+    }
+    run!(dot_product_fallback)
+}
+
+
+/// Multiplies each column loaded from `src` by `first` matrix, transforms the
+/// result with `between`, multiplies it by `second` matrix and stores results
+/// in `dst`.
+///
+/// This is equivalent to calling [`matrix_product`] twice for each element but
+/// CPU feature detection is done once for the whole buffer.
+///
+/// Panics if `src` and `dst` have different lengths.
+#[inline(always)]
+pub(crate) fn matrix_product2_map<S: Copy, D>(
+    first: &[[f32; 3]; 3],
+    second: &[[f32; 3]; 3],
+    src: &[S],
+    dst: &mut [D],
+    load: impl Fn(S) -> [f32; 3],
+    between: impl Fn([f32; 3]) -> [f32; 3],
+    store: impl Fn([f32; 3]) -> D,
+) {
+    assert_eq!(src.len(), dst.len());
+    macro_rules! run {
+        ($dot:path) => {
+            for (src, dst) in src.iter().zip(dst.iter_mut()) {
+                let column = load(*src);
+                let column = between(matrix_product_body!($dot, first, column));
+                *dst = store(matrix_product_body!($dot, second, column));
+            }
+        };
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if sse::has_sse() {
+        if sse::has_sse4_1() {
+            // SAFETY: We’ve just checked whether CPU supports SSE 4.1.
+            unsafe { run!(sse::dot_product_sse4_1) }
+        } else {
+            // SAFETY: We’ve just checked whether CPU supports SSE.
+            unsafe { run!(sse::dot_product_sse) }
+        }
+        return;
+    }
+    #[cfg(target_arch = "aarch64")]
+    if neon::has_neon() {
+        // SAFETY: We’ve just checked whether CPU supports NEON.
+        unsafe { run!(neon::dot_product_neon) }
+        return;
+    }
+    run!(dot_product_fallback)
+}
+
+
+/// Error returned by [`inverse`] when a matrix is singular or nearly singular.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SingularMatrix;
+
+impl core::fmt::Display for SingularMatrix {
+    fn fmt(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmtr.write_str("matrix is singular")
+    }
+}
+
+impl std::error::Error for SingularMatrix {}
+
+/// Tolerance below which [`inverse`] considers a matrix singular.
+///
+/// The tolerance is relative: matrix is considered singular if absolute value
+/// of its determinant is at most this value times product of Euclidean norms
+/// of its rows.  By Hadamard’s inequality the ratio is at most one with one
+/// reached by orthogonal rows.  Being relative, the test doesn’t depend on
+/// scale of the matrix.
+pub(crate) const SINGULAR_TOLERANCE: f64 = 1e-6;
+
+/// Inverts a 3×3 row-major matrix.
+///
+/// Calculations are done in double precision and only the result is rounded
+/// to single precision.  Returns an error if the matrix is singular or so
+/// close to being singular that its inverse would be dominated by rounding
+/// errors.  The test is relative: matrix is rejected if absolute value of its
+/// determinant is at most 10⁻⁶ times product of Euclidean norms of its rows.
+/// This also includes matrices with non-finite elements.  This is useful for
+/// matrices constructed at run time, e.g. from primaries chromaticities
+/// supplied by the user.
+///
+/// # Example
+/// ```
+/// use srgb::{inverse, SingularMatrix};
+/// use srgb::xyz::{SRGB_FROM_XYZ_MATRIX, XYZ_FROM_SRGB_MATRIX};
+///
+/// let inv = inverse(&XYZ_FROM_SRGB_MATRIX).unwrap();
+/// for (want, got) in SRGB_FROM_XYZ_MATRIX.iter().zip(inv.iter()) {
+///     approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = 1e-6);
+/// }
+///
+/// let singular = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+/// assert_eq!(Err(SingularMatrix), inverse(&singular));
+/// ```
+pub fn inverse(
+    matrix: &[[f32; 3]; 3],
+) -> Result<[[f32; 3]; 3], SingularMatrix> {
+    let m = matrix.map(|row| row.map(f64::from));
+    checked_inverse_f64(&m).map(|inv| inv.map(|row| row.map(|v| v as f32)))
+}
+
+/// Inverts a 3×3 matrix returning an error if it’s singular.
+///
+/// See [`inverse`] for description of the tolerance.
+pub(crate) fn checked_inverse_f64(
+    m: &[[f64; 3]; 3],
+) -> Result<[[f64; 3]; 3], SingularMatrix> {
+    let norms = m
+        .iter()
+        .map(|row| row.iter().map(|v| v * v).sum::<f64>().sqrt())
+        .product::<f64>();
+    // Comparison is false for NaNs which results in an error.
+    if determinant_f64(m).abs() > SINGULAR_TOLERANCE * norms {
+        Ok(inverse_f64(m))
+    } else {
+        Err(SingularMatrix)
+    }
+}
+
+/// Multiplies two 3×3 matrices.
+pub(crate) fn matrix_multiply_f64(
+    a: &[[f64; 3]; 3],
+    b: &[[f64; 3]; 3],
+) -> [[f64; 3]; 3] {
+    let mut result = [[0.0; 3]; 3];
+    for (row, a) in result.iter_mut().zip(a.iter()) {
+        for (col, value) in row.iter_mut().enumerate() {
+            *value = (0..3).map(|k| a[k] * b[k][col]).sum();
+        }
+    }
+    result
+}
 
+/// Returns minor of a 3×3 matrix with sign of the cofactor, i.e. cofactor
+/// of the element at given row and column.
+fn cofactor_f64(m: &[[f64; 3]; 3], r: usize, c: usize) -> f64 {
+    let (r0, r1) = ((r + 1) % 3, (r + 2) % 3);
+    let (c0, c1) = ((c + 1) % 3, (c + 2) % 3);
+    m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+}
+
+/// Returns determinant of a 3×3 matrix.
+fn determinant_f64(m: &[[f64; 3]; 3]) -> f64 {
+    (0..3).map(|c| m[0][c] * cofactor_f64(m, 0, c)).sum()
+}
+
+/// Inverts a 3×3 matrix without checking whether it’s singular.
+pub(crate) fn inverse_f64(m: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let det = determinant_f64(m);
+    let mut result = [[0.0; 3]; 3];
+    for (r, row) in result.iter_mut().enumerate() {
+        for (c, value) in row.iter_mut().enumerate() {
+            *value = cofactor_f64(m, c, r) / det;
+        }
+    }
+    result
+}
 
 #[cfg(test)]
 mod test {
+    #[test]
+    fn test_inverse() {
+        use super::{inverse, SingularMatrix};
+
+        let matrices = [
+            crate::xyz::XYZ_FROM_SRGB_MATRIX,
+            crate::xyz::SRGB_FROM_XYZ_MATRIX,
+            [[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 0.5]],
+            [[0.0, 1.0, 0.0], [0.0, 0.0, 1.0], [1.0, 0.0, 0.0]],
+            [[1e-20, 0.0, 0.0], [0.0, 1e-20, 0.0], [0.0, 0.0, 1e-20]],
+        ];
+        for matrix in matrices {
+            let inv = inverse(&matrix).unwrap();
+            for (r, row) in matrix.iter().enumerate() {
+                let got = [0, 1, 2].map(|c| {
+                    (0..3).map(|k| row[k] as f64 * inv[k][c] as f64).sum()
+                });
+                let mut want = [0.0; 3];
+                want[r] = 1.0;
+                approx::assert_abs_diff_eq!(
+                    &want[..],
+                    &got[..],
+                    epsilon = 1e-6
+                );
+            }
+        }
+
+        for matrix in [
+            [[0.0; 3]; 3],
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]],
+            [[1.0, 2.0, 3.0], [2.0, 4.0, 6.000001], [0.0, 0.0, 1.0]],
+            [[1.0, 0.0, 0.0], [0.0, f32::NAN, 0.0], [0.0, 0.0, 1.0]],
+            [[1.0, 0.0, 0.0], [0.0, f32::INFINITY, 0.0], [0.0, 0.0, 1.0]],
+        ] {
+            assert_eq!(Err(SingularMatrix), inverse(&matrix), "{:?}", matrix);
+        }
+    }
+
     #[test]
     pub fn test_matrix_product() {
         let matrix = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
@@ -120,6 +541,15 @@ mod test {
         );
     }
 
+    #[test]
+    pub fn test_matrix_product_f64() {
+        let matrix = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        assert_eq!(
+            [321.0, 654.0, 987.0],
+            super::matrix_product_f64(&matrix, [1.0, 10.0, 100.0])
+        );
+    }
+
     const A: [f32; 3] = [1.0, 2.0, 3.0];
     const B: [f32; 3] = [2.0, 20.0, 200.0];
     const WANT: f32 = 642.0;
@@ -158,4 +588,150 @@ mod test {
             unsupported("SSE 4.1 support");
         }
     }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Not supported on Miri")]
+    fn test_matrix_product_x4() {
+        // See test_sse_divergence.
+        const MAX_ULPS: u32 = if cfg!(target_feature = "fma") { 2 } else { 0 };
+        let matrix = &crate::xyz::XYZ_FROM_SRGB_MATRIX;
+        let columns: Vec<[f32; 3]> = (0..(16 * 16 * 16))
+            .map(|c| {
+                let r = (c & 15) as f32 / 15.0;
+                let g = ((c >> 4) & 15) as f32 / 15.0;
+                let b = ((c >> 8) & 15) as f32 / 15.0;
+                [r, g, b]
+            })
+            .collect();
+        for chunk in columns.chunks_exact(4) {
+            let chunk = [chunk[0], chunk[1], chunk[2], chunk[3]];
+            let got = super::matrix_product_x4(matrix, &chunk);
+            for (column, got) in chunk.iter().zip(got) {
+                let want = matrix_product_body!(
+                    super::dot_product_fallback,
+                    matrix,
+                    column
+                );
+                let ulps = [0, 1, 2].map(|i| ulp_distance(want[i], got[i]));
+                let ok = ulps.iter().max() <= Some(&MAX_ULPS);
+                assert!(ok, "{:?}: {:?}", column, ulps);
+            }
+        }
+
+        // Check handling of buffers whose length is not a multiple of four.
+        for len in 0..10 {
+            let src = &columns[100..100 + len];
+            let mut dst = vec![[0.0; 3]; len];
+            super::matrix_product_map(matrix, src, &mut dst, |c| c, |c| c);
+            for (src, got) in src.iter().zip(dst) {
+                let want = super::matrix_product(matrix, *src);
+                let ulps = [0, 1, 2].map(|i| ulp_distance(want[i], got[i]));
+                let ok = ulps.iter().max() <= Some(&MAX_ULPS);
+                assert!(ok, "{}: {:?}: {:?}", len, src, ulps);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Not supported on Miri")]
+    fn test_active_backend() {
+        let got = super::active_backend();
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        {
+            let want = if is_x86_feature_detected!("sse4.1") {
+                super::Backend::Sse41
+            } else if is_x86_feature_detected!("sse") {
+                super::Backend::Sse
+            } else {
+                super::Backend::Scalar
+            };
+            assert_eq!(want, got);
+        }
+        #[cfg(target_arch = "x86_64")]
+        assert_ne!(super::Backend::Scalar, got);
+        #[cfg(target_arch = "aarch64")]
+        assert_eq!(super::Backend::Neon, got);
+        #[cfg(not(any(
+            target_arch = "x86",
+            target_arch = "x86_64",
+            target_arch = "aarch64"
+        )))]
+        assert_eq!(super::Backend::Scalar, got);
+    }
+
+    /// Returns distance between two numbers in units in the last place of one.
+    ///
+    /// Colour components are in the 0–1 range so this is the precision which
+    /// matters.  Measuring relative to the numbers themselves would blow up
+    /// for results near zero where cancellation happens.
+    fn ulp_distance(a: f32, b: f32) -> u32 {
+        ((a as f64 - b as f64).abs() / f32::EPSILON as f64).ceil() as u32
+    }
+
+    /// Sweeps the RGB cube converting each colour to XYZ and back with matrix
+    /// product using `dot` function and with the fallback implementation and
+    /// returns maximum difference (in ULPs) for each channel.
+    pub(crate) fn max_ulp_difference(
+        dot: unsafe fn(&[f32; 3], &[f32; 3]) -> f32,
+    ) -> [u32; 3] {
+        let mut max = [0; 3];
+        let mut check = |matrix: &[[f32; 3]; 3], column: [f32; 3]| {
+            let want = matrix_product_body!(
+                super::dot_product_fallback,
+                matrix,
+                column
+            );
+            // SAFETY: Caller promises that `dot` is safe to call.
+            let got = unsafe { matrix_product_body!(dot, matrix, column) };
+            for ((max, want), got) in max.iter_mut().zip(want).zip(got) {
+                *max = (*max).max(ulp_distance(want, got));
+            }
+            got
+        };
+        for c in 0..(32 * 32 * 32) {
+            let r = (c & 31) as f32 / 31.0;
+            let g = ((c >> 5) & 31) as f32 / 31.0;
+            let b = ((c >> 10) & 31) as f32 / 31.0;
+            let xyz = check(&crate::xyz::XYZ_FROM_SRGB_MATRIX, [r, g, b]);
+            check(&crate::xyz::SRGB_FROM_XYZ_MATRIX, xyz);
+        }
+        max
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    fn test_sse_divergence() {
+        // Without FMA all implementations add products in the same order and
+        // produce identical results.  With FMA the fallback rounds fewer times
+        // so allow for small differences.
+        const MAX_ULPS: u32 = if cfg!(target_feature = "fma") { 2 } else { 0 };
+        if is_x86_feature_detected!("sse") {
+            let got = max_ulp_difference(super::sse::dot_product_sse);
+            assert!(got.iter().max() <= Some(&MAX_ULPS), "SSE: {:?}", got);
+        }
+        if is_x86_feature_detected!("sse4.1") {
+            let got = max_ulp_difference(super::sse::dot_product_sse4_1);
+            assert!(got.iter().max() <= Some(&MAX_ULPS), "SSE 4.1: {:?}", got);
+        } else {
+            unsupported("SSE 4.1 support");
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Not supported on Miri")]
+    #[cfg(target_arch = "aarch64")]
+    fn test_dot_product_neon() {
+        assert_eq!(WANT, unsafe { super::neon::dot_product_neon(&A, &B) });
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    #[cfg(target_arch = "aarch64")]
+    fn test_neon_divergence() {
+        // See test_sse_divergence.
+        const MAX_ULPS: u32 = if cfg!(target_feature = "fma") { 2 } else { 0 };
+        let got = max_ulp_difference(super::neon::dot_product_neon);
+        assert!(got.iter().max() <= Some(&MAX_ULPS), "NEON: {:?}", got);
+    }
 }
@@ -0,0 +1,383 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions implementing conversion between linear sRGB and [Oklab colour
+//! space](https://bottosson.github.io/posts/oklab/).
+//!
+//! The module also provides conversion between Oklab and its polar form,
+//! Oklch, where colour is described by lightness, chroma and hue.  Throughout
+//! the crate, hue is given in degrees by default (which matches CSS `oklch()`
+//! notation).  Functions which use radians have `_rad` suffix and, where
+//! radian variant exists, the degree variant has `_deg` suffix.  See also
+//! [`crate::oklch`] module which provides the degree variants under shorter
+//! names.
+
+/// Converts a colour in linear sRGB space into Oklab colour space.
+///
+/// The colour is given as three components each in the range from zero to one.
+/// The result is given as `[L, a, b]` triple where lightness is in the range
+/// from zero to one (with white having lightness equal one).
+///
+/// # Example
+/// ```
+/// use srgb::oklab::oklab_from_linear;
+///
+/// let [l, a, b] = oklab_from_linear([1.0, 1.0, 1.0]);
+/// approx::assert_abs_diff_eq!(1.0, l, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.0, a, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.0, b, epsilon = 0.0001);
+/// ```
+pub fn oklab_from_linear(linear: impl Into<[f32; 3]>) -> [f32; 3] {
+    let lms = crate::maths::matrix_product(&LMS_FROM_LINEAR, linear.into());
+    let lms = crate::arr_map(lms, f32::cbrt);
+    crate::maths::matrix_product(&OKLAB_FROM_LMS, lms)
+}
+
+/// Converts a colour in Oklab colour space into linear sRGB space.
+///
+/// This is an inverse of [`oklab_from_linear()`].  Note that not all Oklab
+/// colours can be represented in sRGB space and for such colours the result
+/// will have components outside of the range from zero to one.
+///
+/// # Example
+/// ```
+/// use srgb::oklab::linear_from_oklab;
+///
+/// let [r, g, b] = linear_from_oklab([1.0, 0.0, 0.0]);
+/// approx::assert_abs_diff_eq!(1.0, r, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(1.0, g, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(1.0, b, epsilon = 0.0001);
+/// ```
+pub fn linear_from_oklab(oklab: impl Into<[f32; 3]>) -> [f32; 3] {
+    let lms = crate::maths::matrix_product(&LMS_FROM_OKLAB, oklab.into());
+    let lms = crate::arr_map(lms, |v| v * v * v);
+    crate::maths::matrix_product(&LINEAR_FROM_LMS, lms)
+}
+
+/// Converts a 24-bit sRGB colour into Oklab colour space.
+///
+/// This is a convenience function which performs gamma expansion (see
+/// [`crate::gamma::linear_from_u8()`]) followed by [`oklab_from_linear()`].
+/// See [`oklab_from_u8_slice()`] for converting buffers of colours.
+///
+/// # Example
+/// ```
+/// use srgb::oklab::oklab_from_u8;
+///
+/// let [l, a, b] = oklab_from_u8([255, 255, 255]);
+/// approx::assert_abs_diff_eq!(1.0, l, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.0, a, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.0, b, epsilon = 0.0001);
+/// ```
+pub fn oklab_from_u8(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
+    oklab_from_linear(crate::gamma::linear_from_u8(rgb))
+}
+
+/// Converts a colour in Oklab colour space into 24-bit sRGB colour.
+///
+/// This is a convenience function which performs [`linear_from_oklab()`]
+/// followed by gamma compression (see [`crate::gamma::u8_from_linear()`]).
+/// Colours outside of sRGB gamut are clamped; see
+/// [`crate::gamut::to_srgb_oklch()`] for gamut mapping.
+///
+/// # Example
+/// ```
+/// use srgb::oklab::{oklab_from_u8, u8_from_oklab};
+///
+/// assert_eq!([212, 33, 61], u8_from_oklab(oklab_from_u8([212, 33, 61])));
+/// assert_eq!([255, 255, 255], u8_from_oklab([1.0, 0.0, 0.0]));
+/// ```
+pub fn u8_from_oklab(oklab: impl Into<[f32; 3]>) -> [u8; 3] {
+    crate::gamma::u8_from_linear(linear_from_oklab(oklab))
+}
+
+
+/// Converts a buffer of 24-bit sRGB colours into Oklab colour space.
+///
+/// This is equivalent to converting each element of `src` with
+/// [`crate::gamma::linear_from_u8()`] followed by [`oklab_from_linear()`] and
+/// storing results in `dst` but avoids per-colour overhead and doesn’t need an
+/// intermediate buffer for linear sRGB values.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// # Example
+/// ```
+/// use srgb::oklab::{oklab_from_linear, oklab_from_u8_slice};
+///
+/// let src = [[212, 33, 61], [255, 255, 255]];
+/// let mut dst = [[0.0; 3]; 2];
+/// oklab_from_u8_slice(&src, &mut dst);
+/// let want = oklab_from_linear(srgb::gamma::linear_from_u8(src[0]));
+/// assert_eq!(want, dst[0]);
+/// approx::assert_abs_diff_eq!(1.0, dst[1][0], epsilon = 0.0001);
+/// ```
+pub fn oklab_from_u8_slice(src: &[[u8; 3]], dst: &mut [[f32; 3]]) {
+    crate::maths::matrix_product2_map(
+        &LMS_FROM_LINEAR,
+        &OKLAB_FROM_LMS,
+        src,
+        dst,
+        crate::gamma::linear_from_u8,
+        |lms| crate::arr_map(lms, f32::cbrt),
+        |oklab| oklab,
+    )
+}
+
+/// Converts a buffer of colours in Oklab colour space into 24-bit sRGB
+/// colours.
+///
+/// This is equivalent to converting each element of `src` with
+/// [`linear_from_oklab()`] followed by [`crate::gamma::u8_from_linear()`] and
+/// storing results in `dst` but avoids per-colour overhead and doesn’t need an
+/// intermediate buffer for linear sRGB values.  Colours outside of sRGB gamut
+/// are clamped.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+///
+/// # Example
+/// ```
+/// use srgb::oklab::{oklab_from_u8_slice, u8_from_oklab_slice};
+///
+/// let src = [[212, 33, 61], [255, 255, 255]];
+/// let mut oklab = [[0.0; 3]; 2];
+/// oklab_from_u8_slice(&src, &mut oklab);
+/// let mut dst = [[0; 3]; 2];
+/// u8_from_oklab_slice(&oklab, &mut dst);
+/// assert_eq!(src, dst);
+/// ```
+pub fn u8_from_oklab_slice(src: &[[f32; 3]], dst: &mut [[u8; 3]]) {
+    crate::maths::matrix_product2_map(
+        &LMS_FROM_OKLAB,
+        &LINEAR_FROM_LMS,
+        src,
+        dst,
+        |oklab| oklab,
+        |lms| crate::arr_map(lms, |v| v * v * v),
+        crate::gamma::u8_from_linear,
+    )
+}
+
+/// Converts a colour in Oklab colour space into Oklch with hue in degrees.
+///
+/// The result is given as `[L, C, h]` triple where lightness is unchanged,
+/// chroma is non-negative and hue is in the range from zero (inclusive) to
+/// 360 (exclusive).  For achromatic colours hue is undefined and zero is
+/// returned.  Colours with chroma below [`ACHROMATIC_CHROMA`] are treated as
+/// achromatic; this makes greys converted from sRGB (whose `a` and `b` are
+/// zero only up to rounding errors) consistently get hue of zero.
+///
+/// # Example
+/// ```
+/// use srgb::oklab::oklch_from_oklab_deg;
+///
+/// assert_eq!([0.5, 0.2, 90.0], oklch_from_oklab_deg([0.5, 0.0, 0.2]));
+/// assert_eq!([0.5, 0.2, 180.0], oklch_from_oklab_deg([0.5, -0.2, 0.0]));
+/// ```
+pub fn oklch_from_oklab_deg(oklab: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [l, a, b] = oklab.into();
+    let c = a.hypot(b);
+    if c < ACHROMATIC_CHROMA {
+        return [l, c, 0.0];
+    }
+    [l, c, crate::maths::wrap_angle(b.atan2(a).to_degrees(), 360.0)]
+}
+
+/// Converts a colour in Oklab colour space into Oklch with hue in radians.
+///
+/// This is like [`oklch_from_oklab_deg()`] except that hue is in the range
+/// from zero (inclusive) to 2π (exclusive).
+///
+/// # Example
+/// ```
+/// use srgb::oklab::oklch_from_oklab_rad;
+///
+/// let [l, c, h] = oklch_from_oklab_rad([0.5, 0.0, 0.2]);
+/// assert_eq!([0.5, 0.2, core::f32::consts::FRAC_PI_2], [l, c, h]);
+/// ```
+pub fn oklch_from_oklab_rad(oklab: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [l, a, b] = oklab.into();
+    let c = a.hypot(b);
+    if c < ACHROMATIC_CHROMA {
+        return [l, c, 0.0];
+    }
+    [l, c, crate::maths::wrap_angle(b.atan2(a), core::f32::consts::TAU)]
+}
+
+/// Chroma below which colour is treated as achromatic when converting into
+/// Oklch.
+///
+/// Converting grey sRGB colours into Oklab doesn’t produce exactly zero `a`
+/// and `b` coordinates due to rounding errors.  Hue calculated from such
+/// noise would be arbitrary, thus colours whose chroma is below this
+/// threshold get hue of zero.  The value is far below what human observer
+/// can notice.
+pub const ACHROMATIC_CHROMA: f32 = 1e-5;
+
+/// Converts a colour in Oklch colour space with hue in degrees into Oklab.
+///
+/// This is an inverse of [`oklch_from_oklab_deg()`].  Hue may be any angle,
+/// i.e. it doesn’t have to be in the range from zero to 360.
+///
+/// # Example
+/// ```
+/// use srgb::oklab::oklab_from_oklch_deg;
+///
+/// let [l, a, b] = oklab_from_oklch_deg([0.5, 0.2, 90.0]);
+/// approx::assert_abs_diff_eq!(0.5, l);
+/// approx::assert_abs_diff_eq!(0.0, a);
+/// approx::assert_abs_diff_eq!(0.2, b);
+/// ```
+pub fn oklab_from_oklch_deg(oklch: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [l, c, h] = oklch.into();
+    oklab_from_oklch_rad([l, c, h.to_radians()])
+}
+
+/// Converts a colour in Oklch colour space with hue in radians into Oklab.
+///
+/// This is an inverse of [`oklch_from_oklab_rad()`].
+///
+/// # Example
+/// ```
+/// use srgb::oklab::oklab_from_oklch_rad;
+///
+/// let [l, a, b] = oklab_from_oklch_rad([0.5, 0.2, core::f32::consts::PI]);
+/// approx::assert_abs_diff_eq!(0.5, l);
+/// approx::assert_abs_diff_eq!(-0.2, a);
+/// approx::assert_abs_diff_eq!(0.0, b);
+/// ```
+pub fn oklab_from_oklch_rad(oklch: impl Into<[f32; 3]>) -> [f32; 3] {
+    let [l, c, h] = oklch.into();
+    let (sin, cos) = h.sin_cos();
+    [l, c * cos, c * sin]
+}
+
+/// Matrix converting linear sRGB into LMS cone responses used by Oklab.
+const LMS_FROM_LINEAR: [[f32; 3]; 3] = [
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+];
+
+/// Matrix converting non-linear LMS cone responses into Oklab.
+const OKLAB_FROM_LMS: [[f32; 3]; 3] = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+
+/// Inverse of [`OKLAB_FROM_LMS`].
+const LMS_FROM_OKLAB: [[f32; 3]; 3] = [
+    [1.0, 0.3963377774, 0.2158037573],
+    [1.0, -0.1055613458, -0.0638541728],
+    [1.0, -0.0894841775, -1.2914855480],
+];
+
+/// Inverse of [`LMS_FROM_LINEAR`].
+const LINEAR_FROM_LMS: [[f32; 3]; 3] = [
+    [4.0767416621, -3.3077115913, 0.2309699292],
+    [-1.2684380046, 2.6097574011, -0.3413193965],
+    [-0.0041960863, -0.7034186147, 1.7076147010],
+];
+
+
+#[cfg(test)]
+mod test {
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_slice() {
+        let src = (0..(1 << 24))
+            .step_by(997)
+            .map(crate::from_u32)
+            .collect::<Vec<_>>();
+        let mut oklab = vec![[0.0; 3]; src.len()];
+        super::oklab_from_u8_slice(&src, &mut oklab);
+        let mut dst = vec![[0; 3]; src.len()];
+        super::u8_from_oklab_slice(&oklab, &mut dst);
+        for ((rgb, oklab), dst) in src.iter().zip(oklab.iter()).zip(dst.iter())
+        {
+            let linear = crate::gamma::linear_from_u8(*rgb);
+            assert_eq!(super::oklab_from_linear(linear), *oklab, "{:?}", rgb);
+            let linear = super::linear_from_oklab(*oklab);
+            assert_eq!(crate::gamma::u8_from_linear(linear), *dst, "{:?}", rgb);
+            assert_eq!(rgb, dst);
+        }
+    }
+
+    #[test]
+    fn test_reversible_conversion() {
+        for c in 0..(16 * 16 * 16) {
+            let r = (c & 15) as f32 / 15.0;
+            let g = ((c >> 4) & 15) as f32 / 15.0;
+            let b = ((c >> 8) & 15) as f32 / 15.0;
+            let src = [r, g, b];
+            let dst = super::linear_from_oklab(super::oklab_from_linear(src));
+            approx::assert_abs_diff_eq!(&src[..], &dst[..], epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_near_black() {
+        // Cube root of negative LMS responses (which slightly out of gamut
+        // colours near black produce) must not result in NaNs.
+        for src in [
+            [0.0, 0.0, 0.0],
+            [-1e-6, 0.0, 0.0],
+            [0.0, -1e-6, 1e-6],
+            [1e-7, -1e-7, 1e-7],
+            [-1e-4, -1e-4, -1e-4],
+        ] {
+            let oklab = super::oklab_from_linear(src);
+            assert!(oklab.iter().all(|v| v.is_finite()), "{:?}", src);
+            let dst = super::linear_from_oklab(oklab);
+            approx::assert_abs_diff_eq!(&src[..], &dst[..], epsilon = 1e-7);
+        }
+        for v in 0..=16 {
+            let rgb = [v, 16 - v, v / 2];
+            assert_eq!(rgb, super::u8_from_oklab(super::oklab_from_u8(rgb)));
+        }
+    }
+
+    #[test]
+    fn test_oklch_deg_rad() {
+        for h in [0.0, 20.0, 90.0, 135.0, 180.0, 200.0, 270.0, 359.0] {
+            let oklab = super::oklab_from_oklch_deg([0.6, 0.1, h]);
+            let rad =
+                super::oklab_from_oklch_rad([0.6, 0.1, f32::to_radians(h)]);
+            approx::assert_abs_diff_eq!(&oklab[..], &rad[..]);
+            // Hue outside of [0, 360) range.
+            let got = super::oklab_from_oklch_deg([0.6, 0.1, h - 720.0]);
+            approx::assert_abs_diff_eq!(&oklab[..], &got[..], epsilon = 0.0001);
+
+            let [l, c, deg] = super::oklch_from_oklab_deg(oklab);
+            let [_, _, rad] = super::oklch_from_oklab_rad(oklab);
+            approx::assert_abs_diff_eq!(0.6, l);
+            approx::assert_abs_diff_eq!(0.1, c);
+            let diff = (deg - h + 180.0).rem_euclid(360.0) - 180.0;
+            assert!(diff.abs() < 0.001, "{} vs {}", h, deg);
+            approx::assert_abs_diff_eq!(deg, rad.to_degrees(), epsilon = 0.001);
+            assert!((0.0..360.0).contains(&deg), "{}", deg);
+            assert!((0.0..core::f32::consts::TAU).contains(&rad), "{}", rad);
+        }
+        assert_eq!(
+            [0.5, 0.0, 0.0],
+            super::oklch_from_oklab_deg([0.5, 0.0, 0.0])
+        );
+    }
+}
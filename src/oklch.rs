@@ -0,0 +1,104 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions implementing conversion between Oklab and its polar form, Oklch.
+//!
+//! Oklch describes colour by lightness, chroma and hue.  Lightness is the same
+//! as in Oklab while chroma and hue are polar coordinates of the `a` and `b`
+//! Oklab axes.  Hue is given in degrees; use [`crate::oklab`] functions with
+//! `_rad` suffix for radians.
+
+/// Converts a colour in Oklab colour space into Oklch.
+///
+/// The result is given as `[L, C, h]` triple where lightness is passed through
+/// unchanged, chroma is `hypot(a, b)` and hue is `atan2(b, a)` in degrees in
+/// the range from zero (inclusive) to 360 (exclusive).
+///
+/// For achromatic colours hue is undefined.  To keep the result deterministic,
+/// hue of zero is returned for colours whose chroma is below
+/// [`crate::oklab::ACHROMATIC_CHROMA`].
+///
+/// # Example
+/// ```
+/// use srgb::oklch::oklch_from_oklab;
+///
+/// assert_eq!([0.5, 0.2, 90.0], oklch_from_oklab([0.5, 0.0, 0.2]));
+/// assert_eq!([0.5, 0.0, 0.0], oklch_from_oklab([0.5, -0.0, -0.0]));
+///
+/// let grey = srgb::oklab::oklab_from_u8([128, 128, 128]);
+/// assert_eq!(0.0, oklch_from_oklab(grey)[2]);
+/// ```
+#[inline]
+pub fn oklch_from_oklab(oklab: impl Into<[f32; 3]>) -> [f32; 3] {
+    crate::oklab::oklch_from_oklab_deg(oklab)
+}
+
+/// Converts a colour in Oklch colour space into Oklab.
+///
+/// This is an inverse of [`oklch_from_oklab()`].  Hue is given in degrees and
+/// may be any angle, i.e. it doesn’t have to be in the range from zero to 360.
+/// Lightness is passed through unchanged which makes it easy to generate hue
+/// ramps of constant lightness.
+///
+/// # Example
+/// ```
+/// use srgb::oklch::oklab_from_oklch;
+///
+/// let [l, a, b] = oklab_from_oklch([0.5, 0.2, 450.0]);
+/// assert_eq!(0.5, l);
+/// approx::assert_abs_diff_eq!(0.0, a);
+/// approx::assert_abs_diff_eq!(0.2, b);
+/// ```
+#[inline]
+pub fn oklab_from_oklch(oklch: impl Into<[f32; 3]>) -> [f32; 3] {
+    crate::oklab::oklab_from_oklch_deg(oklch)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_hue_ramp() {
+        for l in [0.0, 0.3, 0.627955, 1.0] {
+            for h in (-360..720).step_by(15) {
+                let h = h as f32;
+                let oklab = oklab_from_oklch([l, 0.1, h]);
+                assert_eq!(l, oklab[0]);
+                let [got_l, c, got_h] = oklch_from_oklab(oklab);
+                assert_eq!(l, got_l);
+                approx::assert_abs_diff_eq!(0.1, c, epsilon = 0.00001);
+                assert!((0.0..360.0).contains(&got_h), "{}", got_h);
+                let diff = (got_h - h + 180.0).rem_euclid(360.0) - 180.0;
+                assert!(diff.abs() < 0.001, "{} vs {}", h, got_h);
+            }
+        }
+    }
+
+    #[test]
+    fn test_achromatic() {
+        for v in 0..=255 {
+            let oklab = crate::oklab::oklab_from_u8([v, v, v]);
+            let [l, c, h] = oklch_from_oklab(oklab);
+            assert_eq!(oklab[0], l);
+            assert!(c < crate::oklab::ACHROMATIC_CHROMA, "{}: {}", v, c);
+            assert_eq!(0.0, h, "{}", v);
+        }
+        for (a, b) in [(0.0, 0.0), (-0.0, 0.0), (0.0, -0.0), (-0.0, -0.0)] {
+            assert_eq!([0.5, 0.0, 0.0], oklch_from_oklab([0.5, a, b]));
+        }
+    }
+}
@@ -0,0 +1,155 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions and constants handling conversion between Display P3 colour space
+//! and XYZ colour space.
+//!
+//! Display P3 uses DCI-P3 primaries with D65 white point (the same as sRGB)
+//! and sRGB transfer function.  Its gamut is about 25% larger than that of
+//! sRGB.  The conversion matrices are calculated at build time with the same
+//! exact rational arithmetic as sRGB matrices (see [`crate::xyz`] module).
+
+/// Converts a colour in linear Display P3 space into XYZ colour space.
+///
+/// The colour is given as three components each in the range from zero to one.
+/// Resulting XYZ space is one where white colour has Y coordinate equal one.
+///
+/// # Example
+/// ```
+/// use srgb::p3::xyz_from_p3_linear;
+///
+/// let xyz = xyz_from_p3_linear([1.0, 1.0, 1.0]);
+/// approx::assert_abs_diff_eq!(
+///     &srgb::xyz::D65_XYZ[..],
+///     &xyz[..],
+///     epsilon = 0.00001
+/// );
+/// ```
+pub fn xyz_from_p3_linear(linear: impl Into<[f32; 3]>) -> [f32; 3] {
+    crate::maths::matrix_product(&XYZ_FROM_P3_MATRIX, linear.into())
+}
+
+/// Converts a colour in XYZ colour space into linear Display P3 space.
+///
+/// This is an inverse of [`xyz_from_p3_linear()`].  Colours outside of Display
+/// P3 gamut result in components outside of the range from zero to one.
+///
+/// # Example
+/// ```
+/// use srgb::p3::p3_linear_from_xyz;
+///
+/// // Pure sRGB red is inside of Display P3 gamut.
+/// let [r, g, b] = p3_linear_from_xyz(srgb::xyz_from_u8([255, 0, 0]));
+/// approx::assert_abs_diff_eq!(0.82246, r, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.03319, g, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.01708, b, epsilon = 0.0001);
+/// ```
+pub fn p3_linear_from_xyz(xyz: impl Into<[f32; 3]>) -> [f32; 3] {
+    crate::maths::matrix_product(&P3_FROM_XYZ_MATRIX, xyz.into())
+}
+
+/// Converts a 24-bit Display P3 colour into linear space.
+///
+/// Display P3 uses sRGB transfer function so this is the same as
+/// [`crate::gamma::linear_from_u8()`].
+pub fn p3_linear_from_u8(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
+    crate::gamma::linear_from_u8(rgb)
+}
+
+/// Converts a colour in linear Display P3 space into 24-bit representation.
+///
+/// Display P3 uses sRGB transfer function so this is the same as
+/// [`crate::gamma::u8_from_linear()`].  Components are clamped to the range
+/// from zero to one.
+pub fn p3_u8_from_linear(linear: impl Into<[f32; 3]>) -> [u8; 3] {
+    crate::gamma::u8_from_linear(linear)
+}
+
+/// Converts a 24-bit Display P3 colour into XYZ colour space.
+///
+/// # Example
+/// ```
+/// use srgb::p3::{p3_u8_from_xyz, xyz_from_p3_u8};
+///
+/// let xyz = xyz_from_p3_u8([212, 33, 61]);
+/// assert_eq!([212, 33, 61], p3_u8_from_xyz(xyz));
+/// ```
+pub fn xyz_from_p3_u8(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
+    xyz_from_p3_linear(p3_linear_from_u8(rgb))
+}
+
+/// Converts a colour in XYZ colour space into 24-bit Display P3
+/// representation.
+///
+/// Colours outside of Display P3 gamut are clamped.
+///
+/// # Example
+/// ```
+/// use srgb::p3::p3_u8_from_xyz;
+///
+/// assert_eq!([234, 51, 35], p3_u8_from_xyz(srgb::xyz_from_u8([255, 0, 0])));
+/// ```
+pub fn p3_u8_from_xyz(xyz: impl Into<[f32; 3]>) -> [u8; 3] {
+    p3_u8_from_linear(p3_linear_from_xyz(xyz))
+}
+
+include!(concat!(env!("OUT_DIR"), "/p3_constants.rs"));
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reversible_conversion() {
+        for c in 0..(16 * 16 * 16) {
+            let src = [
+                (c & 15) as f32 / 15.0,
+                ((c >> 4) & 15) as f32 / 15.0,
+                ((c >> 8) & 15) as f32 / 15.0,
+            ];
+            let dst = p3_linear_from_xyz(xyz_from_p3_linear(src));
+            approx::assert_abs_diff_eq!(&src[..], &dst[..], epsilon = 0.00001);
+        }
+        for c in (0..(1 << 24)).step_by(9973) {
+            let rgb = crate::from_u32(c);
+            assert_eq!(rgb, p3_u8_from_xyz(xyz_from_p3_u8(rgb)));
+        }
+    }
+
+    #[test]
+    fn test_grey_chromaticity() {
+        let (wx, wy) = crate::xyz::white_point();
+        for v in 1..=255 {
+            let [x, y, z] = xyz_from_p3_u8([v, v, v]);
+            let sum = x + y + z;
+            approx::assert_abs_diff_eq!(wx, x / sum, epsilon = 0.00001);
+            approx::assert_abs_diff_eq!(wy, y / sum, epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_matches_rgb_space() {
+        let space = crate::space::RgbSpace::DISPLAY_P3;
+        let want = space.xyz_from_rgb_matrix().unwrap();
+        for (want, got) in want.iter().zip(XYZ_FROM_P3_MATRIX.iter()) {
+            approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = 1e-6);
+        }
+        let want = space.rgb_from_xyz_matrix().unwrap();
+        for (want, got) in want.iter().zip(P3_FROM_XYZ_MATRIX.iter()) {
+            approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = 1e-6);
+        }
+    }
+}
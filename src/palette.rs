@@ -0,0 +1,181 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for interoperability with the [`palette`] crate.
+//!
+//! Colour types of the `palette` crate implement conversions from and into
+//! arrays so they can be passed directly to functions in this crate which
+//! take `impl Into<[u8; 3]>` or `impl Into<[f32; 3]>` arguments while results
+//! can be converted into `palette` types with `into()`:
+//!
+//! ```
+//! let rgb = palette::Srgb::new(212u8, 33, 61);
+//! let xyz: palette::Xyz = srgb::xyz_from_u8(rgb).into();
+//! approx::assert_abs_diff_eq!(0.2853, xyz.x, epsilon = 0.0001);
+//! ```
+//!
+//! Functions in this module wrap conversions provided by this crate such that
+//! they operate on `palette` types directly.
+//!
+//! Note that results may slightly differ from conversions performed by the
+//! `palette` crate.  This crate derives the conversion matrices (and the D65
+//! white point) from the xy chromaticity coordinates given in the sRGB
+//! specification while `palette` uses D65 white point’s XYZ coordinates
+//! rounded to five decimal places.  Differences in XYZ coordinates are in the
+//! order of 10⁻⁴.  Similarly, gamma functions and Oklab conversion may differ
+//! in the last bits of precision.
+//!
+//! This module is available with the `palette` feature.
+
+use palette::{LinSrgb, Oklab, Srgb, Xyz};
+
+/// Converts a 24-bit sRGB colour into linear sRGB space.
+///
+/// This wraps [`crate::gamma::linear_from_u8()`].
+///
+/// # Example
+/// ```
+/// let red = palette::Srgb::new(255, 0, 0);
+/// let linear = srgb::palette::lin_srgb_from_srgb(red);
+/// assert_eq!(palette::LinSrgb::new(1.0, 0.0, 0.0), linear);
+/// ```
+pub fn lin_srgb_from_srgb(rgb: Srgb<u8>) -> LinSrgb {
+    crate::gamma::linear_from_u8(rgb).into()
+}
+
+/// Converts a colour in linear sRGB space into a 24-bit sRGB colour.
+///
+/// This wraps [`crate::gamma::u8_from_linear()`].
+///
+/// # Example
+/// ```
+/// let linear = palette::LinSrgb::new(1.0, 0.5, 0.0);
+/// let rgb = srgb::palette::srgb_from_lin_srgb(linear);
+/// assert_eq!(palette::Srgb::new(255, 188, 0), rgb);
+/// ```
+pub fn srgb_from_lin_srgb(linear: LinSrgb) -> Srgb<u8> {
+    crate::gamma::u8_from_linear(linear).into()
+}
+
+/// Converts a 24-bit sRGB colour into XYZ colour space.
+///
+/// This wraps [`crate::xyz_from_u8()`].
+///
+/// # Example
+/// ```
+/// let xyz = srgb::palette::xyz_from_srgb(palette::Srgb::new(255, 255, 255));
+/// assert_eq!(palette::Xyz::from(srgb::xyz::D65_XYZ), xyz);
+/// ```
+pub fn xyz_from_srgb(rgb: Srgb<u8>) -> Xyz { crate::xyz_from_u8(rgb).into() }
+
+/// Converts a colour in XYZ colour space into a 24-bit sRGB colour.
+///
+/// This wraps [`crate::u8_from_xyz()`].
+///
+/// # Example
+/// ```
+/// let xyz = palette::Xyz::from(srgb::xyz::D65_XYZ);
+/// let rgb = srgb::palette::srgb_from_xyz(xyz);
+/// assert_eq!(palette::Srgb::new(255, 255, 255), rgb);
+/// ```
+pub fn srgb_from_xyz(xyz: Xyz) -> Srgb<u8> { crate::u8_from_xyz(xyz).into() }
+
+/// Converts a colour in linear sRGB space into Oklab colour space.
+///
+/// This wraps [`crate::oklab::oklab_from_linear()`].
+///
+/// # Example
+/// ```
+/// let white = palette::LinSrgb::new(1.0, 1.0, 1.0);
+/// let oklab = srgb::palette::oklab_from_lin_srgb(white);
+/// approx::assert_abs_diff_eq!(1.0, oklab.l, epsilon = 0.0001);
+/// ```
+pub fn oklab_from_lin_srgb(linear: LinSrgb) -> Oklab {
+    crate::oklab::oklab_from_linear(linear).into()
+}
+
+/// Converts a colour in Oklab colour space into linear sRGB space.
+///
+/// This wraps [`crate::oklab::linear_from_oklab()`].
+///
+/// # Example
+/// ```
+/// let oklab = palette::Oklab::new(1.0, 0.0, 0.0);
+/// let linear = srgb::palette::lin_srgb_from_oklab(oklab);
+/// approx::assert_abs_diff_eq!(1.0, linear.green, epsilon = 0.0001);
+/// ```
+pub fn lin_srgb_from_oklab(oklab: Oklab) -> LinSrgb {
+    crate::oklab::linear_from_oklab(oklab).into()
+}
+
+
+#[cfg(test)]
+mod test {
+    use palette::{FromColor, IntoColor};
+
+    use super::*;
+
+    fn assert_close(
+        want: impl Into<[f32; 3]>,
+        got: impl Into<[f32; 3]>,
+        epsilon: f32,
+    ) {
+        let (want, got) = (want.into(), got.into());
+        approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = epsilon);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for c in (0..(1 << 24)).step_by(9973) {
+            let rgb = crate::from_u32(c);
+            let pal = Srgb::from(rgb);
+            assert_eq!(rgb, <[u8; 3]>::from(pal));
+
+            let linear = lin_srgb_from_srgb(pal);
+            assert_eq!(
+                crate::gamma::linear_from_u8(rgb),
+                <[f32; 3]>::from(linear)
+            );
+            assert_eq!(pal, srgb_from_lin_srgb(linear));
+
+            let xyz = xyz_from_srgb(pal);
+            assert_eq!(crate::xyz_from_u8(rgb), <[f32; 3]>::from(xyz));
+            assert_eq!(pal, srgb_from_xyz(xyz));
+
+            let oklab = oklab_from_lin_srgb(linear);
+            let got = lin_srgb_from_oklab(oklab);
+            assert_close(linear, got, 0.0001);
+        }
+    }
+
+    #[test]
+    fn test_matches_palette() {
+        for c in (0..(1 << 24)).step_by(9973) {
+            let pal = Srgb::from(crate::from_u32(c));
+
+            let want: LinSrgb = pal.into_linear();
+            let got = lin_srgb_from_srgb(pal);
+            assert_close(want, got, 0.00001);
+            assert_eq!(pal, Srgb::from_linear(got));
+
+            let want: Xyz = want.into_color();
+            assert_close(want, xyz_from_srgb(pal), 0.001);
+
+            let want = Oklab::from_color(got);
+            let got = oklab_from_lin_srgb(got);
+            assert_close(want, got, 0.001);
+        }
+    }
+}
@@ -0,0 +1,98 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions converting between relative and absolute luminance.
+//!
+//! Throughout the crate luminance is relative: Y coordinate of the reference
+//! white (i.e. sRGB colour `[255, 255, 255]`) equals one and luminance of
+//! other colours is expressed as a fraction of it.  This is how colorimetry
+//! of displays is usually described since actual brightness depends on the
+//! display and its settings.
+//!
+//! Lighting and rendering tools often work with absolute luminance measured
+//! in candelas per square metre (also called nits).  Functions in this module
+//! convert between the two given luminance of the reference white, i.e. the
+//! peak luminance of an SDR display.  The sRGB specification assumes
+//! reference display with white luminance of [`SRGB_REFERENCE_WHITE`] but
+//! typical displays are considerably brighter.
+//!
+//! # Example
+//! ```
+//! use srgb::photometric::{from_nits, to_nits};
+//!
+//! // Luminance of mid-grey on a display with 200 cd/m² peak.
+//! let [_, y, _] = srgb::xyz_from_u8([128, 128, 128]);
+//! let nits = to_nits(y, 200.0);
+//! approx::assert_abs_diff_eq!(43.17, nits, epsilon = 0.01);
+//! approx::assert_abs_diff_eq!(y, from_nits(nits, 200.0));
+//! ```
+
+
+/// Luminance of the white of the reference display (in cd/m²) as defined by
+/// IEC 61966-2-1.
+pub const SRGB_REFERENCE_WHITE: f32 = 80.0;
+
+
+/// Converts relative luminance into absolute luminance in cd/m².
+///
+/// `linear_y` is relative luminance (i.e. Y coordinate as returned by
+/// [`crate::xyz_from_u8()`]) where one corresponds to the reference white.
+/// `peak_nits` is luminance of the reference white in cd/m².  Values outside
+/// of the 0–1 range are scaled as well which may be useful for HDR content.
+///
+/// # Example
+/// ```
+/// use srgb::photometric::{to_nits, SRGB_REFERENCE_WHITE};
+///
+/// assert_eq!(80.0, to_nits(1.0, SRGB_REFERENCE_WHITE));
+/// assert_eq!(150.0, to_nits(0.5, 300.0));
+/// ```
+#[inline]
+pub fn to_nits(linear_y: f32, peak_nits: f32) -> f32 { linear_y * peak_nits }
+
+/// Converts absolute luminance in cd/m² into relative luminance.
+///
+/// This is an inverse of [`to_nits()`].  `peak_nits` is luminance of the
+/// reference white in cd/m² and the result is luminance relative to it.  If
+/// `peak_nits` is zero, the result is infinite or NaN.
+///
+/// # Example
+/// ```
+/// use srgb::photometric::{from_nits, SRGB_REFERENCE_WHITE};
+///
+/// assert_eq!(1.0, from_nits(80.0, SRGB_REFERENCE_WHITE));
+/// assert_eq!(0.5, from_nits(150.0, 300.0));
+/// ```
+#[inline]
+pub fn from_nits(nits: f32, peak_nits: f32) -> f32 { nits / peak_nits }
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for peak in [SRGB_REFERENCE_WHITE, 100.0, 203.0, 1000.0] {
+            assert_eq!(peak, to_nits(1.0, peak));
+            assert_eq!(0.0, to_nits(0.0, peak));
+            for i in 0..=1000 {
+                let y = i as f32 / 1000.0;
+                let got = from_nits(to_nits(y, peak), peak);
+                approx::assert_ulps_eq!(y, got, max_ulps = 1);
+            }
+        }
+    }
+}
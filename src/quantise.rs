@@ -0,0 +1,267 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for reducing number of colours in an image.
+//!
+//! [`median_cut`] builds a palette of at most given number of colours which
+//! represents colours of an image well and [`remap`] converts the image into
+//! indexed representation using that (or any other) palette.  This is useful
+//! for example when exporting images to formats such as GIF.
+//!
+//! Colours are partitioned and matched in Oklab colour space (see
+//! [`crate::oklab`]) so that the palette is distributed evenly in terms of
+//! perceived differences.  Colours of the palette are averaged in linear
+//! space.
+
+/// Builds a palette of at most `n` colours representing given pixels using
+/// the median cut algorithm.
+///
+/// The algorithm starts with a box containing all colours of the image and
+/// repeatedly splits the box which is largest along any axis of the Oklab
+/// colour space at the median (weighted by number of pixels of each colour)
+/// until there are `n` boxes.  Each box then contributes to the palette
+/// average of its colours calculated in linear space.
+///
+/// If the image has at most `n` unique colours, those colours are returned
+/// (sorted) and thus the quantisation is lossless.  Otherwise the returned
+/// palette has `n` colours.
+///
+/// # Example
+/// ```
+/// use srgb::quantise::{median_cut, remap};
+///
+/// let pixels = [[212, 33, 61], [0, 0, 0], [212, 33, 61], [0, 0, 0]];
+/// let palette = median_cut(&pixels, 2);
+/// assert_eq!(vec![[0, 0, 0], [212, 33, 61]], palette);
+/// assert_eq!(vec![1, 0, 1, 0], remap(&pixels, &palette));
+///
+/// let palette = median_cut(&pixels, 1);
+/// assert_eq!(vec![[155, 21, 42]], palette);
+/// ```
+pub fn median_cut(pixels: &[[u8; 3]], n: usize) -> Vec<[u8; 3]> {
+    let mut sorted = pixels.to_vec();
+    sorted.sort_unstable();
+    let mut entries = Vec::<Entry>::new();
+    for rgb in sorted {
+        match entries.last_mut() {
+            Some(entry) if entry.rgb == rgb => entry.count += 1,
+            _ => entries.push(Entry::new(rgb)),
+        }
+    }
+    if entries.len() <= n {
+        return entries.iter().map(|entry| entry.rgb).collect();
+    }
+
+    let mut boxes: Vec<&mut [Entry]> = Vec::with_capacity(n);
+    if n > 0 {
+        boxes.push(&mut entries[..]);
+    }
+    while boxes.len() < n {
+        let best = boxes
+            .iter()
+            .enumerate()
+            .filter(|(_, entries)| entries.len() > 1)
+            .map(|(idx, entries)| (longest_axis(entries), idx))
+            .max_by(|a, b| (a.0).1.total_cmp(&(b.0).1));
+        let ((axis, _), idx) = match best {
+            Some(best) => best,
+            None => break,
+        };
+        let entries = boxes.swap_remove(idx);
+        entries
+            .sort_unstable_by(|a, b| a.oklab[axis].total_cmp(&b.oklab[axis]));
+        let total = entries.iter().map(|entry| entry.count).sum::<u64>();
+        let mut acc = 0;
+        let mid = entries
+            .iter()
+            .position(|entry| {
+                acc += entry.count;
+                2 * acc >= total
+            })
+            .map_or(1, |pos| pos + 1)
+            .clamp(1, entries.len() - 1);
+        let (lo, hi) = entries.split_at_mut(mid);
+        boxes.push(lo);
+        boxes.push(hi);
+    }
+
+    boxes.iter().map(|entries| average(entries)).collect()
+}
+
+/// Converts pixels into indices of colours in the palette closest to them.
+///
+/// For each pixel returns index of the palette colour with the smallest
+/// Euclidean distance in Oklab colour space.  If several palette colours are
+/// equally close, the first one is chosen.  The palette doesn’t need to be
+/// produced by [`median_cut`].
+///
+/// # Panics
+///
+/// Panics if `palette` is empty or has more than 256 colours.
+///
+/// # Example
+/// ```
+/// use srgb::quantise::remap;
+///
+/// let palette = [[0, 0, 0], [255, 255, 255], [255, 0, 0]];
+/// let pixels = [[10, 10, 10], [200, 30, 30], [230, 230, 230]];
+/// assert_eq!(vec![0, 2, 1], remap(&pixels, &palette));
+/// ```
+pub fn remap(pixels: &[[u8; 3]], palette: &[[u8; 3]]) -> Vec<u8> {
+    assert!(!palette.is_empty() && palette.len() <= 256);
+    let palette = palette
+        .iter()
+        .map(|rgb| crate::oklab::oklab_from_u8(*rgb))
+        .collect::<Vec<_>>();
+    pixels
+        .iter()
+        .map(|rgb| {
+            let lab = crate::oklab::oklab_from_u8(*rgb);
+            let distance = |other: &[f32; 3]| {
+                (0..3).map(|i| (lab[i] - other[i]).powi(2)).sum::<f32>()
+            };
+            palette
+                .iter()
+                .enumerate()
+                .map(|(idx, other)| (distance(other), idx))
+                .fold((f32::INFINITY, 0), |a, b| if b.0 < a.0 { b } else { a })
+                .1 as u8
+        })
+        .collect()
+}
+
+
+/// Unique colour of an image together with number of its occurrences.
+struct Entry {
+    rgb: [u8; 3],
+    oklab: [f32; 3],
+    count: u64,
+}
+
+impl Entry {
+    fn new(rgb: [u8; 3]) -> Self {
+        Self { rgb, oklab: crate::oklab::oklab_from_u8(rgb), count: 1 }
+    }
+}
+
+/// Returns axis along which the colours span the largest range and length of
+/// that range.
+fn longest_axis(entries: &[Entry]) -> (usize, f32) {
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [f32::NEG_INFINITY; 3];
+    for entry in entries {
+        for axis in 0..3 {
+            min[axis] = min[axis].min(entry.oklab[axis]);
+            max[axis] = max[axis].max(entry.oklab[axis]);
+        }
+    }
+    (0..3)
+        .map(|axis| (axis, max[axis] - min[axis]))
+        .fold((0, f32::NEG_INFINITY), |a, b| if b.1 > a.1 { b } else { a })
+}
+
+/// Returns average of the colours weighted by number of their occurrences
+/// calculated in linear space.
+fn average(entries: &[Entry]) -> [u8; 3] {
+    let mut sum = [0.0f64; 3];
+    let mut total = 0.0f64;
+    for entry in entries {
+        let linear = crate::gamma::linear_from_u8(entry.rgb);
+        for (sum, value) in sum.iter_mut().zip(linear.iter()) {
+            *sum += *value as f64 * entry.count as f64;
+        }
+        total += entry.count as f64;
+    }
+    crate::gamma::u8_from_linear(sum.map(|sum| (sum / total) as f32))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_two_colours() {
+        let (red, blue) = ([212, 33, 61], [0, 128, 255]);
+        let mut pixels = vec![red; 30];
+        pixels.extend_from_slice(&[blue; 70]);
+        let palette = median_cut(&pixels, 2);
+        assert_eq!(vec![blue, red], palette);
+        let want =
+            pixels.iter().map(|rgb| u8::from(*rgb == red)).collect::<Vec<_>>();
+        assert_eq!(want, remap(&pixels, &palette));
+
+        // Two clusters of similar colours are reduced to one colour each.
+        let pixels = (0..100u8)
+            .map(|i| {
+                let [r, g, b] = if i % 3 == 0 { red } else { blue };
+                [r, g.wrapping_add(i % 5), b - i % 7]
+            })
+            .collect::<Vec<_>>();
+        let palette = median_cut(&pixels, 2);
+        assert_eq!(2, palette.len());
+        let indices = remap(&pixels, &palette);
+        for (i, idx) in indices.iter().enumerate() {
+            assert_eq!(indices[i % 3], *idx, "{}", i);
+        }
+        assert_ne!(indices[0], indices[1]);
+    }
+
+    #[test]
+    fn test_lossless() {
+        let pixels = (0..5000u32)
+            .map(|i| crate::from_u32((i % 97).wrapping_mul(0x9e3779b9)))
+            .collect::<Vec<_>>();
+        for n in [97, 100, 256] {
+            let palette = median_cut(&pixels, n);
+            assert_eq!(97, palette.len());
+            let indices = remap(&pixels, &palette);
+            for (rgb, idx) in pixels.iter().zip(indices.iter()) {
+                assert_eq!(*rgb, palette[usize::from(*idx)]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_reduction() {
+        assert_eq!(Vec::<[u8; 3]>::new(), median_cut(&[], 4));
+        assert_eq!(Vec::<[u8; 3]>::new(), median_cut(&[[1, 2, 3]], 0));
+
+        // Grey gradient reduced to 16 colours doesn’t produce large errors.
+        let pixels = (0..=255).map(|v| [v, v, v]).collect::<Vec<_>>();
+        let palette = median_cut(&pixels, 16);
+        assert_eq!(16, palette.len());
+        let indices = remap(&pixels, &palette);
+        for (rgb, idx) in pixels.iter().zip(indices.iter()) {
+            let got = palette[usize::from(*idx)];
+            let delta_e = crate::delta::delta_e_2000(
+                crate::lab::lab_from_u8(*rgb),
+                crate::lab::lab_from_u8(got),
+            );
+            assert!(delta_e < 4.0, "{:?} → {:?}; ΔE: {}", rgb, got, delta_e);
+        }
+
+        // Every palette colour is used.
+        let mut used = [false; 16];
+        for idx in indices {
+            used[usize::from(idx)] = true;
+        }
+        assert_eq!([true; 16], used);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_remap_empty_palette() { remap(&[[0; 3]], &[]); }
+}
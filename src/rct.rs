@@ -0,0 +1,105 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions implementing the Reversible Colour Transform (RCT) of JPEG 2000.
+//!
+//! RCT is an integer approximation of a YUV transform used by lossless image
+//! and video codecs.  Unlike Rec.709 YCbCr it’s exactly reversible for all
+//! 24-bit colours.  See also [`crate::ycocg`] module for another reversible
+//! transform.  As is conventional for compression, the transform operates on
+//! gamma-encoded 8-bit sRGB values rather than linear ones.
+
+/// Converts a 24-bit sRGB colour into RCT representation.
+///
+/// The result is `[Y, Cb, Cr]` triple where luma is in the range 0–255 while
+/// chroma components are in the range -255–255.  [`inverse()`] recovers the
+/// original colour exactly.
+///
+/// # Example
+/// ```
+/// use srgb::rct::forward;
+///
+/// assert_eq!([255, 0, 0], forward([255, 255, 255]));
+/// assert_eq!([84, 28, 179], forward([212, 33, 61]));
+/// ```
+pub fn forward(rgb: impl Into<[u8; 3]>) -> [i16; 3] {
+    let [r, g, b] = rgb.into();
+    let [r, g, b] = [r as i16, g as i16, b as i16];
+    [(r + 2 * g + b) >> 2, b - g, r - g]
+}
+
+/// Converts a colour in RCT representation into a 24-bit sRGB colour.
+///
+/// This is an exact inverse of [`forward()`].  Triples which weren’t produced
+/// by that function may decode into components outside of the 0–255 range.
+/// Such components are clamped.
+///
+/// # Example
+/// ```
+/// use srgb::rct::inverse;
+///
+/// assert_eq!([255, 255, 255], inverse([255, 0, 0]));
+/// assert_eq!([212, 33, 61], inverse([84, 28, 179]));
+/// ```
+pub fn inverse(yuv: [i16; 3]) -> [u8; 3] {
+    let [y, cb, cr] = yuv.map(i32::from);
+    let g = y - ((cb + cr) >> 2);
+    [cr + g, g, cb + g].map(|v| v.clamp(0, 255) as u8)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check(rgb: [u8; 3]) {
+        let yuv = forward(rgb);
+        let [y, cb, cr] = yuv;
+        assert!((0..=255).contains(&y), "{:?} → {:?}", rgb, yuv);
+        assert!((-255..=255).contains(&cb), "{:?} → {:?}", rgb, yuv);
+        assert!((-255..=255).contains(&cr), "{:?} → {:?}", rgb, yuv);
+        assert_eq!(rgb, inverse(yuv), "{:?}", yuv);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_round_trip() {
+        for c in (0..(1 << 24)).step_by(997) {
+            check(crate::from_u32(c));
+        }
+        for v in 0..=255 {
+            check([v, v, v]);
+            check([v, 0, 255 - v]);
+            check([255, v, 0]);
+            check([0, 255, v]);
+        }
+    }
+
+    #[test]
+    #[ignore = "Checks all 2²⁴ colours; slow in debug builds"]
+    fn test_round_trip_exhaustive() {
+        for c in 0..(1 << 24) {
+            check(crate::from_u32(c));
+        }
+    }
+
+    #[test]
+    fn test_clamping() {
+        assert_eq!([255, 255, 255], inverse([300, 0, 0]));
+        assert_eq!([0, 0, 0], inverse([-300, 0, 0]));
+        assert_eq!([0, 0, 0], inverse([i16::MIN; 3]));
+        assert_eq!([255, 0, 0], inverse([0, -400, 400]));
+    }
+}
@@ -0,0 +1,168 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions and constants handling ITU-R BT.2020 (Rec.2020) colour space.
+//!
+//! Rec.2020 uses D65 white point (the same as sRGB) and much wider primaries.
+//! The conversion matrices are calculated at build time with the same exact
+//! rational arithmetic as sRGB matrices (see [`crate::xyz`] module).  The
+//! module also provides Rec.2020 transfer function, see [`oetf()`] and
+//! [`eotf()`].
+
+/// Converts a colour in linear Rec.2020 space into XYZ colour space.
+///
+/// The colour is given as three components each in the range from zero to one.
+/// Resulting XYZ space is one where white colour has Y coordinate equal one.
+///
+/// # Example
+/// ```
+/// use srgb::rec2020::xyz_from_rec2020_linear;
+///
+/// let xyz = xyz_from_rec2020_linear([1.0, 1.0, 1.0]);
+/// approx::assert_abs_diff_eq!(
+///     &srgb::xyz::D65_XYZ[..],
+///     &xyz[..],
+///     epsilon = 0.00001
+/// );
+/// ```
+pub fn xyz_from_rec2020_linear(linear: impl Into<[f32; 3]>) -> [f32; 3] {
+    crate::maths::matrix_product(&XYZ_FROM_REC2020_MATRIX, linear.into())
+}
+
+/// Converts a colour in XYZ colour space into linear Rec.2020 space.
+///
+/// This is an inverse of [`xyz_from_rec2020_linear()`].  Colours outside of
+/// Rec.2020 gamut result in components outside of the range from zero to one.
+///
+/// # Example
+/// ```
+/// use srgb::rec2020::rec2020_linear_from_xyz;
+///
+/// let [r, g, b] = rec2020_linear_from_xyz(srgb::xyz_from_u8([255, 0, 0]));
+/// approx::assert_abs_diff_eq!(0.6274, r, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.0691, g, epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.0164, b, epsilon = 0.0001);
+/// ```
+pub fn rec2020_linear_from_xyz(xyz: impl Into<[f32; 3]>) -> [f32; 3] {
+    crate::maths::matrix_product(&REC2020_FROM_XYZ_MATRIX, xyz.into())
+}
+
+/// The α constant of Rec.2020 transfer function.
+///
+/// Rec.2020 gives rounded values of α and β for 10-bit systems and more
+/// precise ones for 12-bit systems.  This module always uses the precise
+/// values.
+const ALPHA: f32 = 1.09929682680944;
+
+/// The β constant of Rec.2020 transfer function.  See [`ALPHA`].
+const BETA: f32 = 0.018053968510807;
+
+/// Performs Rec.2020 gamma compression (i.e. applies the OETF) on a linear
+/// component value.
+///
+/// The curve has the same shape as Rec.709 transfer function but uses more
+/// precise constants.  Values below zero are mapped linearly and values above
+/// one aren’t clamped.
+///
+/// # Example
+/// ```
+/// use srgb::rec2020::oetf;
+///
+/// assert_eq!(0.0, oetf(0.0));
+/// approx::assert_abs_diff_eq!(0.009, oetf(0.002));
+/// approx::assert_abs_diff_eq!(1.0, oetf(1.0), epsilon = 1e-6);
+/// approx::assert_abs_diff_eq!(0.4088, oetf(0.18), epsilon = 0.0001);
+/// ```
+pub fn oetf(linear: f32) -> f32 {
+    if linear < BETA {
+        4.5 * linear
+    } else {
+        crate::maths::mul_add(ALPHA, linear.powf(0.45), 1.0 - ALPHA)
+    }
+}
+
+/// Performs Rec.2020 gamma expansion on an encoded component value.
+///
+/// This is an inverse of [`oetf()`].  Note that Rec.2020 doesn’t define
+/// a reference display EOTF (BT.1886 is used for that) and, as is common,
+/// this function is just the inverse of the OETF.
+///
+/// # Example
+/// ```
+/// use srgb::rec2020::eotf;
+///
+/// assert_eq!(0.0, eotf(0.0));
+/// approx::assert_abs_diff_eq!(0.002, eotf(0.009));
+/// approx::assert_abs_diff_eq!(1.0, eotf(1.0), epsilon = 1e-6);
+/// approx::assert_abs_diff_eq!(0.18, eotf(0.4088), epsilon = 0.0001);
+/// ```
+pub fn eotf(encoded: f32) -> f32 {
+    if encoded < 4.5 * BETA {
+        encoded / 4.5
+    } else {
+        ((encoded + (ALPHA - 1.0)) / ALPHA).powf(1.0 / 0.45)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/rec2020_constants.rs"));
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_reversible_conversion() {
+        for c in 0..(16 * 16 * 16) {
+            let src = [
+                (c & 15) as f32 / 15.0,
+                ((c >> 4) & 15) as f32 / 15.0,
+                ((c >> 8) & 15) as f32 / 15.0,
+            ];
+            let dst = rec2020_linear_from_xyz(xyz_from_rec2020_linear(src));
+            approx::assert_abs_diff_eq!(&src[..], &dst[..], epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn test_matches_rgb_space() {
+        let space = crate::space::RgbSpace::REC2020;
+        let want = space.xyz_from_rgb_matrix().unwrap();
+        for (want, got) in want.iter().zip(XYZ_FROM_REC2020_MATRIX.iter()) {
+            approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = 1e-6);
+        }
+        let want = space.rgb_from_xyz_matrix().unwrap();
+        for (want, got) in want.iter().zip(REC2020_FROM_XYZ_MATRIX.iter()) {
+            approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_transfer() {
+        // Both parts of the curve meet at β.
+        let below = 4.5 * BETA;
+        let above = ALPHA * BETA.powf(0.45) - (ALPHA - 1.0);
+        approx::assert_abs_diff_eq!(below, above, epsilon = 1e-6);
+
+        let mut prev = -1.0;
+        for i in 0..=1000 {
+            let v = i as f32 / 1000.0;
+            let e = oetf(v);
+            assert!(prev < e, "{}: {} ≥ {}", v, prev, e);
+            prev = e;
+            approx::assert_abs_diff_eq!(v, eotf(e), epsilon = 1e-6);
+        }
+    }
+}
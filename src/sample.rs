@@ -0,0 +1,238 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for gamma-correct texture sampling.
+//!
+//! GPUs sampling sRGB textures convert texels into linear space before
+//! filtering them.  Functions in this module do the same on the CPU which is
+//! useful for reference rendering.
+//!
+//! Textures are given as slices of 32-bit sRGB colours with straight (i.e.
+//! not premultiplied) alpha in row-major order.
+
+/// Handling of texture coordinates which fall outside of the texture.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// Coordinates are clamped to the edge of the texture such that texels
+    /// at the border are repeated.
+    Clamp,
+    /// Coordinates wrap around such that the texture is tiled.
+    Wrap,
+}
+
+impl Edge {
+    /// Maps (possibly out of range) texel position onto index within a row
+    /// or column of given length.
+    fn index(self, pos: isize, len: usize) -> usize {
+        let len = len as isize;
+        (match self {
+            Self::Clamp => pos.clamp(0, len - 1),
+            Self::Wrap => pos.rem_euclid(len),
+        }) as usize
+    }
+}
+
+/// Samples texture at given position using bilinear filtering.
+///
+/// `u` and `v` are normalised texture coordinates with (0, 0) being the
+/// top-left corner of the texture and (1, 1) the bottom-right corner.  As on
+/// GPUs, centres of texels are at half-texel offsets, i.e. centre of texel
+/// at column `x` and row `y` is at `((x + 0.5) / width, (y + 0.5) /
+/// height)`.  Sampling at centre of a texel returns that texel.
+///
+/// Four texels nearest to the position are converted into linear space with
+/// premultiplied alpha, interpolated and the result converted back into
+/// sRGB with straight alpha.  Premultiplying ensures that colour of fully
+/// transparent texels doesn’t bleed into the result.  If the result is fully
+/// transparent, returns transparent black.
+///
+/// NaN coordinates, as well as infinite coordinates when wrapping, are
+/// treated as pointing at the centre of the first texel.
+///
+/// # Panics
+///
+/// Panics if `width` or `height` is zero or if length of `texels` is not
+/// `width * height`.
+///
+/// # Example
+/// ```
+/// use srgb::sample::{bilinear_srgb, Edge};
+///
+/// let texels = [[0, 0, 0, 255], [255, 255, 255, 255]];
+/// let got = bilinear_srgb(&texels, 2, 1, 0.25, 0.5, Edge::Clamp);
+/// assert_eq!([0, 0, 0, 255], got);
+/// let got = bilinear_srgb(&texels, 2, 1, 0.5, 0.5, Edge::Clamp);
+/// assert_eq!([188, 188, 188, 255], got);
+/// let got = bilinear_srgb(&texels, 2, 1, 0.0, 0.5, Edge::Wrap);
+/// assert_eq!([188, 188, 188, 255], got);
+/// ```
+pub fn bilinear_srgb(
+    texels: &[[u8; 4]],
+    width: usize,
+    height: usize,
+    u: f32,
+    v: f32,
+    edge: Edge,
+) -> [u8; 4] {
+    assert!(width > 0 && height > 0);
+    assert_eq!(width * height, texels.len());
+
+    let split = |coord: f32, len: usize| {
+        // Bring the position into a small range before converting it to
+        // isize so that the conversion doesn’t saturate and calculating
+        // index of the neighbouring texel doesn’t overflow.  When wrapping,
+        // the reduction is done in floating point so that large coordinates
+        // still pick the right texel.  When clamping, all positions outside
+        // of [-1, len] sample the border texels only.
+        let pos = coord * len as f32 - 0.5;
+        let pos = match edge {
+            Edge::Clamp => pos.clamp(-1.0, len as f32),
+            Edge::Wrap => pos.rem_euclid(len as f32),
+        };
+        let pos = if pos.is_nan() { 0.0 } else { pos };
+        let floor = pos.floor();
+        (floor as isize, pos - floor)
+    };
+    let (x, fx) = split(u, width);
+    let (y, fy) = split(v, height);
+
+    let mut sum = [0.0f32; 4];
+    for (dy, wy) in [(0, 1.0 - fy), (1, fy)] {
+        let row = edge.index(y + dy, height) * width;
+        for (dx, wx) in [(0, 1.0 - fx), (1, fx)] {
+            let [r, g, b, a] = texels[row + edge.index(x + dx, width)];
+            let weight = wx * wy;
+            let alpha = a as f32 / 255.0;
+            let linear = crate::gamma::linear_from_u8([r, g, b]);
+            for (sum, value) in sum.iter_mut().zip(linear.iter()) {
+                *sum = crate::maths::mul_add(weight * alpha, *value, *sum);
+            }
+            sum[3] = crate::maths::mul_add(weight, alpha, sum[3]);
+        }
+    }
+
+    let alpha = sum[3];
+    if alpha <= 0.0 {
+        return [0; 4];
+    }
+    let [r, g, b] = crate::gamma::u8_from_linear([
+        sum[0] / alpha,
+        sum[1] / alpha,
+        sum[2] / alpha,
+    ]);
+    [r, g, b, (alpha.min(1.0) * 255.0).round() as u8]
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_texel_centres() {
+        let texels = (0..12u32)
+            .map(|i| {
+                let [r, g, b] = crate::from_u32(i.wrapping_mul(0x9e3779b9));
+                [r, g, b, 255 - i as u8 * 20]
+            })
+            .collect::<Vec<_>>();
+        for edge in [Edge::Clamp, Edge::Wrap] {
+            for y in 0..3 {
+                for x in 0..4 {
+                    let u = (x as f32 + 0.5) / 4.0;
+                    let v = (y as f32 + 0.5) / 3.0;
+                    let got = bilinear_srgb(&texels, 4, 3, u, v, edge);
+                    assert_eq!(texels[y * 4 + x], got, "({}, {})", x, y);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_linear_midpoint() {
+        let texels = [[0, 0, 0, 255], [255, 255, 255, 255]];
+        let got = bilinear_srgb(&texels, 2, 1, 0.5, 0.5, Edge::Clamp);
+        // Midpoint in linear space rather than 128 which is sRGB midpoint.
+        assert_eq!([188, 188, 188, 255], got);
+
+        let texels = [[0, 0, 0, 255], [255, 255, 255, 255]];
+        let got = bilinear_srgb(&texels, 1, 2, 0.5, 0.5, Edge::Clamp);
+        assert_eq!([188, 188, 188, 255], got);
+    }
+
+    #[test]
+    fn test_edges() {
+        let texels = [[255, 0, 0, 255], [0, 0, 255, 255]];
+        let sample = |u, edge| bilinear_srgb(&texels, 2, 1, u, 0.5, edge);
+        // Clamping repeats the border texels.
+        assert_eq!([255, 0, 0, 255], sample(0.0, Edge::Clamp));
+        assert_eq!([255, 0, 0, 255], sample(-3.0, Edge::Clamp));
+        assert_eq!([0, 0, 255, 255], sample(1.0, Edge::Clamp));
+        assert_eq!([0, 0, 255, 255], sample(7.5, Edge::Clamp));
+        // Wrapping tiles the texture.
+        assert_eq!([188, 0, 188, 255], sample(0.0, Edge::Wrap));
+        assert_eq!([188, 0, 188, 255], sample(1.0, Edge::Wrap));
+        assert_eq!(sample(0.25, Edge::Wrap), sample(-2.75, Edge::Wrap));
+        assert_eq!(sample(0.75, Edge::Wrap), sample(5.75, Edge::Wrap));
+    }
+
+    #[test]
+    fn test_extreme_coordinates() {
+        let texels = [[255, 0, 0, 255], [0, 0, 255, 255]];
+        let sample = |u, edge| bilinear_srgb(&texels, 2, 1, u, 0.5, edge);
+        assert_eq!([0, 0, 255, 255], sample(1e30, Edge::Clamp));
+        assert_eq!([0, 0, 255, 255], sample(f32::INFINITY, Edge::Clamp));
+        assert_eq!([255, 0, 0, 255], sample(-1e30, Edge::Clamp));
+        assert_eq!([255, 0, 0, 255], sample(f32::NEG_INFINITY, Edge::Clamp));
+        assert_eq!(sample(0.25, Edge::Wrap), sample(f32::INFINITY, Edge::Wrap));
+        for edge in [Edge::Clamp, Edge::Wrap] {
+            for u in [1e30, -1e30, f32::INFINITY, f32::NEG_INFINITY] {
+                assert_eq!(255, sample(u, edge)[3], "{:?} {}", edge, u);
+            }
+            assert_eq!(sample(0.25, edge), sample(f32::NAN, edge));
+            let got = bilinear_srgb(&texels, 2, 1, f32::NAN, f32::NAN, edge);
+            assert_eq!(sample(0.25, edge), got);
+        }
+    }
+
+    #[test]
+    fn test_wrap_large_coordinates() {
+        let texels = [[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255]];
+        let sample = |u| bilinear_srgb(&texels, 3, 1, u, 0.5, Edge::Wrap);
+        // 2²⁵ ≡ 2 (mod 3) so the coordinate points at the last texel.
+        // 0.5 offset to the texel centre is lost in rounding.
+        let u = (1u32 << 25) as f32 / 3.0;
+        assert_eq!((1u32 << 25) as f32, u * 3.0 - 0.5);
+        assert_eq!(texels[2], sample(u));
+        // 2²⁶ ≡ 1 (mod 3).
+        let u = (1u32 << 26) as f32 / 3.0;
+        assert_eq!((1u32 << 26) as f32, u * 3.0 - 0.5);
+        assert_eq!(texels[1], sample(u));
+        // -2²⁵ ≡ 1 (mod 3).
+        assert_eq!(texels[1], sample(-u / 2.0));
+    }
+
+    #[test]
+    fn test_alpha() {
+        // Colour of fully transparent texel doesn’t bleed into the result.
+        let texels = [[255, 0, 0, 255], [0, 255, 0, 0]];
+        let got = bilinear_srgb(&texels, 2, 1, 0.5, 0.5, Edge::Clamp);
+        assert_eq!([255, 0, 0, 128], got);
+
+        let texels = [[255, 0, 0, 0], [0, 255, 0, 0]];
+        let got = bilinear_srgb(&texels, 2, 1, 0.5, 0.5, Edge::Clamp);
+        assert_eq!([0, 0, 0, 0], got);
+    }
+}
@@ -0,0 +1,160 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Helpers for serialising colours with the [`serde`] crate.
+//!
+//! Arrays used by this crate to represent colours already implement
+//! `Serialize` and `Deserialize` traits and are represented as sequences of
+//! three numbers.  Modules in here are meant to be used with `#[serde(with =
+//! "…")]` attribute to represent colours as strings instead which is more
+//! convenient in human-edited configuration files:
+//!
+//! ```
+//! #[derive(serde::Deserialize)]
+//! struct Palette {
+//!     #[serde(with = "srgb::serde::hex")]
+//!     foreground: [u8; 3],
+//!     #[serde(with = "srgb::serde::linear_hex")]
+//!     background: [f32; 3],
+//! }
+//!
+//! let json = r##"{"foreground": "#d4213d", "background": "hsl(0 0% 100%)"}"##;
+//! let palette: Palette = serde_json::from_str(json).unwrap();
+//! assert_eq!([212, 33, 61], palette.foreground);
+//! assert_eq!([1.0, 1.0, 1.0], palette.background);
+//! ```
+//!
+//! Colours are serialised in hexadecimal notation.  When deserialising, any
+//! syntax accepted by [`crate::css::parse()`] is allowed.  Opacity, if
+//! specified, is ignored.
+//!
+//! This module is available with the `serde` feature.
+
+use ::serde::{de, Deserializer, Serializer};
+
+/// Serialises and deserialises 24-bit sRGB colours as strings.
+///
+/// # Example
+/// ```
+/// #[derive(serde::Serialize)]
+/// struct Entry(#[serde(with = "srgb::serde::hex")] [u8; 3]);
+///
+/// let json = serde_json::to_string(&Entry([212, 33, 61])).unwrap();
+/// assert_eq!(r##""#d4213d""##, json);
+/// ```
+pub mod hex {
+    use super::*;
+
+    /// Serialises a 24-bit sRGB colour as a string in `#rrggbb` format.
+    pub fn serialize<S: Serializer>(
+        rgb: &[u8; 3],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let [r, g, b] = *rgb;
+        serializer.collect_str(&format_args!("#{:02x}{:02x}{:02x}", r, g, b))
+    }
+
+    /// Deserialises a 24-bit sRGB colour from a string in CSS syntax.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[u8; 3], D::Error> {
+        deserializer.deserialize_str(CssVisitor)
+    }
+}
+
+/// Serialises and deserialises colours in linear sRGB space as strings.
+///
+/// When serialising, colour is gamma compressed and represented as a 24-bit
+/// sRGB colour so the conversion is lossy.  When deserialising, the 24-bit
+/// sRGB colour is gamma expanded.
+///
+/// # Example
+/// ```
+/// #[derive(serde::Serialize)]
+/// struct Entry(#[serde(with = "srgb::serde::linear_hex")] [f32; 3]);
+///
+/// let json = serde_json::to_string(&Entry([1.0, 0.2, 0.0])).unwrap();
+/// assert_eq!(r##""#ff7c00""##, json);
+/// ```
+pub mod linear_hex {
+    use super::*;
+
+    /// Serialises a colour in linear sRGB space as a string in `#rrggbb`
+    /// format.
+    pub fn serialize<S: Serializer>(
+        linear: &[f32; 3],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        hex::serialize(&crate::gamma::u8_from_linear(*linear), serializer)
+    }
+
+    /// Deserialises a colour in linear sRGB space from a string in CSS
+    /// syntax.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<[f32; 3], D::Error> {
+        hex::deserialize(deserializer).map(crate::gamma::linear_from_u8)
+    }
+}
+
+
+/// Visitor parsing a colour in CSS syntax into a 24-bit sRGB colour.
+struct CssVisitor;
+
+impl<'de> de::Visitor<'de> for CssVisitor {
+    type Value = [u8; 3];
+
+    fn expecting(&self, fmtr: &mut core::fmt::Formatter) -> core::fmt::Result {
+        fmtr.write_str("a colour in CSS syntax")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<[u8; 3], E> {
+        crate::css::parse(value)
+            .map(|colour| colour.to_srgb_u8())
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Entry {
+        #[serde(with = "super::hex")]
+        rgb: [u8; 3],
+        #[serde(with = "super::linear_hex")]
+        linear: [f32; 3],
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for c in 0..(16 * 16 * 16) {
+            let rgb = crate::from_u32(c * 0x1001);
+            let linear = crate::gamma::linear_from_u8(rgb);
+            let entry = Entry { rgb, linear };
+            let json = serde_json::to_string(&entry).unwrap();
+            assert_eq!(entry, serde_json::from_str(&json).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let json = r##"{"rgb": "#12345", "linear": "#000"}"##;
+        let err = serde_json::from_str::<Entry>(json).unwrap_err();
+        assert!(err.to_string().contains("\"#12345\""), "{}", err);
+        let json = r##"{"rgb": [1, 2, 3], "linear": "#000"}"##;
+        serde_json::from_str::<Entry>(json).unwrap_err();
+    }
+}
@@ -0,0 +1,304 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Description of RGB colour spaces and conversions between them.
+//!
+//! Linear RGB colour space is defined by chromaticities of its primaries and
+//! its white point.  [`RgbSpace`] holds those and calculates matrices
+//! converting colours between the space and CIE XYZ.  [`conversion_matrix()`]
+//! combines the matrices of two spaces (with chromatic adaptation if their
+//! white points differ) into a single matrix so that colours can be converted
+//! between the spaces with a single matrix multiplication.
+//!
+//! Primaries of a user-defined space may be degenerate in which case the
+//! matrices cannot be calculated.  Because of that, functions calculating the
+//! matrices return a `Result` with [`SingularMatrix`] error in such cases.
+//!
+//! Note that the matrices operate on linear values.  Encoded values need to
+//! be decoded with the appropriate transfer function first (see
+//! [`crate::gamma`] and [`crate::transfer`] modules).
+
+use crate::adapt::CatMethod;
+use crate::maths::SingularMatrix;
+use crate::xyz::Primaries;
+
+
+/// Linear RGB colour space defined by its primaries and white point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RgbSpace {
+    /// Chromaticities of the red, green and blue primaries.
+    pub primaries: Primaries,
+    /// Chromaticity of the white point as `(x, y)` coordinates.
+    pub white: (f32, f32),
+}
+
+impl RgbSpace {
+    /// The sRGB colour space.
+    pub const SRGB: Self = Self {
+        primaries: crate::xyz::primaries(),
+        white: crate::xyz::white_point(),
+    };
+
+    /// The Display P3 colour space, i.e. DCI-P3 primaries with D65 white
+    /// point.
+    pub const DISPLAY_P3: Self = Self {
+        primaries: Primaries {
+            red: (0.680, 0.320),
+            green: (0.265, 0.690),
+            blue: (0.150, 0.060),
+        },
+        white: crate::xyz::white_point(),
+    };
+
+    /// The ITU-R BT.2020 colour space.
+    pub const REC2020: Self = Self {
+        primaries: Primaries {
+            red: (0.708, 0.292),
+            green: (0.170, 0.797),
+            blue: (0.131, 0.046),
+        },
+        white: crate::xyz::white_point(),
+    };
+
+    /// Returns matrix converting linear RGB values in this space into XYZ
+    /// coordinates.
+    ///
+    /// The matrix is normalised such that white (i.e. `[1, 1, 1]` RGB
+    /// value) has luminance of one.  Returns an error if the primaries are
+    /// collinear or any of the chromaticities is degenerate (e.g. has zero
+    /// `y` coordinate).
+    ///
+    /// # Example
+    /// ```
+    /// use srgb::space::RgbSpace;
+    ///
+    /// let matrix = RgbSpace::SRGB.xyz_from_rgb_matrix().unwrap();
+    /// let want = srgb::xyz::XYZ_FROM_SRGB_MATRIX;
+    /// for (want, got) in want.iter().zip(matrix.iter()) {
+    ///     approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = 1e-6);
+    /// }
+    /// ```
+    pub fn xyz_from_rgb_matrix(&self) -> Result<[[f32; 3]; 3], SingularMatrix> {
+        self.xyz_from_rgb_f64().map(to_f32)
+    }
+
+    /// Returns matrix converting XYZ coordinates into linear RGB values in
+    /// this space.
+    ///
+    /// This is an inverse of [`Self::xyz_from_rgb_matrix()`].
+    ///
+    /// # Example
+    /// ```
+    /// use srgb::space::RgbSpace;
+    ///
+    /// let matrix = RgbSpace::SRGB.rgb_from_xyz_matrix().unwrap();
+    /// let want = srgb::xyz::SRGB_FROM_XYZ_MATRIX;
+    /// for (want, got) in want.iter().zip(matrix.iter()) {
+    ///     approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = 1e-5);
+    /// }
+    /// ```
+    pub fn rgb_from_xyz_matrix(&self) -> Result<[[f32; 3]; 3], SingularMatrix> {
+        self.rgb_from_xyz_f64().map(to_f32)
+    }
+
+    /// Returns XYZ coordinates of the white point with luminance of one.
+    fn white_xyz(&self) -> [f64; 3] { xyz_from_xy(self.white) }
+
+    fn xyz_from_rgb_f64(&self) -> Result<[[f64; 3]; 3], SingularMatrix> {
+        let Primaries { red, green, blue } = self.primaries;
+        let [r, g, b] = [red, green, blue].map(xyz_from_xy);
+        let columns =
+            [[r[0], g[0], b[0]], [r[1], g[1], b[1]], [r[2], g[2], b[2]]];
+        let inv = crate::maths::checked_inverse_f64(&columns)?;
+        let white = self.white_xyz();
+        let scale = inv.map(|row| {
+            row.iter().zip(white.iter()).map(|(a, b)| a * b).sum::<f64>()
+        });
+        let matrix = columns.map(|row| {
+            [row[0] * scale[0], row[1] * scale[1], row[2] * scale[2]]
+        });
+        if matrix.iter().flatten().all(|v| v.is_finite()) {
+            Ok(matrix)
+        } else {
+            Err(SingularMatrix)
+        }
+    }
+
+    fn rgb_from_xyz_f64(&self) -> Result<[[f64; 3]; 3], SingularMatrix> {
+        crate::maths::checked_inverse_f64(&self.xyz_from_rgb_f64()?)
+    }
+}
+
+/// Returns matrix converting linear RGB values in `src` space into linear
+/// RGB values in `dst` space.
+///
+/// The matrix is a composition of conversion from `src` into XYZ, chromatic
+/// adaptation using `cat` method (if white points of the spaces differ) and
+/// conversion from XYZ into `dst`.  Calculations are performed in double
+/// precision and only the final matrix is rounded.  Colours outside of the
+/// `dst` gamut result in values outside of the 0–1 range.  Returns an error
+/// if either space is degenerate (see [`RgbSpace::xyz_from_rgb_matrix()`]).
+///
+/// # Example
+/// ```
+/// use srgb::adapt::CatMethod;
+/// use srgb::space::{conversion_matrix, RgbSpace};
+///
+/// let matrix = conversion_matrix(
+///     &RgbSpace::SRGB,
+///     &RgbSpace::DISPLAY_P3,
+///     CatMethod::Bradford,
+/// )
+/// .unwrap();
+/// approx::assert_abs_diff_eq!(0.8225, matrix[0][0], epsilon = 0.0001);
+/// approx::assert_abs_diff_eq!(0.9105, matrix[2][2], epsilon = 0.0001);
+/// ```
+pub fn conversion_matrix(
+    src: &RgbSpace,
+    dst: &RgbSpace,
+    cat: CatMethod,
+) -> Result<[[f32; 3]; 3], SingularMatrix> {
+    use crate::maths::matrix_multiply_f64;
+
+    let mut matrix = src.xyz_from_rgb_f64()?;
+    if src.white != dst.white {
+        let adapt =
+            crate::adapt::matrix_f64(cat, src.white_xyz(), dst.white_xyz());
+        matrix = matrix_multiply_f64(&adapt, &matrix);
+    }
+    Ok(to_f32(matrix_multiply_f64(&dst.rgb_from_xyz_f64()?, &matrix)))
+}
+
+/// Returns XYZ coordinates with luminance of one of given chromaticity.
+fn xyz_from_xy((x, y): (f32, f32)) -> [f64; 3] {
+    let (x, y) = (f64::from(x), f64::from(y));
+    [x / y, 1.0, (1.0 - x - y) / y]
+}
+
+/// Rounds elements of a matrix to single precision.
+fn to_f32(matrix: [[f64; 3]; 3]) -> [[f32; 3]; 3] {
+    matrix.map(|row| row.map(|v| v as f32))
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const SPACES: [RgbSpace; 3] =
+        [RgbSpace::SRGB, RgbSpace::DISPLAY_P3, RgbSpace::REC2020];
+
+    #[track_caller]
+    fn assert_near_identity(matrix: [[f32; 3]; 3], epsilon: f32) {
+        for (r, row) in matrix.iter().enumerate() {
+            let mut want = [0.0; 3];
+            want[r] = 1.0;
+            approx::assert_abs_diff_eq!(&want[..], &row[..], epsilon = epsilon);
+        }
+    }
+
+    fn multiply(a: [[f32; 3]; 3], b: [[f32; 3]; 3]) -> [[f32; 3]; 3] {
+        let a = a.map(|row| row.map(f64::from));
+        let b = b.map(|row| row.map(f64::from));
+        to_f32(crate::maths::matrix_multiply_f64(&a, &b))
+    }
+
+    #[test]
+    fn test_identity() {
+        for space in SPACES.iter() {
+            let matrix =
+                conversion_matrix(space, space, CatMethod::Bradford).unwrap();
+            assert_near_identity(matrix, 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let d50 = RgbSpace { white: (0.3457, 0.3585), ..RgbSpace::SRGB };
+        for cat in [CatMethod::Bradford, CatMethod::Cat02, CatMethod::VonKries]
+        {
+            for src in SPACES.iter().chain(Some(&d50)) {
+                for dst in SPACES.iter().chain(Some(&d50)) {
+                    let there = conversion_matrix(src, dst, cat).unwrap();
+                    let back = conversion_matrix(dst, src, cat).unwrap();
+                    assert_near_identity(multiply(back, there), 1e-5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_white() {
+        // White maps onto white even if white points differ.
+        let d50 = RgbSpace { white: (0.3457, 0.3585), ..RgbSpace::DISPLAY_P3 };
+        let matrix =
+            conversion_matrix(&RgbSpace::SRGB, &d50, CatMethod::Bradford)
+                .unwrap();
+        for row in matrix.iter() {
+            approx::assert_abs_diff_eq!(
+                1.0,
+                row.iter().sum::<f32>(),
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn test_rgb_from_xyz() {
+        for space in SPACES.iter() {
+            let there = space.xyz_from_rgb_matrix().unwrap();
+            let back = space.rgb_from_xyz_matrix().unwrap();
+            assert_near_identity(multiply(back, there), 1e-6);
+            let [_, y, _] = there;
+            approx::assert_abs_diff_eq!(
+                1.0,
+                y.iter().sum::<f32>(),
+                epsilon = 1e-6
+            );
+        }
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let collinear = RgbSpace {
+            primaries: Primaries {
+                red: (0.6, 0.3),
+                green: (0.4, 0.3),
+                blue: (0.2, 0.3),
+            },
+            ..RgbSpace::SRGB
+        };
+        let zero_white = RgbSpace { white: (0.3, 0.0), ..RgbSpace::SRGB };
+        let zero_primary = RgbSpace {
+            primaries: Primaries {
+                blue: (0.15, 0.0),
+                ..RgbSpace::SRGB.primaries
+            },
+            ..RgbSpace::SRGB
+        };
+        for space in [collinear, zero_white, zero_primary] {
+            assert_eq!(Err(SingularMatrix), space.xyz_from_rgb_matrix());
+            assert_eq!(Err(SingularMatrix), space.rgb_from_xyz_matrix());
+            assert_eq!(
+                Err(SingularMatrix),
+                conversion_matrix(&space, &RgbSpace::SRGB, CatMethod::Bradford)
+            );
+            assert_eq!(
+                Err(SingularMatrix),
+                conversion_matrix(&RgbSpace::SRGB, &space, CatMethod::Bradford)
+            );
+        }
+    }
+}
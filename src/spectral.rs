@@ -0,0 +1,177 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions for handling monochromatic light based on the CIE 1931 standard
+//! observer colour matching functions.
+
+/// Wavelength (in nanometres) of the first entry in [`CMF_1931`].
+pub const MIN_WAVELENGTH: f32 = 380.0;
+
+/// Wavelength (in nanometres) of the last entry in [`CMF_1931`].
+pub const MAX_WAVELENGTH: f32 = 780.0;
+
+/// Distance (in nanometres) between consecutive entries in [`CMF_1931`].
+const STEP: f32 = 10.0;
+
+/// CIE 1931 2° standard observer colour matching functions x̄, ȳ and z̄
+/// tabulated every 10 nm from [`MIN_WAVELENGTH`] to [`MAX_WAVELENGTH`].
+pub const CMF_1931: [[f32; 3]; 41] = [
+    [0.001368, 0.000039, 0.006450], // 380 nm
+    [0.004243, 0.000120, 0.020050], // 390 nm
+    [0.014310, 0.000396, 0.067850], // 400 nm
+    [0.043510, 0.001210, 0.207400], // 410 nm
+    [0.134380, 0.004000, 0.645600], // 420 nm
+    [0.283900, 0.011600, 1.385600], // 430 nm
+    [0.348280, 0.023000, 1.747060], // 440 nm
+    [0.336200, 0.038000, 1.772110], // 450 nm
+    [0.290800, 0.060000, 1.669200], // 460 nm
+    [0.195360, 0.090980, 1.287640], // 470 nm
+    [0.095640, 0.139020, 0.812950], // 480 nm
+    [0.032010, 0.208020, 0.465180], // 490 nm
+    [0.004900, 0.323000, 0.272000], // 500 nm
+    [0.009300, 0.503000, 0.158200], // 510 nm
+    [0.063270, 0.710000, 0.078250], // 520 nm
+    [0.165500, 0.862000, 0.042160], // 530 nm
+    [0.290400, 0.954000, 0.020300], // 540 nm
+    [0.433450, 0.994950, 0.008750], // 550 nm
+    [0.594500, 0.995000, 0.003900], // 560 nm
+    [0.762100, 0.952000, 0.002100], // 570 nm
+    [0.916300, 0.870000, 0.001650], // 580 nm
+    [1.026300, 0.757000, 0.001100], // 590 nm
+    [1.062200, 0.631000, 0.000800], // 600 nm
+    [1.002600, 0.503000, 0.000340], // 610 nm
+    [0.854450, 0.381000, 0.000190], // 620 nm
+    [0.642400, 0.265000, 0.000050], // 630 nm
+    [0.447900, 0.175000, 0.000020], // 640 nm
+    [0.283500, 0.107000, 0.000000], // 650 nm
+    [0.164900, 0.061000, 0.000000], // 660 nm
+    [0.087400, 0.032000, 0.000000], // 670 nm
+    [0.046770, 0.017000, 0.000000], // 680 nm
+    [0.022700, 0.008210, 0.000000], // 690 nm
+    [0.011359, 0.004102, 0.000000], // 700 nm
+    [0.005790, 0.002091, 0.000000], // 710 nm
+    [0.002899, 0.001047, 0.000000], // 720 nm
+    [0.001440, 0.000520, 0.000000], // 730 nm
+    [0.000690, 0.000249, 0.000000], // 740 nm
+    [0.000332, 0.000120, 0.000000], // 750 nm
+    [0.000166, 0.000060, 0.000000], // 760 nm
+    [0.000083, 0.000030, 0.000000], // 770 nm
+    [0.000042, 0.000015, 0.000000], // 780 nm
+];
+
+/// Returns XYZ coordinates of monochromatic light of given wavelength.
+///
+/// The wavelength is given in nanometres.  The result are values of the CIE
+/// 1931 colour matching functions at that wavelength linearly interpolated from
+/// [`CMF_1931`] table.  Light of unit power is assumed such that light at 555
+/// nm (where luminous efficiency peaks) has Y coordinate close to one.  For
+/// wavelengths outside of the tabulated range (or NaN) returns zeros.
+///
+/// # Example
+/// ```
+/// use srgb::spectral::xyz_from_wavelength;
+///
+/// assert_eq!([0.43345, 0.99495, 0.00875], xyz_from_wavelength(550.0));
+/// assert_eq!([0.0, 0.0, 0.0], xyz_from_wavelength(300.0));
+/// ```
+pub fn xyz_from_wavelength(nm: f32) -> [f32; 3] {
+    if !(MIN_WAVELENGTH..=MAX_WAVELENGTH).contains(&nm) {
+        return [0.0; 3];
+    }
+    let pos = (nm - MIN_WAVELENGTH) / STEP;
+    let idx = (pos as usize).min(CMF_1931.len() - 2);
+    let frac = pos - idx as f32;
+    let (lo, hi) = (CMF_1931[idx], CMF_1931[idx + 1]);
+    [
+        crate::maths::mul_add(hi[0] - lo[0], frac, lo[0]),
+        crate::maths::mul_add(hi[1] - lo[1], frac, lo[1]),
+        crate::maths::mul_add(hi[2] - lo[2], frac, lo[2]),
+    ]
+}
+
+/// Converts monochromatic light of given wavelength into a displayable 24-bit
+/// sRGB colour.
+///
+/// The wavelength is given in nanometres.  Spectral colours lie outside of the
+/// sRGB gamut so the colour is brought into it by desaturating (i.e. adding
+/// white until no component is negative) and then, if necessary, scaling it
+/// down so that no component exceeds one.  Brightness of the result follows
+/// luminous efficiency of the light, i.e. colours near the edges of the
+/// visible spectrum are dark.  For wavelengths outside of the visible range
+/// (see [`MIN_WAVELENGTH`] and [`MAX_WAVELENGTH`]) returns black.
+///
+/// # Example
+/// ```
+/// use srgb::spectral::srgb_from_wavelength;
+///
+/// let [r, g, b] = srgb_from_wavelength(620.0);
+/// assert!(r > g && r > b);
+/// let [r, g, b] = srgb_from_wavelength(530.0);
+/// assert!(g > r && g > b);
+/// assert_eq!([0, 0, 0], srgb_from_wavelength(800.0));
+/// ```
+pub fn srgb_from_wavelength(nm: f32) -> [u8; 3] {
+    let linear = crate::xyz::linear_from_xyz(xyz_from_wavelength(nm));
+    crate::gamma::u8_from_linear(crate::gamut::desaturate_into_gamut(linear))
+}
+
+
+#[cfg(test)]
+mod test {
+    #[test]
+    fn test_xyz_from_wavelength() {
+        use super::xyz_from_wavelength;
+
+        for (i, want) in super::CMF_1931.iter().enumerate() {
+            let nm = super::MIN_WAVELENGTH + i as f32 * super::STEP;
+            assert_eq!(*want, xyz_from_wavelength(nm));
+        }
+        let [x, y, z] = xyz_from_wavelength(555.0);
+        approx::assert_abs_diff_eq!(0.513975, x, epsilon = 0.00001);
+        approx::assert_abs_diff_eq!(0.994975, y, epsilon = 0.00001);
+        approx::assert_abs_diff_eq!(0.006325, z, epsilon = 0.00001);
+
+        for nm in [0.0, 379.9, 780.1, f32::NAN, f32::INFINITY] {
+            assert_eq!([0.0; 3], xyz_from_wavelength(nm));
+        }
+    }
+
+    #[test]
+    fn test_srgb_from_wavelength() {
+        use super::srgb_from_wavelength;
+
+        fn hue(rgb: [u8; 3]) -> f32 {
+            let linear = crate::gamma::linear_from_u8(rgb);
+            let [_, a, b] = crate::oklab::oklab_from_linear(linear);
+            b.atan2(a).to_degrees().rem_euclid(360.0)
+        }
+
+        // Red, yellow, green and blue respectively.
+        for (nm, lo, hi) in [
+            (650.0, 0.0, 45.0),
+            (580.0, 70.0, 115.0),
+            (530.0, 120.0, 160.0),
+            (460.0, 240.0, 290.0),
+        ] {
+            let rgb = srgb_from_wavelength(nm);
+            let h = hue(rgb);
+            assert!(lo <= h && h <= hi, "{} nm: {:?} hue {}", nm, rgb, h);
+        }
+
+        for nm in [200.0, 800.0, f32::NAN] {
+            assert_eq!([0, 0, 0], srgb_from_wavelength(nm));
+        }
+    }
+}
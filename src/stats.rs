@@ -0,0 +1,173 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Incremental computation of image statistics.
+//!
+//! [`Accumulator`] processes colours one at a time so that statistics of an
+//! image can be computed while it’s being streamed (e.g. from a decoder)
+//! without holding the whole image in memory.
+
+/// Statistics of a set of colours computed by [`Accumulator`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Stats {
+    /// Number of colours.
+    pub count: u64,
+    /// Mean colour in linear sRGB space.
+    pub mean_linear: [f32; 3],
+    /// Mean relative luminance (i.e. Y coordinate in XYZ colour space).
+    pub mean_luminance: f32,
+    /// Smallest relative luminance of all colours.
+    pub min_luminance: f32,
+    /// Largest relative luminance of all colours.
+    pub max_luminance: f32,
+}
+
+/// Accumulator computing statistics of 24-bit sRGB colours incrementally.
+///
+/// Averaging is done in linear sRGB space with sums calculated using Kahan
+/// summation which keeps rounding errors small even for images with many
+/// millions of pixels.
+///
+/// # Example
+/// ```
+/// let mut acc = srgb::stats::Accumulator::default();
+/// acc.add([0, 0, 0]);
+/// acc.add([255, 255, 255]);
+/// let stats = acc.finish();
+/// assert_eq!(2, stats.count);
+/// assert_eq!([0.5, 0.5, 0.5], stats.mean_linear);
+/// assert_eq!(0.0, stats.min_luminance);
+/// assert_eq!(1.0, stats.max_luminance);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Accumulator {
+    count: u64,
+    linear: [KahanSum; 3],
+    luminance: KahanSum,
+    min_luminance: f32,
+    max_luminance: f32,
+}
+
+impl Accumulator {
+    /// Adds a colour to the accumulator.
+    pub fn add(&mut self, rgb: impl Into<[u8; 3]>) {
+        let linear = crate::gamma::linear_from_u8(rgb);
+        let [_, luminance, _] = crate::xyz::xyz_from_linear(linear);
+        for (sum, value) in self.linear.iter_mut().zip(linear.iter()) {
+            sum.add(*value);
+        }
+        self.luminance.add(luminance);
+        if self.count == 0 {
+            self.min_luminance = luminance;
+            self.max_luminance = luminance;
+        } else {
+            self.min_luminance = self.min_luminance.min(luminance);
+            self.max_luminance = self.max_luminance.max(luminance);
+        }
+        self.count += 1;
+    }
+
+    /// Returns statistics of all colours added so far.
+    ///
+    /// If no colours were added, all statistics are zero.
+    pub fn finish(self) -> Stats {
+        if self.count == 0 {
+            return Stats::default();
+        }
+        let count = self.count as f64;
+        let mean = |sum: &KahanSum| (sum.sum as f64 / count) as f32;
+        Stats {
+            count: self.count,
+            mean_linear: [
+                mean(&self.linear[0]),
+                mean(&self.linear[1]),
+                mean(&self.linear[2]),
+            ],
+            mean_luminance: mean(&self.luminance),
+            min_luminance: self.min_luminance,
+            max_luminance: self.max_luminance,
+        }
+    }
+}
+
+impl<C: Into<[u8; 3]>> Extend<C> for Accumulator {
+    fn extend<I: IntoIterator<Item = C>>(&mut self, iter: I) {
+        for rgb in iter {
+            self.add(rgb);
+        }
+    }
+}
+
+
+/// Sum computed with Kahan summation algorithm.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct KahanSum {
+    pub(crate) sum: f32,
+    compensation: f32,
+}
+
+impl KahanSum {
+    pub(crate) fn add(&mut self, value: f32) {
+        let value = value - self.compensation;
+        let sum = self.sum + value;
+        self.compensation = (sum - self.sum) - value;
+        self.sum = sum;
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(Stats::default(), Accumulator::default().finish());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_accumulator() {
+        let pixels =
+            (0..(1 << 24)).step_by(97).map(crate::from_u32).collect::<Vec<_>>();
+
+        let mut acc = Accumulator::default();
+        acc.extend(pixels.iter().copied());
+        let got = acc.finish();
+
+        let mut sum = [0.0f64; 4];
+        let (mut min, mut max) = (f32::INFINITY, f32::NEG_INFINITY);
+        for rgb in pixels.iter() {
+            let linear = crate::gamma::linear_from_u8(*rgb);
+            let [_, y, _] = crate::xyz::xyz_from_linear(linear);
+            for (sum, value) in
+                sum.iter_mut().zip(linear.iter().chain([y].iter()))
+            {
+                *sum += *value as f64;
+            }
+            min = min.min(y);
+            max = max.max(y);
+        }
+        let count = pixels.len() as f64;
+        let want = sum.map(|sum| (sum / count) as f32);
+
+        assert_eq!(pixels.len() as u64, got.count);
+        let mean_linear = &got.mean_linear[..];
+        approx::assert_ulps_eq!(&want[..3], mean_linear, max_ulps = 2);
+        approx::assert_ulps_eq!(want[3], got.mean_luminance, max_ulps = 2);
+        assert_eq!(min, got.min_luminance);
+        assert_eq!(max, got.max_luminance);
+    }
+}
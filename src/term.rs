@@ -0,0 +1,184 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions producing ANSI escape sequences for colouring terminal output.
+//!
+//! [`fg()`] and [`bg()`] produce 24-bit (so called true colour) sequences.
+//! For terminals which don’t support those, [`nearest_256()`] maps a colour to
+//! the xterm 256-colour palette.
+
+use core::fmt::Write;
+
+/// Returns escape sequence setting foreground colour to given 24-bit sRGB
+/// colour.
+///
+/// See [`write_fg()`] for a variant which doesn’t allocate.
+///
+/// # Example
+/// ```
+/// assert_eq!("\x1b[38;2;212;33;61m", srgb::term::fg([212, 33, 61]));
+/// ```
+pub fn fg(rgb: impl Into<[u8; 3]>) -> String {
+    let mut buf = String::with_capacity(19);
+    let _ = write_fg(&mut buf, rgb);
+    buf
+}
+
+/// Returns escape sequence setting background colour to given 24-bit sRGB
+/// colour.
+///
+/// See [`write_bg()`] for a variant which doesn’t allocate.
+///
+/// # Example
+/// ```
+/// assert_eq!("\x1b[48;2;212;33;61m", srgb::term::bg([212, 33, 61]));
+/// ```
+pub fn bg(rgb: impl Into<[u8; 3]>) -> String {
+    let mut buf = String::with_capacity(19);
+    let _ = write_bg(&mut buf, rgb);
+    buf
+}
+
+/// Writes escape sequence setting foreground colour to given 24-bit sRGB
+/// colour.
+///
+/// # Example
+/// ```
+/// let mut buf = String::new();
+/// srgb::term::write_fg(&mut buf, [212, 33, 61]).unwrap();
+/// assert_eq!("\x1b[38;2;212;33;61m", buf);
+/// ```
+pub fn write_fg(
+    out: &mut impl Write,
+    rgb: impl Into<[u8; 3]>,
+) -> core::fmt::Result {
+    write_sequence(out, 38, rgb.into())
+}
+
+/// Writes escape sequence setting background colour to given 24-bit sRGB
+/// colour.
+///
+/// # Example
+/// ```
+/// let mut buf = String::new();
+/// srgb::term::write_bg(&mut buf, [212, 33, 61]).unwrap();
+/// assert_eq!("\x1b[48;2;212;33;61m", buf);
+/// ```
+pub fn write_bg(
+    out: &mut impl Write,
+    rgb: impl Into<[u8; 3]>,
+) -> core::fmt::Result {
+    write_sequence(out, 48, rgb.into())
+}
+
+fn write_sequence(
+    out: &mut impl Write,
+    code: u8,
+    [r, g, b]: [u8; 3],
+) -> core::fmt::Result {
+    write!(out, "\x1b[{};2;{};{};{}m", code, r, g, b)
+}
+
+
+/// Returns index of the colour in xterm 256-colour palette closest to given
+/// 24-bit sRGB colour.
+///
+/// Only the 6×6×6 colour cube (indices 16–231) and greyscale ramp (indices
+/// 232–255) are considered since the first 16 colours are customisable and
+/// differ between terminals.  Distance is measured in linear sRGB space.
+///
+/// The index can be used in `\x1b[38;5;<index>m` and `\x1b[48;5;<index>m`
+/// escape sequences.
+///
+/// # Example
+/// ```
+/// use srgb::term::nearest_256;
+///
+/// assert_eq!(196, nearest_256([255, 0, 0]));
+/// assert_eq!(244, nearest_256([128, 128, 128]));
+/// assert_eq!(160, nearest_256([212, 33, 61]));
+/// ```
+pub fn nearest_256(rgb: impl Into<[u8; 3]>) -> u8 {
+    let linear = crate::gamma::linear_from_u8(rgb);
+    let distance = |index: u8| {
+        let other = crate::gamma::linear_from_u8(rgb_from_256(index));
+        (0..3).map(|i| (linear[i] - other[i]).powi(2)).sum::<f32>()
+    };
+    (16..=255)
+        .map(|index| (distance(index), index))
+        .fold((f32::INFINITY, 0), |a, b| if b.0 < a.0 { b } else { a })
+        .1
+}
+
+/// Returns colour of given entry in xterm 256-colour palette.  Must not be
+/// called for indices below 16.
+fn rgb_from_256(index: u8) -> [u8; 3] {
+    const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+    if index >= 232 {
+        let v = 8 + (index - 232) * 10;
+        [v, v, v]
+    } else {
+        let index = index - 16;
+        [
+            LEVELS[usize::from(index / 36)],
+            LEVELS[usize::from(index / 6 % 6)],
+            LEVELS[usize::from(index % 6)],
+        ]
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_sequences() {
+        for c in (0..(1 << 24)).step_by(9973) {
+            let rgb = crate::from_u32(c);
+            for (seq, code) in [(fg(rgb), "38"), (bg(rgb), "48")] {
+                let body = seq
+                    .strip_prefix("\x1b[")
+                    .and_then(|seq| seq.strip_suffix('m'))
+                    .unwrap();
+                let parts = body.split(';').collect::<Vec<_>>();
+                assert_eq!(5, parts.len(), "{:?}", seq);
+                assert_eq!([code, "2"], parts[..2], "{:?}", seq);
+                let got =
+                    [0, 1, 2].map(|i| parts[i + 2].parse::<u8>().unwrap());
+                assert_eq!(rgb, got, "{:?}", seq);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nearest_256() {
+        assert_eq!(16, nearest_256([0, 0, 0]));
+        assert_eq!(196, nearest_256([255, 0, 0]));
+        assert_eq!(46, nearest_256([0, 255, 0]));
+        assert_eq!(21, nearest_256([0, 0, 255]));
+        assert_eq!(226, nearest_256([255, 255, 0]));
+        assert_eq!(51, nearest_256([0, 255, 255]));
+        assert_eq!(201, nearest_256([255, 0, 255]));
+        assert_eq!(231, nearest_256([255, 255, 255]));
+
+        // Every palette colour maps to itself or, for the few greys which
+        // appear both in the cube and in the ramp, an identical colour.
+        for index in 16..=255 {
+            let rgb = rgb_from_256(index);
+            assert_eq!(rgb, rgb_from_256(nearest_256(rgb)), "{}", index);
+        }
+    }
+}
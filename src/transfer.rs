@@ -0,0 +1,192 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Selectable transfer functions for the convenience conversions.
+//!
+//! Top-level convenience functions such as [`crate::u8_from_xyz()`] and
+//! [`crate::xyz_from_u8()`] always use the sRGB transfer function.  Code
+//! which consistently works with a different encoding can instead use
+//! methods of the [`Transfer`] trait implemented by types in this module.
+//! Importing the type under an alias selects the transfer function for the
+//! whole module (or crate) in a single place:
+//!
+//! ```
+//! use srgb::transfer::{Rec709 as Encoding, Transfer};
+//!
+//! let xyz = srgb::xyz_from_u8([212, 33, 61]);
+//! // Calls through the alias use Rec.709 transfer function.
+//! let rgb = Encoding::u8_from_xyz(xyz);
+//! assert_eq!([193, 31, 54], rgb);
+//! ```
+//!
+//! Note that changing the alias changes meaning of all calls which use it.
+//! Functions in the [`crate::gamma`] module specific to each transfer
+//! function remain available.
+
+/// Transfer function used to encode 8-bit colours.
+///
+/// Types implementing this trait act as a switch selecting the transfer
+/// function used by the conversion methods.  Implementations need to provide
+/// only [`Transfer::expand_u8`] and [`Transfer::compress_u8`] functions;
+/// remaining methods are built from those and conversions in the
+/// [`crate::xyz`] module.
+pub trait Transfer {
+    /// Converts an 8-bit encoded component value into linear value in the
+    /// range 0–1.
+    fn expand_u8(e: u8) -> f32;
+
+    /// Converts a linear component value into 8-bit encoded value.  The
+    /// argument is clamped to the range 0–1.
+    fn compress_u8(s: f32) -> u8;
+
+    /// Converts a 24-bit colour into linear space.
+    fn linear_from_u8(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
+        crate::arr_map(rgb, Self::expand_u8)
+    }
+
+    /// Converts a colour in linear space into 24-bit representation.
+    fn u8_from_linear(linear: impl Into<[f32; 3]>) -> [u8; 3] {
+        crate::arr_map(linear, Self::compress_u8)
+    }
+
+    /// Converts a 24-bit colour into XYZ colour space.
+    fn xyz_from_u8(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
+        crate::xyz::xyz_from_linear(Self::linear_from_u8(rgb))
+    }
+
+    /// Converts a colour in XYZ colour space into 24-bit representation.
+    fn u8_from_xyz(xyz: impl Into<[f32; 3]>) -> [u8; 3] {
+        Self::u8_from_linear(crate::xyz::linear_from_xyz(xyz))
+    }
+}
+
+/// sRGB transfer function.
+///
+/// This is what top-level convenience functions use.  See
+/// [`crate::gamma::expand_u8()`] and [`crate::gamma::compress_u8()`].
+///
+/// # Example
+/// ```
+/// use srgb::transfer::{Srgb, Transfer};
+///
+/// let xyz = srgb::xyz_from_u8([212, 33, 61]);
+/// assert_eq!(srgb::u8_from_xyz(xyz), Srgb::u8_from_xyz(xyz));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Srgb;
+
+/// Rec.709 transfer function with 8-bit coding in the [16, 235] range.
+///
+/// See [`crate::gamma::expand_rec709_8bit()`] and
+/// [`crate::gamma::compress_rec709_8bit()`].
+///
+/// # Example
+/// ```
+/// use srgb::transfer::{Rec709, Transfer};
+///
+/// assert_eq!([16, 16, 16], Rec709::u8_from_linear([0.0, 0.0, 0.0]));
+/// assert_eq!([235, 235, 235], Rec709::u8_from_linear([1.0, 1.0, 1.0]));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Rec709;
+
+/// Pure power function with exponent of 2.2.
+///
+/// Some software and displays approximate sRGB curve with a simple power
+/// function.  Compared to sRGB the curve is darker near black.
+///
+/// # Example
+/// ```
+/// use srgb::transfer::{Gamma22, Transfer};
+///
+/// assert_eq!(0.0, Gamma22::expand_u8(0));
+/// assert_eq!(0.21951973, Gamma22::expand_u8(128));
+/// assert_eq!(1.0, Gamma22::expand_u8(255));
+/// assert_eq!(128, Gamma22::compress_u8(0.21951973));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Gamma22;
+
+impl Transfer for Srgb {
+    #[inline]
+    fn expand_u8(e: u8) -> f32 { crate::gamma::expand_u8(e) }
+    #[inline]
+    fn compress_u8(s: f32) -> u8 { crate::gamma::compress_u8(s) }
+}
+
+impl Transfer for Rec709 {
+    #[inline]
+    fn expand_u8(e: u8) -> f32 { crate::gamma::expand_rec709_8bit(e) }
+    #[inline]
+    fn compress_u8(s: f32) -> u8 { crate::gamma::compress_rec709_8bit(s) }
+}
+
+impl Transfer for Gamma22 {
+    #[inline]
+    fn expand_u8(e: u8) -> f32 { (e as f32 / 255.0).powf(2.2) }
+    #[inline]
+    fn compress_u8(s: f32) -> u8 {
+        // Adding 0.5 is for rounding.  NaN is converted to zero.
+        crate::maths::mul_add(s.clamp(0.0, 1.0).powf(1.0 / 2.2), 255.0, 0.5)
+            as u8
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_srgb() {
+        for c in (0..(1 << 24)).step_by(9973) {
+            let rgb = crate::from_u32(c);
+            let xyz = crate::xyz_from_u8(rgb);
+            assert_eq!(xyz, Srgb::xyz_from_u8(rgb));
+            assert_eq!(crate::u8_from_xyz(xyz), Srgb::u8_from_xyz(xyz));
+        }
+    }
+
+    #[test]
+    fn test_rec709() {
+        for c in (0..(1 << 24)).step_by(9973) {
+            let xyz = crate::xyz_from_u8(crate::from_u32(c));
+            let linear = crate::xyz::linear_from_xyz(xyz);
+            let want =
+                crate::arr_map(linear, crate::gamma::compress_rec709_8bit);
+            assert_eq!(want, Rec709::u8_from_xyz(xyz));
+
+            let want = crate::xyz::xyz_from_linear(crate::arr_map(
+                want,
+                crate::gamma::expand_rec709_8bit,
+            ));
+            assert_eq!(want, Rec709::xyz_from_u8(Rec709::u8_from_xyz(xyz)));
+        }
+    }
+
+    #[test]
+    fn test_gamma22() {
+        for e in 0..=255 {
+            assert_eq!(e, Gamma22::compress_u8(Gamma22::expand_u8(e)));
+        }
+        assert_eq!(0, Gamma22::compress_u8(-1.0));
+        assert_eq!(0, Gamma22::compress_u8(f32::NAN));
+        assert_eq!(255, Gamma22::compress_u8(2.0));
+        // Pure power curve is darker near black than sRGB.
+        for e in 1..16 {
+            assert!(Gamma22::expand_u8(e) < Srgb::expand_u8(e), "{}", e);
+        }
+    }
+}
@@ -0,0 +1,459 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Strongly-typed wrappers for colours in different representations.
+//!
+//! Functions in this crate operate on bare `[f32; 3]` arrays which makes it
+//! easy to pass e.g. a gamma-encoded colour where a linear one is expected.
+//! Types in this module wrap the arrays such that such mistakes are caught at
+//! compile time while methods on them wrap the free functions:
+//!
+//! ```
+//! use srgb::types::Srgb8;
+//!
+//! let xyz = Srgb8::new([212, 33, 61]).to_linear().to_xyz();
+//! approx::assert_abs_diff_eq!(0.1542, xyz[1], epsilon = 0.0001);
+//! let oklab = xyz.to_linear().to_oklab();
+//! approx::assert_abs_diff_eq!(0.5624, oklab[0], epsilon = 0.0001);
+//! ```
+//!
+//! Floating point types convert from and into `[f32; 3]` so they can be
+//! passed to functions which take `impl Into<[f32; 3]>` arguments.  Their
+//! components can be accessed without copying through indexing, `as_slice()`
+//! method or `AsRef<[f32; 3]>` and `AsRef<[f32]>` implementations.
+//!
+//! [`Srgb8`] implements `Eq`, `Hash` and `Ord` and can be used as a key in
+//! ordered and hashed collections.  Floating point types don’t implement
+//! those traits but can be wrapped in [`TotalOrd`] which does.
+//!
+//! With `serde` feature the types implement `Serialize` and `Deserialize`
+//! traits and are represented the same way the arrays are.
+
+macro_rules! define_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq)]
+        #[cfg_attr(
+            feature = "serde",
+            derive(::serde::Serialize, ::serde::Deserialize)
+        )]
+        pub struct $name(pub [f32; 3]);
+
+        impl From<[f32; 3]> for $name {
+            fn from(value: [f32; 3]) -> Self { Self(value) }
+        }
+
+        impl From<$name> for [f32; 3] {
+            fn from(value: $name) -> Self { value.0 }
+        }
+
+        impl $name {
+            /// Returns components of the colour as a slice.
+            pub fn as_slice(&self) -> &[f32] { &self.0[..] }
+
+            /// Returns components of the colour as a mutable slice.
+            pub fn as_mut_slice(&mut self) -> &mut [f32] { &mut self.0[..] }
+        }
+
+        impl AsRef<[f32; 3]> for $name {
+            fn as_ref(&self) -> &[f32; 3] { &self.0 }
+        }
+
+        impl AsRef<[f32]> for $name {
+            fn as_ref(&self) -> &[f32] { &self.0[..] }
+        }
+
+        impl AsMut<[f32; 3]> for $name {
+            fn as_mut(&mut self) -> &mut [f32; 3] { &mut self.0 }
+        }
+
+        impl core::ops::Index<usize> for $name {
+            type Output = f32;
+            fn index(&self, index: usize) -> &f32 { &self.0[index] }
+        }
+
+        impl core::ops::IndexMut<usize> for $name {
+            fn index_mut(&mut self, index: usize) -> &mut f32 {
+                &mut self.0[index]
+            }
+        }
+    };
+}
+
+define_type! {
+    /// sRGB colour with gamma-encoded components in the range from zero to
+    /// one.
+    ///
+    /// This is the normalised representation used by e.g.
+    /// [`crate::gamma::linear_from_normalised()`].
+    EncodedRgb
+}
+
+define_type! {
+    /// Colour in linear sRGB space.
+    LinearRgb
+}
+
+define_type! {
+    /// Colour in XYZ colour space where white colour has Y coordinate equal
+    /// one.
+    Xyz
+}
+
+define_type! {
+    /// Colour in CIELAB colour space given as `[L*, a*, b*]` triple.
+    Lab
+}
+
+define_type! {
+    /// Colour in Oklab colour space given as `[L, a, b]` triple.
+    Oklab
+}
+
+/// 24-bit sRGB colour.
+///
+/// Unlike the floating point types, this type implements `Eq`, `Hash` and
+/// `Ord` (comparing colours lexicographically by channel) so it can be used
+/// as a key in `HashMap` or `BTreeMap` e.g. when deduplicating palettes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+pub struct Srgb8(pub [u8; 3]);
+
+impl From<[u8; 3]> for Srgb8 {
+    fn from(value: [u8; 3]) -> Self { Self(value) }
+}
+
+impl From<Srgb8> for [u8; 3] {
+    fn from(value: Srgb8) -> Self { value.0 }
+}
+
+impl Srgb8 {
+    /// Constructs the colour from its red, green and blue components.
+    pub const fn new(rgb: [u8; 3]) -> Self { Self(rgb) }
+
+    /// Converts the colour into normalised representation.
+    ///
+    /// This wraps [`crate::normalised_from_u8()`].
+    pub fn normalise(self) -> EncodedRgb {
+        EncodedRgb(crate::normalised_from_u8(self))
+    }
+
+    /// Performs gamma expansion converting the colour into linear space.
+    ///
+    /// This wraps [`crate::gamma::linear_from_u8()`].
+    pub fn to_linear(self) -> LinearRgb {
+        LinearRgb(crate::gamma::linear_from_u8(self))
+    }
+}
+
+impl EncodedRgb {
+    /// Converts a 24-bit sRGB colour into normalised representation.
+    ///
+    /// This wraps [`crate::normalised_from_u8()`].
+    pub fn from_u8(rgb: impl Into<[u8; 3]>) -> Self {
+        Self(crate::normalised_from_u8(rgb))
+    }
+
+    /// Converts the colour into a 24-bit sRGB colour.
+    ///
+    /// This wraps [`crate::u8_from_normalised()`].
+    pub fn to_u8(self) -> [u8; 3] { crate::u8_from_normalised(self) }
+
+    /// Performs gamma expansion converting the colour into linear space.
+    ///
+    /// This wraps [`crate::gamma::linear_from_normalised()`].
+    ///
+    /// # Example
+    /// ```
+    /// use srgb::types::{EncodedRgb, LinearRgb};
+    ///
+    /// let linear = EncodedRgb([0.0, 1.0, 0.0]).expand();
+    /// assert_eq!(LinearRgb([0.0, 1.0, 0.0]), linear);
+    /// ```
+    pub fn expand(self) -> LinearRgb {
+        LinearRgb(crate::gamma::linear_from_normalised(self))
+    }
+}
+
+impl LinearRgb {
+    /// Performs gamma compression converting the colour into gamma-encoded
+    /// representation.
+    ///
+    /// This wraps [`crate::gamma::normalised_from_linear()`].
+    pub fn compress(self) -> EncodedRgb {
+        EncodedRgb(crate::gamma::normalised_from_linear(self))
+    }
+
+    /// Converts the colour into XYZ colour space.
+    ///
+    /// This wraps [`crate::xyz::xyz_from_linear()`].
+    ///
+    /// # Example
+    /// ```
+    /// use srgb::types::LinearRgb;
+    ///
+    /// let xyz = LinearRgb([1.0, 1.0, 1.0]).to_xyz();
+    /// assert_eq!(srgb::xyz::D65_XYZ, <[f32; 3]>::from(xyz));
+    /// ```
+    pub fn to_xyz(self) -> Xyz { Xyz(crate::xyz::xyz_from_linear(self)) }
+
+    /// Converts the colour into Oklab colour space.
+    ///
+    /// This wraps [`crate::oklab::oklab_from_linear()`].
+    pub fn to_oklab(self) -> Oklab {
+        Oklab(crate::oklab::oklab_from_linear(self))
+    }
+}
+
+impl Xyz {
+    /// Converts the colour into linear sRGB space.
+    ///
+    /// This wraps [`crate::xyz::linear_from_xyz()`].
+    pub fn to_linear(self) -> LinearRgb {
+        LinearRgb(crate::xyz::linear_from_xyz(self))
+    }
+
+    /// Converts the colour into CIELAB colour space relative to the D65 white
+    /// point.
+    ///
+    /// This wraps [`crate::lab::lab_from_xyz()`].
+    ///
+    /// # Example
+    /// ```
+    /// use srgb::types::Srgb8;
+    ///
+    /// let lab = Srgb8::new([212, 33, 61]).to_linear().to_xyz().to_lab();
+    /// approx::assert_abs_diff_eq!(46.2, lab[0], epsilon = 0.1);
+    /// ```
+    pub fn to_lab(self) -> Lab { Lab(crate::lab::lab_from_xyz(self)) }
+}
+
+impl Oklab {
+    /// Converts the colour into linear sRGB space.
+    ///
+    /// This wraps [`crate::oklab::linear_from_oklab()`].
+    pub fn to_linear(self) -> LinearRgb {
+        LinearRgb(crate::oklab::linear_from_oklab(self))
+    }
+}
+
+/// Wrapper providing total ordering, equality and hashing of colours.
+///
+/// `f32` doesn’t implement `Ord`, `Eq` or `Hash` which makes colours
+/// unusable as keys in `BTreeMap` or `HashMap`.  This wrapper compares colours
+/// lexicographically by component with each component compared using
+/// [`f32::total_cmp()`].  Two colours are equal if and only if their
+/// components have identical bit representations.
+///
+/// Note that this differs from the `PartialEq` implementation of the colour
+/// types.  In particular, negative zero is less than positive zero, NaN is
+/// equal to itself and NaNs are ordered by their sign and payload with
+/// positive NaNs greater than positive infinity and negative NaNs less than
+/// negative infinity.
+///
+/// # Example
+/// ```
+/// use srgb::types::{LinearRgb, TotalOrd};
+///
+/// let mut colours = std::collections::BTreeSet::new();
+/// colours.insert(TotalOrd(LinearRgb([1.0, 0.0, 0.0])));
+/// colours.insert(TotalOrd(LinearRgb([0.0, 1.0, 0.0])));
+/// colours.insert(TotalOrd(LinearRgb([1.0, 0.0, 0.0])));
+/// let first = colours.iter().next().unwrap();
+/// assert_eq!(2, colours.len());
+/// assert_eq!(LinearRgb([0.0, 1.0, 0.0]), first.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct TotalOrd<T>(pub T);
+
+impl<T: AsRef<[f32; 3]>> TotalOrd<T> {
+    fn bits(&self) -> [u32; 3] {
+        let [a, b, c] = *self.0.as_ref();
+        [a.to_bits(), b.to_bits(), c.to_bits()]
+    }
+}
+
+impl<T: AsRef<[f32; 3]>> PartialEq for TotalOrd<T> {
+    fn eq(&self, other: &Self) -> bool { self.bits() == other.bits() }
+}
+
+impl<T: AsRef<[f32; 3]>> Eq for TotalOrd<T> {}
+
+impl<T: AsRef<[f32; 3]>> PartialOrd for TotalOrd<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: AsRef<[f32; 3]>> Ord for TotalOrd<T> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        let (lhs, rhs) = (self.0.as_ref(), other.0.as_ref());
+        lhs.iter()
+            .zip(rhs.iter())
+            .map(|(lhs, rhs)| lhs.total_cmp(rhs))
+            .find(|ord| ord.is_ne())
+            .unwrap_or(core::cmp::Ordering::Equal)
+    }
+}
+
+impl<T: AsRef<[f32; 3]>> core::hash::Hash for TotalOrd<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.bits().hash(state)
+    }
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_round_trip() {
+        for c in 0..(16 * 16 * 16) {
+            let rgb = crate::from_u32(c * 0x1001);
+            let encoded = EncodedRgb::from_u8(rgb);
+            let linear = encoded.expand();
+            let want = crate::gamma::linear_from_u8(rgb);
+            let got = linear.0;
+            approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = 1e-6);
+            let xyz = linear.to_xyz();
+            let want = crate::xyz_from_u8(rgb);
+            let got = xyz.0;
+            approx::assert_abs_diff_eq!(&want[..], &got[..], epsilon = 1e-6);
+            assert_eq!(rgb, xyz.to_linear().compress().to_u8());
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_chain() {
+        use crate::gamma::{linear_from_u8, normalised_from_linear};
+        use crate::lab::lab_from_xyz;
+        use crate::oklab::{linear_from_oklab, oklab_from_linear};
+        use crate::xyz::{linear_from_xyz, xyz_from_linear};
+
+        for c in 0..(16 * 16 * 16) {
+            let rgb = crate::from_u32(c * 0x1001);
+
+            let got = Srgb8::new(rgb).to_linear().to_xyz().to_lab();
+            let want = lab_from_xyz(xyz_from_linear(linear_from_u8(rgb)));
+            assert_eq!(want, got.0);
+
+            let xyz = Srgb8::new(rgb).to_linear().to_xyz();
+            let got = xyz.to_linear().to_oklab().to_linear().compress();
+            let want =
+                normalised_from_linear(linear_from_oklab(oklab_from_linear(
+                    linear_from_xyz(xyz_from_linear(linear_from_u8(rgb))),
+                )));
+            assert_eq!(want, got.0);
+        }
+    }
+
+    #[test]
+    fn test_component_access() {
+        fn is_copy<T: Copy>(_: &T) {}
+
+        let mut xyz = Xyz([0.25, 0.5, 0.75]);
+        is_copy(&xyz);
+        assert_eq!((0.25, 0.5, 0.75), (xyz[0], xyz[1], xyz[2]));
+        assert_eq!(&[0.25, 0.5, 0.75][..], xyz.as_slice());
+        assert_eq!(&[0.25, 0.5, 0.75], AsRef::<[f32; 3]>::as_ref(&xyz));
+        assert_eq!(&[0.25, 0.5, 0.75][..], AsRef::<[f32]>::as_ref(&xyz));
+        assert!(core::ptr::eq(&xyz.0[1], &xyz[1]));
+
+        xyz[1] = 1.0;
+        xyz.as_mut_slice()[2] = 2.0;
+        AsMut::<[f32; 3]>::as_mut(&mut xyz)[0] = 0.0;
+        assert_eq!(Xyz([0.0, 1.0, 2.0]), xyz);
+
+        let linear = LinearRgb([1.0, 0.5, 0.0]);
+        let copy = linear;
+        assert_eq!(linear.as_slice(), copy.as_slice());
+        assert_eq!(0.5, EncodedRgb([0.0, 0.5, 1.0])[1]);
+    }
+
+    #[test]
+    fn test_total_ord() {
+        use core::cmp::Ordering;
+
+        let values = [
+            -f32::NAN,
+            f32::NEG_INFINITY,
+            -1.0,
+            -0.0,
+            0.0,
+            0.5,
+            f32::INFINITY,
+            f32::NAN,
+        ];
+        let colours = values
+            .iter()
+            .flat_map(|&a| values.iter().map(move |&b| (a, b)))
+            .flat_map(|(a, b)| values.iter().map(move |&c| [a, b, c]))
+            .map(|rgb| TotalOrd(LinearRgb(rgb)))
+            .collect::<Vec<_>>();
+
+        // Colours are generated in lexicographic order of their components
+        // so the ordering must agree with their indices.
+        for (i, lhs) in colours.iter().enumerate() {
+            for (j, rhs) in colours.iter().enumerate() {
+                assert_eq!(i.cmp(&j), lhs.cmp(rhs), "{:?} {:?}", lhs, rhs);
+                assert_eq!(i == j, lhs == rhs, "{:?} {:?}", lhs, rhs);
+            }
+        }
+
+        let nan = TotalOrd(Xyz([f32::NAN; 3]));
+        assert_eq!(Ordering::Equal, nan.cmp(&nan));
+        let set = std::collections::HashSet::from([nan, nan]);
+        assert_eq!(1, set.len());
+    }
+
+    #[test]
+    fn test_srgb8_key() {
+        let colours = [[212, 33, 61], [0, 0, 0], [212, 33, 61], [0, 0, 1]];
+        let mut counts = std::collections::HashMap::new();
+        for rgb in colours.iter().copied() {
+            *counts.entry(Srgb8::new(rgb)).or_insert(0) += 1;
+        }
+        assert_eq!(3, counts.len());
+        assert_eq!(2, counts[&Srgb8([212, 33, 61])]);
+        assert_eq!(1, counts[&Srgb8([0, 0, 0])]);
+
+        let sorted = colours
+            .iter()
+            .copied()
+            .map(Srgb8)
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .map(<[u8; 3]>::from)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![[0, 0, 0], [0, 0, 1], [212, 33, 61]], sorted);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde() {
+        let json = serde_json::to_string(&LinearRgb([1.0, 0.5, 0.0])).unwrap();
+        assert_eq!("[1.0,0.5,0.0]", json);
+        let got: Xyz = serde_json::from_str(&json).unwrap();
+        assert_eq!(Xyz([1.0, 0.5, 0.0]), got);
+
+        let json = serde_json::to_string(&Srgb8([212, 33, 61])).unwrap();
+        assert_eq!("[212,33,61]", json);
+        let got: Srgb8 = serde_json::from_str(&json).unwrap();
+        assert_eq!(Srgb8([212, 33, 61]), got);
+    }
+}
@@ -0,0 +1,89 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions implementing WCAG 2.x contrast ratio.
+//!
+//! See [`crate::apca_contrast()`] for the contrast method proposed for WCAG 3.
+
+/// Calculates WCAG 2.x contrast ratio between two 24-bit sRGB colours.
+///
+/// The ratio is calculated as `(L1 + 0.05) / (L2 + 0.05)` where `L1` and `L2`
+/// are relative luminances of the lighter and darker colour respectively.  The
+/// result is thus in the range from one (no contrast) to 21 (black on white)
+/// regardless of the order of the arguments.
+///
+/// Relative luminance is calculated with the 0.2126, 0.7152 and 0.0722 weights
+/// given in WCAG 2.x.  They differ from the Y row of
+/// [`crate::xyz::XYZ_FROM_SRGB_MATRIX`] in the fifth decimal place.
+///
+/// # Example
+/// ```
+/// use srgb::wcag::contrast_ratio;
+///
+/// let ratio = contrast_ratio([0, 0, 0], [255, 255, 255]);
+/// approx::assert_abs_diff_eq!(21.0, ratio, epsilon = 0.0001);
+/// assert_eq!(ratio, contrast_ratio([255, 255, 255], [0, 0, 0]));
+/// assert_eq!(1.0, contrast_ratio([212, 33, 61], [212, 33, 61]));
+/// let ratio = contrast_ratio([118, 118, 118], [255, 255, 255]);
+/// approx::assert_abs_diff_eq!(4.54, ratio, epsilon = 0.01);
+/// ```
+pub fn contrast_ratio(fg: impl Into<[u8; 3]>, bg: impl Into<[u8; 3]>) -> f32 {
+    let fg = relative_luminance(fg.into());
+    let bg = relative_luminance(bg.into());
+    let (hi, lo) = if fg > bg { (fg, bg) } else { (bg, fg) };
+    (hi + 0.05) / (lo + 0.05)
+}
+
+/// Checks whether contrast ratio passes WCAG 2.x level AA requirements.
+///
+/// Normal text requires contrast ratio of at least 4.5 while large text (at
+/// least 18 point or 14 point bold) requires contrast ratio of at least 3.
+///
+/// # Example
+/// ```
+/// use srgb::wcag::{contrast_ratio, passes_aa};
+///
+/// let ratio = contrast_ratio([118, 118, 118], [255, 255, 255]);
+/// assert!(passes_aa(ratio, false));
+/// let ratio = contrast_ratio([119, 119, 119], [255, 255, 255]);
+/// assert!(!passes_aa(ratio, false));
+/// assert!(passes_aa(ratio, true));
+/// ```
+pub fn passes_aa(ratio: f32, large_text: bool) -> bool {
+    ratio >= if large_text { 3.0 } else { 4.5 }
+}
+
+/// Calculates relative luminance as defined by WCAG 2.x.
+fn relative_luminance([r, g, b]: [u8; 3]) -> f32 {
+    use crate::gamma::expand_u8;
+    0.2126 * expand_u8(r) + 0.7152 * expand_u8(g) + 0.0722 * expand_u8(b)
+}
+
+
+#[cfg(test)]
+mod test {
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_contrast_ratio() {
+        for c in (0..(1 << 24)).step_by(997) {
+            let rgb = crate::from_u32(c);
+            let ratio = super::contrast_ratio(rgb, [0, 0, 0]);
+            assert!((1.0..=21.0).contains(&ratio), "{:?}: {}", rgb, ratio);
+            assert_eq!(ratio, super::contrast_ratio([0, 0, 0], rgb));
+            let ratio = super::contrast_ratio(rgb, rgb);
+            assert_eq!(1.0, ratio, "{:?}", rgb);
+        }
+    }
+}
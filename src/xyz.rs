@@ -56,11 +56,244 @@ pub fn linear_from_xyz(xyz: impl Into<[f32; 3]>) -> [f32; 3] {
 }
 
 
+/// Converts a 24-bit sRGB colour into XYZ colour space using lookup tables.
+///
+/// This is equivalent to [`crate::xyz_from_u8()`] but rather than performing
+/// gamma expansion followed by matrix multiplication, it uses lookup tables
+/// (generated at build time) which hold products of matrix columns and all
+/// possible expanded component values.  Conversion of a colour is thus
+/// reduced to three table lookups and two vector additions.
+///
+/// The tables occupy 9 KiB.  Since they are calculated with high precision
+/// the result may differ slightly from that returned by
+/// [`crate::xyz_from_u8()`] with the difference being at most a few ULPs.
+///
+/// # Example
+/// ```
+/// use srgb::xyz::xyz_from_u8_lut;
+///
+/// assert_eq!([0.0, 0.0, 0.0], xyz_from_u8_lut([0, 0, 0]));
+/// approx::assert_ulps_eq!(
+///     &srgb::xyz_from_u8([212, 33, 61])[..],
+///     &xyz_from_u8_lut([212, 33, 61])[..],
+///     max_ulps = 2
+/// );
+/// ```
+pub fn xyz_from_u8_lut(rgb: impl Into<[u8; 3]>) -> [f32; 3] {
+    let [r, g, b] = rgb.into();
+    let r = &XYZ_FROM_U8_LUT[0][r as usize];
+    let g = &XYZ_FROM_U8_LUT[1][g as usize];
+    let b = &XYZ_FROM_U8_LUT[2][b as usize];
+    [r[0] + g[0] + b[0], r[1] + g[1] + b[1], r[2] + g[2] + b[2]]
+}
+
+/// Normalises XYZ coordinates relative to given white point.
+///
+/// Each coordinate is divided by the corresponding coordinate of the `white`
+/// point such that the white point itself maps to `[1, 1, 1]`.  This is the
+/// scaling (sometimes called ‘wrong von Kries’ adaptation) used by ICC
+/// relative colorimetric rendering to express colours relative to the media
+/// white point before they are converted into profile connection space.
+///
+/// Note that this is not a proper chromatic adaptation.  Methods such as
+/// Bradford transform scale cone responses rather than XYZ coordinates and
+/// better predict how colours appear under different illuminants.  This
+/// function should be used when compatibility with ICC behaviour is needed.
+///
+/// # Example
+/// ```
+/// use srgb::xyz::{normalise_to_white, D65_XYZ};
+///
+/// assert_eq!([1.0, 1.0, 1.0], normalise_to_white(D65_XYZ, D65_XYZ));
+/// assert_eq!([0.5, 0.5, 0.5], normalise_to_white([0.5, 0.5, 0.5], [1.0; 3]));
+/// ```
+pub fn normalise_to_white(
+    xyz: impl Into<[f32; 3]>,
+    white: impl Into<[f32; 3]>,
+) -> [f32; 3] {
+    let ([x, y, z], [wx, wy, wz]) = (xyz.into(), white.into());
+    [x / wx, y / wy, z / wz]
+}
+
+/// Converts XYZ coordinates normalised relative to given white point back
+/// into absolute coordinates.
+///
+/// This is an inverse of [`normalise_to_white()`], i.e. multiplies each
+/// coordinate by the corresponding coordinate of the `white` point.
+///
+/// # Example
+/// ```
+/// use srgb::xyz::{denormalise_from_white, D65_XYZ};
+///
+/// assert_eq!(D65_XYZ, denormalise_from_white([1.0, 1.0, 1.0], D65_XYZ));
+/// ```
+pub fn denormalise_from_white(
+    xyz: impl Into<[f32; 3]>,
+    white: impl Into<[f32; 3]>,
+) -> [f32; 3] {
+    let ([x, y, z], [wx, wy, wz]) = (xyz.into(), white.into());
+    [x * wx, y * wy, z * wz]
+}
+
+/// Lays out a 3×3 matrix as expected by GPU uniform buffers.
+///
+/// Matrices in this crate (such as [`XYZ_FROM_SRGB_MATRIX`]) are stored in
+/// row-major order, i.e. `matrix[row][column]`.  GLSL (with `std140` and
+/// `std430` layouts) and HLSL (with default `column_major` packing) store
+/// `mat3`/`float3x3` matrices as three columns each padded to four floats.
+/// This function returns the matrix in that layout: `result[column][row]`
+/// holds `matrix[row][column]` and `result[column][3]` is zero padding.  The
+/// result can be copied directly into a uniform buffer (48 bytes).
+///
+/// This is a `const fn` so it can be used to define constants.
+///
+/// # Example
+/// ```
+/// use srgb::xyz::{matrix_as_std140, XYZ_FROM_SRGB_MATRIX};
+///
+/// const UNIFORM: [[f32; 4]; 3] = matrix_as_std140(&XYZ_FROM_SRGB_MATRIX);
+/// assert_eq!(XYZ_FROM_SRGB_MATRIX[1][0], UNIFORM[0][1]);
+/// assert_eq!(0.0, UNIFORM[2][3]);
+/// ```
+pub const fn matrix_as_std140(matrix: &[[f32; 3]; 3]) -> [[f32; 4]; 3] {
+    let m = matrix;
+    [[m[0][0], m[1][0], m[2][0], 0.0], [m[0][1], m[1][1], m[2][1], 0.0], [
+        m[0][2], m[1][2], m[2][2], 0.0,
+    ]]
+}
+
+/// Chromaticities of red, green and blue primaries of an RGB colour space.
+///
+/// Each primary is given as `(x, y)` chromaticity coordinates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Primaries {
+    /// Chromaticity of the red primary.
+    pub red: (f32, f32),
+    /// Chromaticity of the green primary.
+    pub green: (f32, f32),
+    /// Chromaticity of the blue primary.
+    pub blue: (f32, f32),
+}
+
+/// Returns chromaticities of primaries defining the sRGB space.
+///
+/// This is the same data as in [`PRIMARIES_xyY`] but with named fields
+/// rather than positions in an array.
+///
+/// # Example
+/// ```
+/// let primaries = srgb::xyz::primaries();
+/// assert_eq!((0.64, 0.33), primaries.red);
+/// assert_eq!((0.3, 0.6), primaries.green);
+/// assert_eq!((0.15, 0.06), primaries.blue);
+/// ```
+pub const fn primaries() -> Primaries {
+    let p = &PRIMARIES_xyY;
+    Primaries {
+        red: (p[0][0], p[0][1]),
+        green: (p[1][0], p[1][1]),
+        blue: (p[2][0], p[2][1]),
+    }
+}
+
+/// Returns chromaticity of the D65 reference white-point used in sRGB colour
+/// space.
+///
+/// This is the same data as `x` and `y` coordinates in [`D65_xyY`].
+///
+/// Note that D65 is defined by a spectral power distribution and different
+/// sources give slightly different chromaticity for it.  This crate uses
+/// (0.312713, 0.329016).  sRGB and Rec.709 specifications round it to four
+/// decimal places, (0.3127, 0.3290), while CIE 15 gives (0.31272, 0.32903).
+/// The differences are well below what’s perceptible but may matter when
+/// comparing results with other software; see [`white_point_delta_from()`].
+///
+/// # Example
+/// ```
+/// assert_eq!((0.312713, 0.329016), srgb::xyz::white_point());
+/// ```
+pub const fn white_point() -> (f32, f32) { (D65_xyY[0], D65_xyY[1]) }
+
+/// Returns distance between given chromaticity and the white point used by
+/// this crate.
+///
+/// The distance is Euclidean distance in CIE 1976 u′v′ chromaticity diagram,
+/// i.e. Δu′v′, which is commonly used to specify white point tolerances.
+/// Differences around 0.004 are just noticeable.  Alternative definitions of
+/// D65 (see [`white_point()`]) are within 0.00002 of the crate’s white point.
+///
+/// # Example
+/// ```
+/// use srgb::xyz::white_point_delta_from;
+///
+/// assert_eq!(0.0, white_point_delta_from(srgb::xyz::white_point()));
+/// // D65 as given in sRGB specification.
+/// assert!(white_point_delta_from((0.3127, 0.3290)) < 0.00002);
+/// // D50 used by ICC profile connection space.
+/// assert!(white_point_delta_from((0.3457, 0.3585)) > 0.02);
+/// ```
+pub fn white_point_delta_from(other: (f32, f32)) -> f32 {
+    let uv = |(x, y): (f32, f32)| {
+        let (x, y) = (x as f64, y as f64);
+        let d = 12.0 * y - 2.0 * x + 3.0;
+        (4.0 * x / d, 9.0 * y / d)
+    };
+    let (u0, v0) = uv(white_point());
+    let (u1, v1) = uv(other);
+    (u1 - u0).hypot(v1 - v0) as f32
+}
+
 include!(concat!(env!("OUT_DIR"), "/xyz_constants.rs"));
 
 
 #[cfg(test)]
 mod test {
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_xyz_from_u8_lut() {
+        for c in (0..(1 << 24)).step_by(101) {
+            let rgb = crate::from_u32(c);
+            let want = crate::xyz_from_u8(rgb);
+            let got = super::xyz_from_u8_lut(rgb);
+            approx::assert_ulps_eq!(&want[..], &got[..], max_ulps = 4);
+        }
+    }
+
+    #[test]
+    fn test_normalise_to_white() {
+        for white in [super::D65_XYZ, super::D50_XYZ] {
+            assert_eq!([1.0; 3], super::normalise_to_white(white, white));
+            assert_eq!(white, super::denormalise_from_white([1.0; 3], white));
+            for rgb in [[212, 33, 61], [0, 0, 255], [10, 200, 30]] {
+                let xyz = crate::xyz_from_u8(rgb);
+                let norm = super::normalise_to_white(xyz, white);
+                let got = super::denormalise_from_white(norm, white);
+                approx::assert_ulps_eq!(&xyz[..], &got[..], max_ulps = 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_as_std140() {
+        let matrix = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        let want =
+            [[1.0, 4.0, 7.0, 0.0], [2.0, 5.0, 8.0, 0.0], [3.0, 6.0, 9.0, 0.0]];
+        assert_eq!(want, super::matrix_as_std140(&matrix));
+
+        for matrix in [super::XYZ_FROM_SRGB_MATRIX, super::SRGB_FROM_XYZ_MATRIX]
+        {
+            let got = super::matrix_as_std140(&matrix);
+            for (row, values) in matrix.iter().enumerate() {
+                for (col, value) in values.iter().enumerate() {
+                    assert_eq!(*value, got[col][row], "{}×{}", row, col);
+                }
+            }
+            for column in got.iter() {
+                assert_eq!(0.0, column[3]);
+            }
+        }
+    }
+
     #[test]
     fn test_d65() {
         let [x, y, _] = super::D65_xyY;
@@ -75,6 +308,140 @@ mod test {
         assert_eq!(&want[..], &got[..]);
     }
 
+    #[test]
+    fn test_white_points() {
+        for (xy, got) in [
+            ((0.34567, 0.35850), super::D50_XYZ),
+            ((0.33242, 0.34743), super::D55_XYZ),
+            ((0.29902, 0.31485), super::D75_XYZ),
+            ((0.44757, 0.40745), super::A_XYZ),
+            ((0.31006, 0.31616), super::C_XYZ),
+            ((1.0 / 3.0, 1.0 / 3.0), super::E_XYZ),
+        ] {
+            let (x, y): (f64, f64) = xy;
+            let want = [(x / y) as f32, 1.0, ((1.0 - x - y) / y) as f32];
+            assert_eq!(&want[..], &got[..], "{:?}", xy);
+        }
+    }
+
+    #[test]
+    fn test_primaries() {
+        let got = super::primaries();
+        for (got, want) in [got.red, got.green, got.blue]
+            .iter()
+            .zip(super::PRIMARIES_xyY.iter())
+        {
+            assert_eq!((want[0], want[1]), *got);
+        }
+        let [x, y, _] = super::D65_xyY;
+        assert_eq!((x, y), super::white_point());
+    }
+
+    #[test]
+    fn test_white_point_delta_from() {
+        use super::white_point_delta_from;
+
+        assert_eq!(0.0, white_point_delta_from(super::white_point()));
+        // D65 as given in sRGB and Rec.709 specifications and in CIE 15.
+        let got = white_point_delta_from((0.3127, 0.3290));
+        approx::assert_abs_diff_eq!(0.0000109, got, epsilon = 0.0000001);
+        let got = white_point_delta_from((0.31272, 0.32903));
+        approx::assert_abs_diff_eq!(0.0000085, got, epsilon = 0.0000001);
+        // D50 white point.
+        let got = white_point_delta_from((0.3457, 0.3585));
+        approx::assert_abs_diff_eq!(0.022777, got, epsilon = 0.000001);
+    }
+
+    /// Conversion matrices calculated with exact rational arithmetic from
+    /// [`super::PRIMARIES_xyY`] and [`super::D65_xyY`] chromaticities as
+    /// described in SMPTE RP 177.
+    const REFERENCE_XYZ_FROM_SRGB: [[f64; 3]; 3] = [
+        [
+            0.41241084648853883206,
+            0.35758456785295191513,
+            0.18045380393360833613,
+        ],
+        [
+            0.21264934272065283528,
+            0.71516913570590383027,
+            0.07218152157344333445,
+        ],
+        [
+            0.01933175842915025775,
+            0.11919485595098397171,
+            0.95039003405033723697,
+        ],
+    ];
+    const REFERENCE_SRGB_FROM_XYZ: [[f64; 3]; 3] = [
+        [
+            3.24081239889528297955,
+            -1.53730844562981372107,
+            -0.49858652290696661224,
+        ],
+        [
+            -0.96924301700864069123,
+            1.87596630290857416135,
+            0.04155503085668563798,
+        ],
+        [
+            0.05563839843611280686,
+            -0.20400746093241362516,
+            1.05712957028614333040,
+        ],
+    ];
+
+    #[test]
+    fn test_reference() {
+        fn check(
+            matrix: &[[f32; 3]; 3],
+            reference: &[[f64; 3]; 3],
+            convert: fn([f32; 3]) -> [f32; 3],
+            max_error: f64,
+        ) {
+            // Matrix elements are correctly rounded.
+            for (got, want) in matrix.iter().zip(reference.iter()) {
+                assert_eq!(want.map(|v| v as f32), *got);
+            }
+            for c in 0..(16 * 16 * 16) {
+                let column = [
+                    (c & 15) as f32 / 15.0,
+                    ((c >> 4) & 15) as f32 / 15.0,
+                    ((c >> 8) & 15) as f32 / 15.0,
+                ];
+                let got = convert(column);
+                for (got, row) in got.iter().zip(reference.iter()) {
+                    let want = row
+                        .iter()
+                        .zip(column.iter())
+                        .map(|(a, b)| a * *b as f64)
+                        .sum::<f64>();
+                    let error = (*got as f64 - want).abs();
+                    assert!(
+                        error <= max_error,
+                        "{:?} → {:?}; want: {}; error: {}",
+                        column,
+                        got,
+                        want,
+                        error
+                    );
+                }
+            }
+        }
+
+        check(
+            &super::XYZ_FROM_SRGB_MATRIX,
+            &REFERENCE_XYZ_FROM_SRGB,
+            super::xyz_from_linear,
+            0.0000002,
+        );
+        check(
+            &super::SRGB_FROM_XYZ_MATRIX,
+            &REFERENCE_SRGB_FROM_XYZ,
+            super::linear_from_xyz,
+            0.0000006,
+        );
+    }
+
     #[test]
     fn test_reversible_conversion() {
         let mut error = kahan::KahanSum::new();
@@ -0,0 +1,111 @@
+/* This file is part of srgb crate.
+ * Copyright 2026 by agent <agent@local>
+ *
+ * srgb crate is free software: you can redistribute it and/or modify it under
+ * the terms of the GNU Lesser General Public License as published by the Free
+ * Software Foundation; either version 3 of the License, or (at your option) any
+ * later version.
+ *
+ * srgb crate is distributed in the hope that it will be useful, but WITHOUT ANY
+ * WARRANTY; without even the implied warranty of MERCHANTABILITY or FITNESS FOR
+ * A PARTICULAR PURPOSE.  See the GNU General Public License for more details.
+ *
+ * You should have received a copy of the GNU General Public License along with
+ * srgb crate.  If not, see <http://www.gnu.org/licenses/>. */
+
+//! Functions implementing the reversible YCoCg-R luma/chroma transform.
+//!
+//! YCoCg separates a colour into luma (Y), orange chroma (Co) and green
+//! chroma (Cg).  Its YCoCg-R variant uses integer lifting steps and is
+//! exactly reversible which makes it popular in lossless and real-time
+//! compression.  As is conventional for compression, the transform operates
+//! on gamma-encoded 8-bit sRGB values rather than linear ones.
+
+/// Converts a 24-bit sRGB colour into YCoCg-R representation.
+///
+/// The result is `[Y, Co, Cg]` triple where luma is in the range 0–255 while
+/// chroma components are in the range -255–255.  [`rgb_from_ycocg()`]
+/// recovers the original colour exactly.
+///
+/// # Example
+/// ```
+/// use srgb::ycocg::ycocg_from_rgb;
+///
+/// assert_eq!([255, 0, 0], ycocg_from_rgb([255, 255, 255]));
+/// assert_eq!([84, 151, -103], ycocg_from_rgb([212, 33, 61]));
+/// ```
+pub fn ycocg_from_rgb(rgb: impl Into<[u8; 3]>) -> [i16; 3] {
+    let [r, g, b] = rgb.into();
+    let [r, g, b] = [r as i16, g as i16, b as i16];
+    let co = r - b;
+    let t = b + (co >> 1);
+    let cg = g - t;
+    let y = t + (cg >> 1);
+    [y, co, cg]
+}
+
+/// Converts a colour in YCoCg-R representation into a 24-bit sRGB colour.
+///
+/// This is an exact inverse of [`ycocg_from_rgb()`].  Triples which weren’t
+/// produced by that function may decode into components outside of the 0–255
+/// range.  Such components are clamped.
+///
+/// # Example
+/// ```
+/// use srgb::ycocg::rgb_from_ycocg;
+///
+/// assert_eq!([255, 255, 255], rgb_from_ycocg([255, 0, 0]));
+/// assert_eq!([212, 33, 61], rgb_from_ycocg([84, 151, -103]));
+/// ```
+pub fn rgb_from_ycocg(ycocg: [i16; 3]) -> [u8; 3] {
+    let [y, co, cg] = ycocg.map(i32::from);
+    let t = y - (cg >> 1);
+    let g = cg + t;
+    let b = t - (co >> 1);
+    let r = b + co;
+    [r, g, b].map(|v| v.clamp(0, 255) as u8)
+}
+
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn check(rgb: [u8; 3]) {
+        let ycocg = ycocg_from_rgb(rgb);
+        let [y, co, cg] = ycocg;
+        assert!((0..=255).contains(&y), "{:?} → {:?}", rgb, ycocg);
+        assert!((-255..=255).contains(&co), "{:?} → {:?}", rgb, ycocg);
+        assert!((-255..=255).contains(&cg), "{:?} → {:?}", rgb, ycocg);
+        assert_eq!(rgb, rgb_from_ycocg(ycocg), "{:?}", ycocg);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "Runs too slow on Miri")]
+    fn test_round_trip() {
+        for c in (0..(1 << 24)).step_by(997) {
+            check(crate::from_u32(c));
+        }
+        for v in 0..=255 {
+            check([v, v, v]);
+            check([v, 0, 255 - v]);
+            check([255, v, 0]);
+        }
+    }
+
+    #[test]
+    #[ignore = "Checks all 2²⁴ colours; slow in debug builds"]
+    fn test_round_trip_exhaustive() {
+        for c in 0..(1 << 24) {
+            check(crate::from_u32(c));
+        }
+    }
+
+    #[test]
+    fn test_clamping() {
+        assert_eq!([255, 255, 255], rgb_from_ycocg([300, 0, 0]));
+        assert_eq!([0, 0, 0], rgb_from_ycocg([-300, 0, 0]));
+        assert_eq!([0, 0, 0], rgb_from_ycocg([i16::MIN; 3]));
+        assert_eq!([255, 0, 255], rgb_from_ycocg([0, 0, i16::MIN]));
+    }
+}